@@ -40,6 +40,7 @@ pub enum Binaries {
     Tor,
     BridgeTapplet,
     LolMiner,
+    Glytex,
 }
 impl Binaries {
     pub fn name(&self) -> &str {
@@ -51,6 +52,7 @@ impl Binaries {
             Binaries::Tor => "tor",
             Binaries::BridgeTapplet => "bridge",
             Binaries::LolMiner => "lolminer",
+            Binaries::Glytex => "glytex",
         }
     }
 
@@ -63,6 +65,7 @@ impl Binaries {
             "tor" => Binaries::Tor,
             "bridge" => Binaries::BridgeTapplet,
             "lolminer" => Binaries::LolMiner,
+            "glytex" => Binaries::Glytex,
             _ => panic!("Unknown binary name: {name}"),
         }
     }
@@ -107,6 +110,10 @@ impl Binaries {
                 let file_name = "lolMiner";
                 Self::append_exe_if_windows(&mut PathBuf::from(version).join(file_name))
             }
+            Binaries::Glytex => {
+                let file_name = "glytex";
+                Self::append_exe_if_windows(&mut PathBuf::from(file_name))
+            }
         }
     }
 
@@ -199,6 +206,16 @@ impl Binaries {
                 }
                 _ => "Not available for this platform".to_string(),
             },
+            // OpenCL builds run on both AMD and NVIDIA cards
+            Binaries::Glytex => match platform {
+                BinaryPlatformAssets::LinuxX64 => {
+                    format!("glytex-opencl-linux-x86_64-{network}-{version}-{hash}.zip")
+                }
+                BinaryPlatformAssets::WindowsX64 => {
+                    format!("glytex-opencl-windows-x64-{network}-{version}-{hash}.exe.zip")
+                }
+                _ => "Not available for this platform".to_string(),
+            },
         }
     }
 }
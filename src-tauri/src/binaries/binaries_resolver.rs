@@ -141,6 +141,19 @@ impl BinaryResolver {
             ),
         );
 
+        binary_manager.insert(
+            Binaries::Glytex,
+            BinaryManager::new(
+                Binaries::Glytex.name().to_string(),
+                None,
+                Box::new(GithubReleasesAdapter {
+                    repo: "glytex".to_string(),
+                    owner: "tari-project".to_string(),
+                }),
+                true,
+            ),
+        );
+
         binary_manager.insert(
             Binaries::MergeMiningProxy,
             BinaryManager::new(
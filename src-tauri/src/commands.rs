@@ -32,19 +32,35 @@ use crate::configs::config_pools::{ConfigPools, ConfigPoolsContent};
 use crate::configs::config_ui::{ConfigUI, ConfigUIContent, DisplayMode};
 use crate::configs::config_wallet::{ConfigWallet, ConfigWalletContent, WalletId};
 use crate::configs::pools::BasePoolData;
+use crate::configs::pools::custom_pools::{CustomPoolConfig, validate_custom_pool};
 use crate::configs::pools::{cpu_pools::CpuPool, gpu_pools::GpuPool};
 use crate::configs::trait_config::ConfigImpl;
 use crate::consts::DEFAULT_SYSTEM_LOCALE_FALLBACK;
-use crate::event_scheduler::{EventScheduler, SchedulerEventTiming, SchedulerEventType};
+use crate::event_scheduler::{
+    EventActivations, EventScheduler, SchedulerEventTiming, SchedulerEventType,
+};
 use crate::events::ConnectionStatusPayload;
 use crate::events_emitter::EventsEmitter;
 use crate::events_manager::EventsManager;
 use crate::internal_wallet::{InternalWallet, PaperWalletConfig, mnemonic_to_tari_cipher_seed};
+use crate::mcp::tools::scheduler::check_mining_mode;
+use crate::mining::cpu::benchmark::CpuThreadBenchmark;
+use crate::mining::cpu::consts::CpuMinerType;
 use crate::mining::cpu::manager::CpuManager;
+use crate::mining::gpu::benchmark::GpuMinersBenchmark;
+use crate::mining::gpu::consts::{
+    GpuMinerType, GpuMiningAlgorithm, MAX_GPU_TEMPERATURE_CAP, MIN_GPU_TEMPERATURE_CAP,
+};
 use crate::mining::gpu::manager::GpuManager;
+use crate::mining::is_benchmark_running;
 use crate::mining::pools::PoolManagerInterfaceTrait;
 use crate::mining::pools::cpu_pool_manager::CpuPoolManager;
+use crate::mining::pools::failover::{
+    PoolFailoverSettings, PoolKind, PoolSwitchRecord, parse_pool_priority,
+};
 use crate::mining::pools::gpu_pool_manager::GpuPoolManager;
+use crate::mining::pools::stratum_probe::{self, PoolLatencyReport};
+use crate::mining::thermal::ThermalLimitsSettings;
 use crate::network_utils::NetworkExt;
 use crate::node::node_adapter::BaseNodeStatus;
 use crate::node::node_manager::NodeType;
@@ -56,6 +72,7 @@ use crate::system_dependencies::system_dependencies_manager::SystemDependenciesM
 use crate::systemtray_manager::{SystemTrayEvents, SystemTrayManager};
 use crate::tapplets::interface::ActiveTapplet;
 use crate::tapplets::tapplet_server::start_tapplet;
+use crate::tariff::{self, TariffBandEstimate, TariffSettings};
 use crate::tasks_tracker::TasksTrackers;
 use crate::tor_adapter::TorConfig;
 use crate::utils::address_utils::verify_send;
@@ -981,6 +998,46 @@ pub async fn set_cpu_mining_enabled(enabled: bool) -> Result<(), InvokeError> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn switch_cpu_miner(miner: CpuMinerType) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET_APP_LOGIC, "switch_cpu_miner called with miner: {miner}");
+    CpuManager::write()
+        .await
+        .select_miner(miner)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn switch_gpu_miner(miner: GpuMinerType) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET_APP_LOGIC, "switch_gpu_miner called with miner: {miner}");
+    GpuManager::write()
+        .await
+        .select_miner(miner)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn benchmark_gpu_miners(auto_select: bool) -> Result<GpuMinersBenchmark, InvokeError> {
+    info!(target: LOG_TARGET_APP_LOGIC, "benchmark_gpu_miners called with auto_select: {auto_select}");
+    GpuManager::benchmark_gpu_miners(auto_select)
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn benchmark_cpu_threads() -> Result<CpuThreadBenchmark, InvokeError> {
+    info!(target: LOG_TARGET_APP_LOGIC, "benchmark_cpu_threads called");
+    CpuManager::benchmark_cpu_threads()
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
 #[tauri::command]
 pub async fn sign_ws_data(data: String) -> Result<SignWsDataResponse, String> {
     let key: ring::signature::Ed25519KeyPair = get_websocket_key().map_err(|e| {
@@ -1047,6 +1104,93 @@ pub async fn toggle_device_exclusion(device_index: u32, excluded: bool) -> Resul
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_gpu_device_intensity(
+    device_index: u32,
+    intensity_percentage: Option<u32>,
+) -> Result<(), InvokeError> {
+    if intensity_percentage.is_some_and(|intensity| !(1..=100).contains(&intensity)) {
+        return Err(InvokeError::from(
+            "Intensity percentage must be between 1 and 100".to_string(),
+        ));
+    }
+
+    let config = ConfigMining::content().await;
+    config
+        .gpu_devices_settings()
+        .ensure_device(device_index)
+        .map_err(InvokeError::from_anyhow)?;
+    // Clearing an override is always allowed, it only takes effect with miners that read it
+    if intensity_percentage.is_some() && !config.selected_gpu_miner().is_intensity_supported() {
+        return Err(InvokeError::from(format!(
+            "{} does not support a per device intensity",
+            config.selected_gpu_miner()
+        )));
+    }
+
+    info!(target: LOG_TARGET_APP_LOGIC, "Setting intensity of device {device_index} to {intensity_percentage:?}");
+    ConfigMining::update_field(
+        ConfigMiningContent::set_gpu_device_intensity,
+        (device_index, intensity_percentage),
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_update_gpu_devices_settings(
+        ConfigMining::content().await.gpu_devices_settings().clone(),
+    )
+    .await;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_gpu_device_limits(
+    device_index: u32,
+    power_limit_watts: Option<u32>,
+    max_temperature: Option<u32>,
+) -> Result<(), InvokeError> {
+    if power_limit_watts == Some(0) {
+        return Err(InvokeError::from(
+            "Power limit must be greater than 0".to_string(),
+        ));
+    }
+    if max_temperature.is_some_and(|temperature| {
+        !(MIN_GPU_TEMPERATURE_CAP..=MAX_GPU_TEMPERATURE_CAP).contains(&temperature)
+    }) {
+        return Err(InvokeError::from(format!(
+            "Max temperature must be between {MIN_GPU_TEMPERATURE_CAP} and {MAX_GPU_TEMPERATURE_CAP}"
+        )));
+    }
+
+    let config = ConfigMining::content().await;
+    config
+        .gpu_devices_settings()
+        .ensure_device(device_index)
+        .map_err(InvokeError::from_anyhow)?;
+    if (power_limit_watts.is_some() || max_temperature.is_some())
+        && !config.selected_gpu_miner().is_device_limits_supported()
+    {
+        return Err(InvokeError::from(format!(
+            "{} does not support per device limits",
+            config.selected_gpu_miner()
+        )));
+    }
+
+    info!(target: LOG_TARGET_APP_LOGIC, "Setting limits of device {device_index} to power: {power_limit_watts:?} W, temperature: {max_temperature:?} C");
+    ConfigMining::update_field(
+        ConfigMiningContent::set_gpu_device_limits,
+        (device_index, power_limit_watts, max_temperature),
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_update_gpu_devices_settings(
+        ConfigMining::content().await.gpu_devices_settings().clone(),
+    )
+    .await;
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn set_gpu_mining_enabled(enabled: bool) -> Result<(), InvokeError> {
     let timer = Instant::now();
@@ -1088,11 +1232,22 @@ pub async fn set_mine_on_app_start(mine_on_app_start: bool) -> Result<(), Invoke
 pub async fn select_mining_mode(mode: String) -> Result<(), InvokeError> {
     let timer = Instant::now();
     info!(target: LOG_TARGET_APP_LOGIC, "[select_mining_mode] called with mode: {mode:?}");
+    if is_benchmark_running() {
+        return Err(InvokeError::from_anyhow(anyhow::anyhow!(
+            "Mining mode can not change while a miner benchmark runs"
+        )));
+    }
 
     ConfigMining::update_field(ConfigMiningContent::set_selected_mining_mode, mode.clone())
         .await
         .map_err(InvokeError::from_anyhow)?;
 
+    CpuManager::write()
+        .await
+        .update_mining_mode()
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
     if mode != "Eco" {
         ConfigMining::update_field(ConfigMiningContent::set_eco_alert_needed, false)
             .await
@@ -1117,6 +1272,11 @@ pub async fn update_custom_mining_mode(
 ) -> Result<(), InvokeError> {
     let timer = Instant::now();
     info!(target: LOG_TARGET_APP_LOGIC, "[update_custom_mining_mode] called with custom_cpu_usage: {custom_cpu_usage:?}, custom_gpu_usage: {custom_gpu_usage:?}");
+    if is_benchmark_running() {
+        return Err(InvokeError::from_anyhow(anyhow::anyhow!(
+            "Mining mode can not change while a miner benchmark runs"
+        )));
+    }
 
     ConfigMining::update_field(
         ConfigMiningContent::update_custom_mode_cpu_usage,
@@ -1132,6 +1292,12 @@ pub async fn update_custom_mining_mode(
     .await
     .map_err(InvokeError::from_anyhow)?;
 
+    CpuManager::write()
+        .await
+        .update_mining_mode()
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
     if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
         warn!(target: LOG_TARGET_APP_LOGIC, "update_custom_mining_mode took too long: {:?}", timer.elapsed());
     }
@@ -1825,12 +1991,20 @@ pub async fn change_cpu_pool(cpu_pool: String) -> Result<(), InvokeError> {
     let timer = Instant::now();
     info!(target: LOG_TARGET_APP_LOGIC, "[change_cpu_pool] called with cpu_pool: {cpu_pool:?}");
 
-    ConfigPools::update_field(
-        ConfigPoolsContent::set_current_cpu_pool,
-        CpuPool::from_string(&cpu_pool).map_err(InvokeError::from_anyhow)?,
-    )
-    .await
-    .map_err(InvokeError::from_anyhow)?;
+    let cpu_pool = CpuPool::from_string(&cpu_pool).map_err(InvokeError::from_anyhow)?;
+    if !ConfigPools::content()
+        .await
+        .cpu_pools()
+        .contains_key(&cpu_pool)
+    {
+        return Err(InvokeError::from_anyhow(anyhow::anyhow!(
+            "CPU pool {cpu_pool} is not configured"
+        )));
+    }
+
+    ConfigPools::update_field(ConfigPoolsContent::promote_cpu_pool, cpu_pool)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
 
     let cpu_pool_content = ConfigPools::content().await.current_cpu_pool();
 
@@ -1847,12 +2021,20 @@ pub async fn change_gpu_pool(gpu_pool: String) -> Result<(), InvokeError> {
     let timer = Instant::now();
     info!(target: LOG_TARGET_APP_LOGIC, "[change_gpu_pool] called with gpu_pool: {gpu_pool:?}");
 
-    ConfigPools::update_field(
-        ConfigPoolsContent::set_current_gpu_pool,
-        GpuPool::from_string(&gpu_pool).map_err(InvokeError::from_anyhow)?,
-    )
-    .await
-    .map_err(InvokeError::from_anyhow)?;
+    let gpu_pool = GpuPool::from_string(&gpu_pool).map_err(InvokeError::from_anyhow)?;
+    if !ConfigPools::content()
+        .await
+        .gpu_pools()
+        .contains_key(&gpu_pool)
+    {
+        return Err(InvokeError::from_anyhow(anyhow::anyhow!(
+            "GPU pool {gpu_pool} is not configured"
+        )));
+    }
+
+    ConfigPools::update_field(ConfigPoolsContent::promote_gpu_pool, gpu_pool)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
 
     let gpu_pool_content = ConfigPools::content().await.current_gpu_pool();
 
@@ -1864,6 +2046,202 @@ pub async fn change_gpu_pool(gpu_pool: String) -> Result<(), InvokeError> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn save_custom_cpu_pool(config: CustomPoolConfig) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET_APP_LOGIC, "[save_custom_cpu_pool] called with config: {config:?}");
+
+    let pool_data = config
+        .into_pool_data(CpuPool::Custom)
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigPools::update_field(ConfigPoolsContent::upsert_cpu_pool, pool_data)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    let pools_config = ConfigPools::content().await;
+    EventsEmitter::emit_pools_config_loaded(&pools_config).await;
+    if *pools_config.current_cpu_pool_type() == CpuPool::Custom {
+        CpuPoolManager::handle_new_selected_pool(pools_config.current_cpu_pool()).await;
+    }
+    Ok(())
+}
+
+/// Saves the user defined GPU pool for `algorithm`, C29 when not given
+#[tauri::command]
+pub async fn save_custom_gpu_pool(
+    config: CustomPoolConfig,
+    algorithm: Option<GpuMiningAlgorithm>,
+) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET_APP_LOGIC, "[save_custom_gpu_pool] called with config: {config:?}, algorithm: {algorithm:?}");
+
+    let gpu_pool = GpuPool::custom_for(&algorithm.unwrap_or_default());
+    let pool_data = config
+        .into_pool_data(gpu_pool.clone())
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigPools::update_field(ConfigPoolsContent::upsert_gpu_pool, pool_data)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    let pools_config = ConfigPools::content().await;
+    EventsEmitter::emit_pools_config_loaded(&pools_config).await;
+    if *pools_config.current_gpu_pool_type() == gpu_pool {
+        GpuPoolManager::handle_new_selected_pool(pools_config.current_gpu_pool()).await;
+    }
+    Ok(())
+}
+
+/// Removes a user defined CPU pool, the default pool is selected if the removed one was in use
+#[tauri::command]
+pub async fn remove_cpu_pool(cpu_pool: String) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET_APP_LOGIC, "[remove_cpu_pool] called with cpu_pool: {cpu_pool:?}");
+
+    let cpu_pool = CpuPool::from_string(&cpu_pool).map_err(InvokeError::from_anyhow)?;
+    if cpu_pool != CpuPool::Custom {
+        return Err(InvokeError::from_anyhow(anyhow::anyhow!(
+            "Only custom pools can be removed"
+        )));
+    }
+    let was_selected = *ConfigPools::content().await.current_cpu_pool_type() == cpu_pool;
+    ConfigPools::update_field(ConfigPoolsContent::remove_cpu_pool, cpu_pool)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    let pools_config = ConfigPools::content().await;
+    EventsEmitter::emit_pools_config_loaded(&pools_config).await;
+    if was_selected {
+        CpuPoolManager::handle_new_selected_pool(pools_config.current_cpu_pool()).await;
+    }
+    Ok(())
+}
+
+/// Removes a user defined GPU pool, the default pool is selected if the removed one was in use
+#[tauri::command]
+pub async fn remove_gpu_pool(gpu_pool: String) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET_APP_LOGIC, "[remove_gpu_pool] called with gpu_pool: {gpu_pool:?}");
+
+    let gpu_pool = GpuPool::from_string(&gpu_pool).map_err(InvokeError::from_anyhow)?;
+    if !gpu_pool.is_custom() {
+        return Err(InvokeError::from_anyhow(anyhow::anyhow!(
+            "Only custom pools can be removed"
+        )));
+    }
+    let was_selected = *ConfigPools::content().await.current_gpu_pool_type() == gpu_pool;
+    ConfigPools::update_field(ConfigPoolsContent::remove_gpu_pool, gpu_pool)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    let pools_config = ConfigPools::content().await;
+    EventsEmitter::emit_pools_config_loaded(&pools_config).await;
+    if was_selected {
+        GpuPoolManager::handle_new_selected_pool(pools_config.current_gpu_pool()).await;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_cpu_pool_priority(cpu_pools: Vec<String>) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_cpu_pool_priority] called with cpu_pools: {cpu_pools:?}");
+
+    let configured_pools = ConfigPools::content().await.cpu_pools().clone();
+    let priority = parse_pool_priority(&cpu_pools, |pool_name| {
+        let pool = CpuPool::from_string(pool_name)?;
+        if configured_pools.contains_key(&pool) {
+            Ok(pool)
+        } else {
+            Err(anyhow::anyhow!("CPU pool {pool_name} is not configured"))
+        }
+    })
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigPools::update_field(ConfigPoolsContent::set_cpu_pool_priority, priority)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_gpu_pool_priority(gpu_pools: Vec<String>) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_gpu_pool_priority] called with gpu_pools: {gpu_pools:?}");
+
+    let configured_pools = ConfigPools::content().await.gpu_pools().clone();
+    let priority = parse_pool_priority(&gpu_pools, |pool_name| {
+        let pool = GpuPool::from_string(pool_name)?;
+        if configured_pools.contains_key(&pool) {
+            Ok(pool)
+        } else {
+            Err(anyhow::anyhow!("GPU pool {pool_name} is not configured"))
+        }
+    })
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigPools::update_field(ConfigPoolsContent::set_gpu_pool_priority, priority)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_pool_failover_settings(settings: PoolFailoverSettings) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_pool_failover_settings] called with settings: {settings:?}");
+
+    if settings.failover_after_secs == 0 || settings.failback_probe_interval_secs == 0 {
+        return Err(InvokeError::from_anyhow(anyhow::anyhow!(
+            "Failover periods have to be greater than zero"
+        )));
+    }
+    ConfigPools::update_field(ConfigPoolsContent::set_pool_failover, settings)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_thermal_limits(settings: ThermalLimitsSettings) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET_APP_LOGIC, "[set_thermal_limits] called with settings: {settings:?}");
+
+    settings.validate().map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(ConfigMiningContent::set_thermal_limits, settings)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+    Ok(())
+}
+
+/// Automatic pool switches made since the app started, oldest first.
+/// The history is kept in memory only and starts empty after a restart.
+#[tauri::command]
+pub async fn get_pool_switch_history() -> Result<Vec<PoolSwitchRecord>, String> {
+    let mut history = CpuPoolManager::get_pool_switches().await;
+    history.extend(GpuPoolManager::get_pool_switches().await);
+    history.sort_by_key(|record| record.switched_at);
+    Ok(history)
+}
+
+#[tauri::command]
+pub async fn rank_pools_by_latency() -> Result<Vec<PoolLatencyReport>, String> {
+    let pools_config = ConfigPools::content().await;
+    let cpu_pools = pools_config.cpu_pools().values().map(|pool| {
+        (
+            PoolKind::Cpu,
+            pool.pool_type.key_string(),
+            pool.pool_name.clone(),
+            pool.pool_url.clone(),
+        )
+    });
+    let gpu_pools = pools_config.gpu_pools().values().map(|pool| {
+        (
+            PoolKind::Gpu,
+            pool.pool_type.key_string(),
+            pool.pool_name.clone(),
+            pool.pool_url.clone(),
+        )
+    });
+    let pools = cpu_pools.chain(gpu_pools).collect();
+    let login = InternalWallet::tari_address().await.to_base58();
+
+    Ok(stratum_probe::rank_pools_by_latency(pools, &login).await)
+}
+
 #[tauri::command]
 pub async fn update_selected_gpu_pool_config(
     updated_config: BasePoolData<GpuPool>,
@@ -1871,6 +2249,10 @@ pub async fn update_selected_gpu_pool_config(
     let timer = Instant::now();
     info!(target: LOG_TARGET_APP_LOGIC, "[update_selected_gpu_pool_config] called with updated_config: {updated_config:?}");
 
+    if updated_config.pool_type.is_custom() {
+        validate_custom_pool(&updated_config).map_err(InvokeError::from_anyhow)?;
+    }
+
     ConfigPools::update_field(
         ConfigPoolsContent::update_current_gpu_config,
         updated_config,
@@ -1892,6 +2274,10 @@ pub async fn update_selected_cpu_pool_config(
     let timer = Instant::now();
     info!(target: LOG_TARGET_APP_LOGIC, "[update_selected_cpu_pool_config] called with updated_config: {updated_config:?}");
 
+    if updated_config.pool_type == CpuPool::Custom {
+        validate_custom_pool(&updated_config).map_err(InvokeError::from_anyhow)?;
+    }
+
     ConfigPools::update_field(
         ConfigPoolsContent::update_current_cpu_config,
         updated_config,
@@ -1912,6 +2298,11 @@ pub async fn reset_gpu_pool_config(gpu_pool_type: String) -> Result<(), InvokeEr
     info!(target: LOG_TARGET_APP_LOGIC, "[reset_pool_gpu_pool_config] called with gpu_pool_name: {gpu_pool_type:?}");
 
     let gpu_pool = GpuPool::from_string(&gpu_pool_type).map_err(InvokeError::from_anyhow)?;
+    if gpu_pool.is_custom() {
+        return Err(InvokeError::from_anyhow(anyhow::anyhow!(
+            "Custom pools do not have a default configuration"
+        )));
+    }
 
     ConfigPools::update_field(
         ConfigPoolsContent::update_current_gpu_config,
@@ -1934,6 +2325,11 @@ pub async fn reset_cpu_pool_config(cpu_pool_type: String) -> Result<(), InvokeEr
     info!(target: LOG_TARGET_APP_LOGIC, "[reset_pool_cpu_pool_config] called with cpu_pool_name: {cpu_pool_type:?}");
 
     let cpu_pool = CpuPool::from_string(&cpu_pool_type).map_err(InvokeError::from_anyhow)?;
+    if cpu_pool == CpuPool::Custom {
+        return Err(InvokeError::from_anyhow(anyhow::anyhow!(
+            "Custom pools do not have a default configuration"
+        )));
+    }
 
     ConfigPools::update_field(
         ConfigPoolsContent::update_current_cpu_config,
@@ -2092,6 +2488,62 @@ pub async fn resume_scheduler_event(event_id: String) -> Result<(), String> {
     Ok(())
 }
 
+#[tauri::command]
+pub async fn get_scheduler_event_activations(
+    count: Option<usize>,
+) -> Result<Vec<EventActivations>, String> {
+    EventScheduler::instance()
+        .upcoming_activations(count.unwrap_or(5))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ================ Tariff Commands ==================
+#[tauri::command]
+pub async fn set_tariff_settings(
+    settings: TariffSettings,
+) -> Result<Vec<TariffBandEstimate>, String> {
+    info!(target: LOG_TARGET_APP_LOGIC, "set_tariff_settings called with settings: {settings:?}");
+
+    // Everything is checked before the settings are saved, so invalid settings never persist
+    check_mining_mode(
+        &settings.cheap_mining_mode,
+        ConfigMining::content().await.mining_modes().keys(),
+    )?;
+    let estimates = if settings.file_path.is_some() {
+        let schedule = tariff::plan_tariff_schedule(&settings).map_err(|e| e.to_string())?;
+        tariff::apply_tariff_schedule(schedule)
+            .await
+            .map_err(|e| e.to_string())?
+    } else {
+        tariff::clear_tariff_schedule()
+            .await
+            .map_err(|e| e.to_string())?;
+        Vec::new()
+    };
+
+    ConfigMining::update_field(ConfigMiningContent::set_tariff_settings, settings)
+        .await
+        .map_err(|e| e.to_string())?;
+    EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+    Ok(estimates)
+}
+
+#[tauri::command]
+pub async fn get_tariff_cost_estimates() -> Result<Vec<TariffBandEstimate>, String> {
+    let settings = ConfigMining::content().await.tariff_settings().clone();
+    tariff::estimate_tariff_costs(&settings).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub async fn clear_tariff_schedule() -> Result<(), String> {
+    info!(target: LOG_TARGET_APP_LOGIC, "clear_tariff_schedule called");
+
+    tariff::clear_tariff_schedule()
+        .await
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn refresh_wallet_history(
     state: tauri::State<'_, UniverseAppState>,
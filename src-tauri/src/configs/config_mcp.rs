@@ -22,6 +22,7 @@
 
 use super::trait_config::{ConfigContentImpl, ConfigImpl};
 use crate::LOG_TARGET_APP_LOGIC;
+use crate::mcp::stdio::STDIO_CLIENT_NAME;
 use base64::prelude::*;
 use getset::{Getters, Setters};
 use log::info;
@@ -34,6 +35,11 @@ use tokio::sync::RwLock;
 
 pub const MCP_CONFIG_VERSION: u32 = 0;
 pub(crate) const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+/// Client name reported for requests authenticated with the primary bearer token.
+pub(crate) const DEFAULT_CLIENT_NAME: &str = "default";
+pub(crate) const MCP_TIERS: [&str; 3] = ["read", "control", "transaction"];
+/// Longest spend window tracked; older records are pruned.
+pub(crate) const SPEND_HISTORY_WINDOW_SECS: u64 = 7 * SECONDS_PER_DAY;
 static INSTANCE: LazyLock<RwLock<ConfigMcp>> = LazyLock::new(|| RwLock::new(ConfigMcp::new()));
 
 pub(crate) mod token_cipher {
@@ -133,6 +139,53 @@ pub(crate) mod token_cipher {
     }
 }
 
+/// A named MCP client credential with its own scope.
+/// Only a SHA-256 hash of the token is persisted; the plaintext is returned once on creation.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct McpClientCredential {
+    pub name: String,
+    pub token_hash: String,
+    pub tiers: Vec<String>,
+    pub created_at: SystemTime,
+    pub expires_at: Option<SystemTime>,
+    pub rate_limit_per_minute: Option<u32>,
+    pub revoked: bool,
+}
+
+impl McpClientCredential {
+    pub fn is_active(&self, now: SystemTime) -> bool {
+        !self.revoked && self.expires_at.is_none_or(|expiry| now <= expiry)
+    }
+}
+
+/// An MCP transaction that counts against the rolling spend budget.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct McpSpendRecord {
+    pub timestamp: SystemTime,
+    pub amount: u64,
+}
+
+/// The client a request was authenticated as.
+#[derive(Clone, Debug)]
+pub struct McpClientIdentity {
+    pub name: String,
+    /// `None` for the primary bearer token, which is only bound by the global tier switches.
+    pub tiers: Option<Vec<String>>,
+    pub rate_limit_per_minute: Option<u32>,
+}
+
+impl McpClientIdentity {
+    pub fn is_default(&self) -> bool {
+        self.name == DEFAULT_CLIENT_NAME
+    }
+
+    pub fn has_tier(&self, tier: &str) -> bool {
+        self.tiers
+            .as_ref()
+            .is_none_or(|tiers| tiers.iter().any(|t| t == tier))
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "snake_case")]
 #[serde(default)]
@@ -157,6 +210,14 @@ pub struct ConfigMcpContent {
     read_tier_enabled: bool,
     control_tier_enabled: bool,
     rate_limit_transaction: u32,
+    clients: Vec<McpClientCredential>,
+    /// Cumulative µT MCP may send over any rolling 24 hours.
+    daily_spend_limit: Option<u64>,
+    /// Cumulative µT MCP may send over any rolling 7 days.
+    weekly_spend_limit: Option<u64>,
+    spend_history: Vec<McpSpendRecord>,
+    /// Only allow sends to addresses in the wallet's external address book.
+    destination_allowlist_enabled: bool,
 }
 
 impl Default for ConfigMcpContent {
@@ -175,6 +236,11 @@ impl Default for ConfigMcpContent {
             read_tier_enabled: true,
             control_tier_enabled: true,
             rate_limit_transaction: 5,
+            clients: Vec::new(),
+            daily_spend_limit: None,
+            weekly_spend_limit: None,
+            spend_history: Vec::new(),
+            destination_allowlist_enabled: false,
         }
     }
 }
@@ -233,6 +299,153 @@ impl ConfigMcpContent {
         }
     }
 
+    pub fn hash_token(token: &str) -> String {
+        hex::encode(ring::digest::digest(
+            &ring::digest::SHA256,
+            token.as_bytes(),
+        ))
+    }
+
+    /// Registers a new named client and returns its plaintext token.
+    pub fn add_client(
+        &mut self,
+        name: &str,
+        tiers: Vec<String>,
+        expiry_days: Option<u32>,
+        rate_limit_per_minute: Option<u32>,
+    ) -> Result<String, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Client name must not be empty".to_string());
+        }
+        // Both names already identify a connection in the audit log
+        if let Some(reserved) = [DEFAULT_CLIENT_NAME, STDIO_CLIENT_NAME]
+            .into_iter()
+            .find(|reserved| name.eq_ignore_ascii_case(reserved))
+        {
+            return Err(format!("Client name '{reserved}' is reserved"));
+        }
+        if self.clients.iter().any(|c| c.name == name) {
+            return Err(format!("A client named '{name}' already exists"));
+        }
+        let mut tiers: Vec<String> = tiers.iter().map(|t| t.to_lowercase()).collect();
+        tiers.sort();
+        tiers.dedup();
+        if tiers.is_empty() {
+            return Err("At least one tier must be granted".to_string());
+        }
+        if let Some(unknown) = tiers.iter().find(|t| !MCP_TIERS.contains(&t.as_str())) {
+            return Err(format!("Unknown tier: {unknown}"));
+        }
+        if rate_limit_per_minute == Some(0) {
+            return Err("Rate limit must be greater than zero".to_string());
+        }
+
+        let token = Self::generate_token();
+        let now = SystemTime::now();
+        self.clients.push(McpClientCredential {
+            name: name.to_string(),
+            token_hash: Self::hash_token(&token),
+            tiers,
+            created_at: now,
+            expires_at: expiry_days.map(|days| {
+                now + std::time::Duration::from_secs(u64::from(days) * SECONDS_PER_DAY)
+            }),
+            rate_limit_per_minute,
+            revoked: false,
+        });
+        Ok(token)
+    }
+
+    pub fn revoke_client(&mut self, name: &str) -> Result<(), String> {
+        let client = self
+            .clients
+            .iter_mut()
+            .find(|c| c.name == name)
+            .ok_or_else(|| format!("No client named '{name}'"))?;
+        client.revoked = true;
+        Ok(())
+    }
+
+    pub fn has_active_clients(&self) -> bool {
+        let now = SystemTime::now();
+        self.clients.iter().any(|c| c.is_active(now))
+    }
+
+    /// Resolves a bearer token to the client it was issued to.
+    /// Returns `None` for unknown, expired, or revoked tokens.
+    pub fn authenticate(&self, token: &str) -> Option<McpClientIdentity> {
+        let provided_hash = Self::hash_token(token);
+        if let Some(expected) = &self.bearer_token
+            && Self::hash_token(expected) == provided_hash
+        {
+            if self.is_token_expired() {
+                return None;
+            }
+            return Some(McpClientIdentity {
+                name: DEFAULT_CLIENT_NAME.to_string(),
+                tiers: None,
+                rate_limit_per_minute: None,
+            });
+        }
+
+        let now = SystemTime::now();
+        self.clients
+            .iter()
+            .find(|c| c.token_hash == provided_hash && c.is_active(now))
+            .map(|c| McpClientIdentity {
+                name: c.name.clone(),
+                tiers: Some(c.tiers.clone()),
+                rate_limit_per_minute: c.rate_limit_per_minute,
+            })
+    }
+
+    /// Total µT sent within `window` before `now`. Records stamped after `now` (clock changes)
+    /// are counted rather than ignored.
+    pub fn spent_within(&self, now: SystemTime, window: std::time::Duration) -> u64 {
+        self.spend_history
+            .iter()
+            .filter(|r| {
+                now.duration_since(r.timestamp)
+                    .ok()
+                    .is_none_or(|age| age <= window)
+            })
+            .fold(0u64, |spent, r| spent.saturating_add(r.amount))
+    }
+
+    /// Checks `amount` against the 24h and 7d budgets without recording it.
+    pub fn check_spend_budget(&self, amount: u64, now: SystemTime) -> Result<(), String> {
+        let windows = [
+            ("24h", self.daily_spend_limit, SECONDS_PER_DAY),
+            ("7d", self.weekly_spend_limit, SPEND_HISTORY_WINDOW_SECS),
+        ];
+        for (label, limit, window_secs) in windows {
+            let Some(limit) = limit else { continue };
+            let spent = self.spent_within(now, std::time::Duration::from_secs(window_secs));
+            if spent.saturating_add(amount) > limit {
+                return Err(format!(
+                    "Amount {amount} µT exceeds the {label} MCP spend budget: {spent} µT of {limit} µT already spent"
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Records a completed send and drops records older than the longest window.
+    pub fn record_spend(&mut self, amount: u64, now: SystemTime) -> &mut Self {
+        let window = std::time::Duration::from_secs(SPEND_HISTORY_WINDOW_SECS);
+        self.spend_history.retain(|r| {
+            now.duration_since(r.timestamp)
+                .ok()
+                .is_none_or(|age| age <= window)
+        });
+        self.spend_history.push(McpSpendRecord {
+            timestamp: now,
+            amount,
+        });
+        self
+    }
+
     /// Returns a redacted version of the token for safe display
     pub fn redacted_token(&self) -> Option<String> {
         self.bearer_token.as_ref().map(|t| {
@@ -256,6 +469,11 @@ impl ConfigMcpContent {
                 );
             }
             obj.remove("bearer_token");
+            if let Some(clients) = obj.get_mut("clients").and_then(|v| v.as_array_mut()) {
+                for client in clients.iter_mut().filter_map(|c| c.as_object_mut()) {
+                    client.remove("token_hash");
+                }
+            }
         }
         Ok(value)
     }
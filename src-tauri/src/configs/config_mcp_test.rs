@@ -308,3 +308,211 @@ fn config_serialization_with_token() {
 
     assert_eq!(config.bearer_token(), deserialized.bearer_token());
 }
+
+// =============================================================================
+// Named clients
+// =============================================================================
+
+#[test]
+fn default_config_has_no_clients() {
+    let config = ConfigMcpContent::default();
+    assert!(config.clients().is_empty());
+    assert!(!config.has_active_clients());
+}
+
+#[test]
+fn add_client_returns_token_that_authenticates() {
+    let mut config = ConfigMcpContent::default();
+    let token = config
+        .add_client("dashboard", vec!["read".to_string()], None, Some(30))
+        .unwrap();
+    assert!(token.starts_with("tu_"));
+
+    let identity = config.authenticate(&token).unwrap();
+    assert_eq!(identity.name, "dashboard");
+    assert_eq!(identity.rate_limit_per_minute, Some(30));
+    assert!(identity.has_tier("read"));
+    assert!(!identity.has_tier("control"));
+    assert!(!identity.is_default());
+}
+
+#[test]
+fn add_client_stores_only_token_hash() {
+    let mut config = ConfigMcpContent::default();
+    let token = config
+        .add_client("dashboard", vec!["read".to_string()], None, None)
+        .unwrap();
+    let stored = &config.clients()[0];
+    assert_ne!(stored.token_hash, token);
+    assert_eq!(stored.token_hash, ConfigMcpContent::hash_token(&token));
+}
+
+#[test]
+fn add_client_rejects_duplicate_and_reserved_names() {
+    let mut config = ConfigMcpContent::default();
+    config
+        .add_client("automation", vec!["control".to_string()], None, None)
+        .unwrap();
+    assert!(
+        config
+            .add_client("automation", vec!["read".to_string()], None, None)
+            .is_err()
+    );
+    assert!(
+        config
+            .add_client("default", vec!["read".to_string()], None, None)
+            .is_err()
+    );
+    assert!(
+        config
+            .add_client("  ", vec!["read".to_string()], None, None)
+            .is_err()
+    );
+}
+
+#[test]
+fn add_client_rejects_the_stdio_bridge_name() {
+    let mut config = ConfigMcpContent::default();
+    assert_eq!(
+        config
+            .add_client("Stdio", vec!["read".to_string()], None, None)
+            .unwrap_err(),
+        "Client name 'stdio' is reserved"
+    );
+    assert!(config.clients().is_empty());
+}
+
+#[test]
+fn add_client_rejects_unknown_or_empty_tiers() {
+    let mut config = ConfigMcpContent::default();
+    assert!(config.add_client("a", vec![], None, None).is_err());
+    assert!(
+        config
+            .add_client("a", vec!["admin".to_string()], None, None)
+            .is_err()
+    );
+}
+
+#[test]
+fn revoked_client_no_longer_authenticates() {
+    let mut config = ConfigMcpContent::default();
+    let token = config
+        .add_client("dashboard", vec!["read".to_string()], None, None)
+        .unwrap();
+    config.revoke_client("dashboard").unwrap();
+    assert!(config.authenticate(&token).is_none());
+    assert!(!config.has_active_clients());
+    assert!(config.revoke_client("missing").is_err());
+}
+
+#[test]
+fn expired_client_no_longer_authenticates() {
+    let mut config = ConfigMcpContent::default();
+    let token = config
+        .add_client("dashboard", vec!["read".to_string()], Some(0), None)
+        .unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    assert!(config.authenticate(&token).is_none());
+}
+
+#[test]
+fn primary_token_authenticates_as_default_client() {
+    let mut config = ConfigMcpContent::default();
+    let token = config.ensure_token().to_string();
+    let identity = config.authenticate(&token).unwrap();
+    assert!(identity.is_default());
+    assert!(identity.has_tier("transaction"));
+    assert!(config.authenticate("tu_unknown").is_none());
+}
+
+#[test]
+fn redacted_value_strips_client_token_hashes() {
+    ensure_test_identity();
+    let mut config = ConfigMcpContent::default();
+    config
+        .add_client("dashboard", vec!["read".to_string()], None, None)
+        .unwrap();
+    let value = config.to_redacted_value().unwrap();
+    let client = &value["clients"][0];
+    assert_eq!(client["name"], "dashboard");
+    assert!(client.get("token_hash").is_none());
+}
+
+// =============================================================================
+// Spend budget
+// =============================================================================
+
+const HOUR: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+#[test]
+fn spend_budget_is_unlimited_by_default() {
+    let mut config = ConfigMcpContent::default();
+    let now = std::time::SystemTime::now();
+    config.record_spend(u64::MAX / 2, now);
+    assert!(config.check_spend_budget(u64::MAX / 2, now).is_ok());
+}
+
+#[test]
+fn daily_budget_counts_sends_within_24h() {
+    let mut config = ConfigMcpContent::default();
+    config.set_daily_spend_limit(Some(1_000_000));
+    let now = std::time::SystemTime::now();
+    config.record_spend(400_000, now - 2 * HOUR);
+    config.record_spend(400_000, now - HOUR);
+
+    assert!(config.check_spend_budget(200_000, now).is_ok());
+    let err = config.check_spend_budget(200_001, now).unwrap_err();
+    assert!(err.contains("24h"));
+    assert!(err.contains("800000 µT"));
+}
+
+#[test]
+fn daily_budget_ignores_sends_older_than_24h() {
+    let mut config = ConfigMcpContent::default();
+    config.set_daily_spend_limit(Some(1_000_000));
+    let now = std::time::SystemTime::now();
+    config.record_spend(1_000_000, now - 25 * HOUR);
+
+    assert!(config.check_spend_budget(1_000_000, now).is_ok());
+}
+
+#[test]
+fn weekly_budget_spans_several_days() {
+    let mut config = ConfigMcpContent::default();
+    config.set_daily_spend_limit(Some(1_000_000));
+    config.set_weekly_spend_limit(Some(2_000_000));
+    let now = std::time::SystemTime::now();
+    config.record_spend(900_000, now - 72 * HOUR);
+    config.record_spend(900_000, now - 48 * HOUR);
+
+    let err = config.check_spend_budget(300_000, now).unwrap_err();
+    assert!(err.contains("7d"));
+    assert!(config.check_spend_budget(200_000, now).is_ok());
+}
+
+#[test]
+fn spent_within_saturates_instead_of_overflowing() {
+    let mut config = ConfigMcpContent::default();
+    config.set_daily_spend_limit(Some(u64::MAX));
+    let now = std::time::SystemTime::now();
+    config.record_spend(u64::MAX, now - HOUR);
+    config.record_spend(u64::MAX, now);
+
+    assert_eq!(
+        config.spent_within(now, std::time::Duration::from_secs(24 * 60 * 60)),
+        u64::MAX
+    );
+    assert!(config.check_spend_budget(1, now).is_err());
+}
+
+#[test]
+fn record_spend_prunes_records_older_than_a_week() {
+    let mut config = ConfigMcpContent::default();
+    let now = std::time::SystemTime::now();
+    config.record_spend(1, now - 8 * 24 * HOUR);
+    config.record_spend(2, now - 24 * HOUR);
+    config.record_spend(3, now);
+
+    let amounts: Vec<u64> = config.spend_history().iter().map(|r| r.amount).collect();
+    assert_eq!(amounts, vec![2, 3]);
+}
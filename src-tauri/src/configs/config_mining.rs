@@ -23,6 +23,12 @@
 use super::trait_config::{ConfigContentImpl, ConfigImpl};
 use crate::LOG_TARGET_APP_LOGIC;
 use crate::events_emitter::EventsEmitter;
+use crate::mining::cpu::benchmark::CpuThreadBenchmark;
+use crate::mining::cpu::consts::CpuMinerType;
+use crate::mining::gpu::benchmark::GpuMinersBenchmark;
+use crate::mining::gpu::consts::GpuMinerType;
+use crate::mining::thermal::{ThermalLimitsSettings, apply_throttle};
+use crate::tariff::TariffSettings;
 use getset::{Getters, Setters};
 use log::{info, warn};
 use serde::{Deserialize, Serialize};
@@ -31,7 +37,7 @@ use std::{collections::HashMap, fmt::Display, sync::LazyLock, time::SystemTime};
 use tauri::AppHandle;
 use tokio::sync::RwLock;
 
-pub const MINING_CONFIG_VERSION: u32 = 2;
+pub const MINING_CONFIG_VERSION: u32 = 3;
 static INSTANCE: LazyLock<RwLock<ConfigMining>> =
     LazyLock::new(|| RwLock::new(ConfigMining::new()));
 
@@ -88,12 +94,20 @@ pub struct MiningMode {
     pub gpu_usage_percentage: u32,
 }
 
-#[derive(Serialize, Deserialize, Clone, Default)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
 pub struct GpuDeviceSettings {
-    device_id: u32,
-    is_excluded: bool,
+    pub device_id: u32,
+    pub is_excluded: bool,
+    /// Overrides the gpu usage of the selected mining mode for this device
+    pub intensity_percentage: Option<u32>,
+    /// Power draw cap in watts
+    pub power_limit_watts: Option<u32>,
+    /// Temperature in celsius above which the device stops mining
+    pub max_temperature: Option<u32>,
 }
-#[derive(Serialize, Deserialize, Clone)]
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
 pub struct GpuDevicesSettings(HashMap<u32, GpuDeviceSettings>);
 
 impl GpuDevicesSettings {
@@ -104,14 +118,83 @@ impl GpuDevicesSettings {
     pub fn add(&mut self, device_id: u32) {
         self.0.entry(device_id).or_insert(GpuDeviceSettings {
             device_id,
-            is_excluded: false,
+            ..Default::default()
         });
     }
+    /// Fails for devices the miners did not detect, overrides for them would be ignored
+    pub fn ensure_device(&self, device_id: u32) -> Result<(), anyhow::Error> {
+        if self.0.contains_key(&device_id) {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Unknown GPU device {device_id}"))
+        }
+    }
     pub fn set_excluded(&mut self, device_id: u32, is_excluded: bool) {
         if let Some(settings) = self.0.get_mut(&device_id) {
             settings.is_excluded = is_excluded;
         }
     }
+    pub fn set_intensity(&mut self, device_id: u32, intensity_percentage: Option<u32>) {
+        if let Some(settings) = self.0.get_mut(&device_id) {
+            settings.intensity_percentage = intensity_percentage;
+        }
+    }
+    pub fn set_limits(
+        &mut self,
+        device_id: u32,
+        power_limit_watts: Option<u32>,
+        max_temperature: Option<u32>,
+    ) {
+        if let Some(settings) = self.0.get_mut(&device_id) {
+            settings.power_limit_watts = power_limit_watts;
+            settings.max_temperature = max_temperature;
+        }
+    }
+
+    /// Values of one per device setting in the order of `device_ids`, ready to be joined into a miner argument
+    /// Returns None when none of the devices overrides the setting, so the argument can be left out
+    pub fn per_device_values(
+        &self,
+        device_ids: &[u32],
+        value: impl Fn(&GpuDeviceSettings) -> Option<u32>,
+    ) -> Option<Vec<Option<u32>>> {
+        let values: Vec<Option<u32>> = device_ids
+            .iter()
+            .map(|device_id| self.0.get(device_id).and_then(&value))
+            .collect();
+
+        values.iter().any(Option::is_some).then_some(values)
+    }
+
+    /// Scales the per device intensity overrides while the thermal throttle is active
+    pub fn throttle_intensity(&mut self, throttle_percentage: u32) {
+        for settings in self.0.values_mut() {
+            settings.intensity_percentage = settings
+                .intensity_percentage
+                .map(|intensity| apply_throttle(intensity, throttle_percentage));
+        }
+    }
+
+    /// Scales the per device power limits while the thermal throttle is active, for miners that
+    /// can only be slowed down through their power limit
+    pub fn throttle_power_limits(&mut self, throttle_percentage: u32) {
+        for settings in self.0.values_mut() {
+            settings.power_limit_watts = settings
+                .power_limit_watts
+                .map(|power_limit| apply_throttle(power_limit, throttle_percentage));
+        }
+    }
+
+    /// Whether each of the mining devices has a power limit the thermal throttle can lower
+    /// A device without one would keep running at full power while the others are throttled
+    pub fn have_power_limits(&self, device_ids: &[u32]) -> bool {
+        !device_ids.is_empty()
+            && device_ids.iter().all(|device_id| {
+                self.0
+                    .get(device_id)
+                    .is_some_and(|settings| settings.power_limit_watts.is_some())
+            })
+    }
 }
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum PauseOnBatteryModeState {
@@ -151,6 +234,12 @@ pub struct ConfigMiningContent {
 
     eco_alert_needed: bool,
     mode_mining_times: HashMap<String, Duration>, // we only need Eco for now, but we can add to this if needed
+    tariff_settings: TariffSettings,
+    selected_cpu_miner: CpuMinerType,
+    selected_gpu_miner: GpuMinerType,
+    cpu_thread_benchmark: Option<CpuThreadBenchmark>,
+    gpu_miner_benchmark: Option<GpuMinersBenchmark>,
+    thermal_limits: ThermalLimitsSettings,
 }
 
 impl Default for ConfigMiningContent {
@@ -207,6 +296,12 @@ impl Default for ConfigMiningContent {
             is_gpu_mining_recommended: true,
             eco_alert_needed: true,
             mode_mining_times: HashMap::from([("Eco".to_string(), Duration::new(0, 0))]),
+            tariff_settings: TariffSettings::default(),
+            selected_cpu_miner: CpuMinerType::default(),
+            selected_gpu_miner: GpuMinerType::default(),
+            cpu_thread_benchmark: None,
+            gpu_miner_benchmark: None,
+            thermal_limits: ThermalLimitsSettings::default(),
         }
     }
 }
@@ -246,6 +341,24 @@ impl ConfigMiningContent {
         self
     }
 
+    pub fn set_gpu_device_intensity(
+        &mut self,
+        (device_id, intensity_percentage): (u32, Option<u32>),
+    ) -> &mut Self {
+        self.gpu_devices_settings
+            .set_intensity(device_id, intensity_percentage);
+        self
+    }
+
+    pub fn set_gpu_device_limits(
+        &mut self,
+        (device_id, power_limit_watts, max_temperature): (u32, Option<u32>, Option<u32>),
+    ) -> &mut Self {
+        self.gpu_devices_settings
+            .set_limits(device_id, power_limit_watts, max_temperature);
+        self
+    }
+
     pub fn get_selected_cpu_usage_percentage(&self) -> u32 {
         match self.mining_modes.get(&self.selected_mining_mode) {
             Some(mode) => mode.cpu_usage_percentage,
@@ -256,6 +369,17 @@ impl ConfigMiningContent {
         }
     }
 
+    /// Benchmarked thread count of the selected mode, None for modes outside the thread map
+    /// or when the benchmark ran with a different number of available threads
+    pub fn get_selected_benchmarked_cpu_threads(&self, available_threads: u32) -> Option<u32> {
+        self.cpu_thread_benchmark
+            .as_ref()
+            .filter(|benchmark| benchmark.available_threads == available_threads)?
+            .mode_threads
+            .get(&self.selected_mining_mode)
+            .copied()
+    }
+
     pub fn get_excluded_devices(&self) -> Vec<u32> {
         self.gpu_devices_settings
             .0
@@ -367,6 +491,11 @@ impl ConfigMining {
         // since they're removed from the struct. GPU mining disable is handled at runtime
         // via is_supported_on_current_platform() check in phase_gpu_mining.rs
 
+        // v2 -> v3 migration (per device GPU settings)
+        // Note: legacy gpu_devices_settings entries only hold device_id and is_excluded. The new overrides
+        // are deserialized as unset thanks to serde(default), so every device keeps following the selected
+        // mining mode. The file is rewritten in the new format when the version counter is bumped
+
         Ok(())
     }
 
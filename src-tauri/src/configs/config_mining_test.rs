@@ -0,0 +1,151 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::config_mining::{ConfigMiningContent, GpuDevicesSettings};
+
+#[test]
+fn legacy_gpu_devices_settings_load_without_overrides() {
+    let legacy = r#"{
+        "0": { "device_id": 0, "is_excluded": false },
+        "1": { "device_id": 1, "is_excluded": true }
+    }"#;
+
+    let settings: GpuDevicesSettings = serde_json::from_str(legacy).unwrap();
+
+    assert_eq!(
+        settings.per_device_values(&[0, 1], |s| s.intensity_percentage),
+        None
+    );
+    assert_eq!(
+        settings.per_device_values(&[0, 1], |s| s.power_limit_watts),
+        None
+    );
+    assert_eq!(
+        settings.per_device_values(&[0, 1], |s| s.max_temperature),
+        None
+    );
+}
+
+#[test]
+fn per_device_values_follow_the_requested_device_order() {
+    let mut config = ConfigMiningContent::default();
+    config.populate_gpu_devices_settings(vec![0, 1, 2]);
+    config.set_gpu_device_intensity((2, Some(30)));
+    config.set_gpu_device_intensity((0, Some(100)));
+
+    let values = config
+        .gpu_devices_settings()
+        .per_device_values(&[2, 1, 0], |s| s.intensity_percentage);
+
+    assert_eq!(values, Some(vec![Some(30), None, Some(100)]));
+}
+
+#[test]
+fn device_limits_are_stored_per_device_and_can_be_cleared() {
+    let mut config = ConfigMiningContent::default();
+    config.populate_gpu_devices_settings(vec![0, 1]);
+    config.set_gpu_device_limits((1, Some(150), Some(75)));
+
+    let settings = config.gpu_devices_settings();
+    assert_eq!(
+        settings.per_device_values(&[0, 1], |s| s.power_limit_watts),
+        Some(vec![None, Some(150)])
+    );
+    assert_eq!(
+        settings.per_device_values(&[0, 1], |s| s.max_temperature),
+        Some(vec![None, Some(75)])
+    );
+
+    config.set_gpu_device_limits((1, None, None));
+    assert_eq!(
+        config
+            .gpu_devices_settings()
+            .per_device_values(&[0, 1], |s| s.power_limit_watts),
+        None
+    );
+}
+
+#[test]
+fn overrides_for_unknown_devices_are_ignored() {
+    let mut config = ConfigMiningContent::default();
+    config.populate_gpu_devices_settings(vec![0]);
+    config.set_gpu_device_intensity((5, Some(50)));
+
+    assert_eq!(
+        config
+            .gpu_devices_settings()
+            .per_device_values(&[0, 5], |s| s.intensity_percentage),
+        None
+    );
+}
+
+#[test]
+fn ensure_device_rejects_devices_that_were_not_detected() {
+    let mut config = ConfigMiningContent::default();
+    config.populate_gpu_devices_settings(vec![0, 1]);
+
+    let settings = config.gpu_devices_settings();
+    assert!(settings.ensure_device(1).is_ok());
+    assert_eq!(
+        settings.ensure_device(5).unwrap_err().to_string(),
+        "Unknown GPU device 5"
+    );
+}
+
+#[test]
+fn throttle_intensity_scales_only_the_overridden_devices() {
+    let mut config = ConfigMiningContent::default();
+    config.populate_gpu_devices_settings(vec![0, 1]);
+    config.set_gpu_device_intensity((1, Some(80)));
+
+    let mut devices_settings = config.gpu_devices_settings().clone();
+    devices_settings.throttle_intensity(50);
+    assert_eq!(
+        devices_settings.per_device_values(&[0, 1], |s| s.intensity_percentage),
+        Some(vec![None, Some(40)])
+    );
+}
+
+#[test]
+fn throttle_power_limits_scales_the_limits_of_mining_devices() {
+    let mut config = ConfigMiningContent::default();
+    config.populate_gpu_devices_settings(vec![0, 1, 2]);
+    assert!(!config.gpu_devices_settings().have_power_limits(&[0, 1]));
+
+    config.set_gpu_device_limits((1, Some(200), Some(80)));
+    config.set_gpu_device_limits((2, Some(150), None));
+    config.enable_gpu_device_exclusion(2);
+    assert!(!config.gpu_devices_settings().have_power_limits(&[0, 1]));
+    assert!(config.gpu_devices_settings().have_power_limits(&[1]));
+
+    let mut devices_settings = config.gpu_devices_settings().clone();
+    devices_settings.throttle_power_limits(50);
+    assert_eq!(
+        devices_settings.per_device_values(&[0, 1], |s| s.power_limit_watts),
+        Some(vec![None, Some(100)])
+    );
+    assert_eq!(
+        devices_settings.per_device_values(&[1], |s| s.max_temperature),
+        Some(vec![Some(80)])
+    );
+    assert!(!config.gpu_devices_settings().have_power_limits(&[]));
+}
@@ -28,12 +28,15 @@ use tokio::sync::RwLock;
 
 use crate::{
     configs::pools::{BasePoolData, cpu_pools::CpuPool, gpu_pools::GpuPool},
-    mining::pools::{cpu_pool_manager::CpuPoolManager, gpu_pool_manager::GpuPoolManager},
+    mining::pools::{
+        cpu_pool_manager::CpuPoolManager, failover::PoolFailoverSettings,
+        gpu_pool_manager::GpuPoolManager,
+    },
 };
 
 use super::trait_config::{ConfigContentImpl, ConfigImpl};
 
-pub const POOLS_CONFIG_VERSION: u32 = 1;
+pub const POOLS_CONFIG_VERSION: u32 = 2;
 static INSTANCE: LazyLock<RwLock<ConfigPools>> = LazyLock::new(|| RwLock::new(ConfigPools::new()));
 
 #[allow(clippy::struct_excessive_bools)]
@@ -54,6 +57,8 @@ pub struct ConfigPoolsContent {
     current_gpu_pool: GpuPool,
     #[getset(get = "pub", set = "pub")]
     gpu_pools: HashMap<GpuPool, BasePoolData<GpuPool>>,
+    #[getset(get = "pub", set = "pub")]
+    gpu_pool_priority: Vec<GpuPool>,
     // ======= Cpu Pool =======
     #[getset(get = "pub", set = "pub")]
     cpu_pool_enabled: bool,
@@ -61,6 +66,11 @@ pub struct ConfigPoolsContent {
     current_cpu_pool: CpuPool,
     #[getset(get = "pub", set = "pub")]
     cpu_pools: HashMap<CpuPool, BasePoolData<CpuPool>>,
+    #[getset(get = "pub", set = "pub")]
+    cpu_pool_priority: Vec<CpuPool>,
+    // ======= Failover =======
+    #[getset(get = "pub", set = "pub")]
+    pool_failover: PoolFailoverSettings,
 }
 
 impl Default for ConfigPoolsContent {
@@ -73,10 +83,14 @@ impl Default for ConfigPoolsContent {
             gpu_pool_enabled: true,
             current_gpu_pool: GpuPool::default(),
             gpu_pools: GpuPool::load_default_pools_data(),
+            gpu_pool_priority: GpuPool::default_priority(),
             // ======= Cpu Pool =======
             cpu_pool_enabled: true,
             current_cpu_pool: CpuPool::default(),
             cpu_pools: CpuPool::load_default_pools_data(),
+            cpu_pool_priority: CpuPool::default_priority(),
+            // ======= Failover =======
+            pool_failover: PoolFailoverSettings::default(),
         }
     }
 }
@@ -96,6 +110,70 @@ impl ConfigPoolsContent {
             .unwrap_or_else(|| CpuPool::default().default_content())
     }
 
+    pub fn current_cpu_pool_type(&self) -> &CpuPool {
+        &self.current_cpu_pool
+    }
+
+    pub fn current_gpu_pool_type(&self) -> &GpuPool {
+        &self.current_gpu_pool
+    }
+
+    /// Selects the pool and moves it to the top of the failover priority list
+    pub fn promote_cpu_pool(&mut self, pool: CpuPool) -> &mut Self {
+        self.cpu_pool_priority.retain(|entry| *entry != pool);
+        self.cpu_pool_priority.insert(0, pool.clone());
+        self.current_cpu_pool = pool;
+        self
+    }
+
+    /// Selects the pool and moves it to the top of the failover priority list
+    pub fn promote_gpu_pool(&mut self, pool: GpuPool) -> &mut Self {
+        self.gpu_pool_priority.retain(|entry| *entry != pool);
+        self.gpu_pool_priority.insert(0, pool.clone());
+        self.current_gpu_pool = pool;
+        self
+    }
+
+    /// Adds or replaces a pool definition, new pools are appended to the failover priority list
+    pub fn upsert_cpu_pool(&mut self, pool_data: BasePoolData<CpuPool>) -> &mut Self {
+        if !self.cpu_pool_priority.contains(&pool_data.pool_type) {
+            self.cpu_pool_priority.push(pool_data.pool_type.clone());
+        }
+        self.cpu_pools
+            .insert(pool_data.pool_type.clone(), pool_data);
+        self
+    }
+
+    /// Adds or replaces a pool definition, new pools are appended to the failover priority list
+    pub fn upsert_gpu_pool(&mut self, pool_data: BasePoolData<GpuPool>) -> &mut Self {
+        if !self.gpu_pool_priority.contains(&pool_data.pool_type) {
+            self.gpu_pool_priority.push(pool_data.pool_type.clone());
+        }
+        self.gpu_pools
+            .insert(pool_data.pool_type.clone(), pool_data);
+        self
+    }
+
+    /// Removes a pool definition, the default pool is selected if the removed one was in use
+    pub fn remove_cpu_pool(&mut self, pool: CpuPool) -> &mut Self {
+        self.cpu_pools.remove(&pool);
+        self.cpu_pool_priority.retain(|entry| *entry != pool);
+        if self.current_cpu_pool == pool {
+            self.current_cpu_pool = CpuPool::default();
+        }
+        self
+    }
+
+    /// Removes a pool definition, the default pool is selected if the removed one was in use
+    pub fn remove_gpu_pool(&mut self, pool: GpuPool) -> &mut Self {
+        self.gpu_pools.remove(&pool);
+        self.gpu_pool_priority.retain(|entry| *entry != pool);
+        if self.current_gpu_pool == pool {
+            self.current_gpu_pool = GpuPool::default();
+        }
+        self
+    }
+
     pub fn update_current_cpu_config(
         &mut self,
         updated_config: BasePoolData<CpuPool>,
@@ -161,11 +239,42 @@ impl ConfigPools {
             )
             .await?;
 
-            // Update the pools map to only include C29 pools
-            let gpu_pools = GpuPool::load_default_pools_data();
+            // Update the pools map to only include C29 pools, user defined pools are kept
+            let mut gpu_pools = GpuPool::load_default_pools_data();
+            for (pool, pool_data) in Self::content().await.gpu_pools() {
+                if pool.is_custom() {
+                    gpu_pools.insert(pool.clone(), pool_data.clone());
+                }
+            }
             Self::update_field(ConfigPoolsContent::set_gpu_pools, gpu_pools).await?;
         }
 
+        // v1 -> v2 migration (ordered pool lists for failover)
+        if current_version < 2 {
+            // Keep the pool the user had selected as the primary one
+            let content = Self::content().await;
+            Self::update_field(
+                ConfigPoolsContent::set_cpu_pool_priority,
+                CpuPool::default_priority(),
+            )
+            .await?;
+            Self::update_field(
+                ConfigPoolsContent::promote_cpu_pool,
+                content.current_cpu_pool.clone(),
+            )
+            .await?;
+            Self::update_field(
+                ConfigPoolsContent::set_gpu_pool_priority,
+                GpuPool::default_priority(),
+            )
+            .await?;
+            Self::update_field(
+                ConfigPoolsContent::promote_gpu_pool,
+                content.current_gpu_pool.clone(),
+            )
+            .await?;
+        }
+
         Ok(())
     }
 }
@@ -25,6 +25,8 @@ pub mod config_mcp;
 #[cfg(test)]
 mod config_mcp_test;
 pub mod config_mining;
+#[cfg(test)]
+mod config_mining_test;
 pub mod config_pools;
 pub mod config_ui;
 #[cfg(test)]
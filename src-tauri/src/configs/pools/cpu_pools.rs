@@ -25,7 +25,7 @@ use std::{collections::HashMap, fmt::Display, sync::LazyLock};
 use serde::{Deserialize, Serialize};
 use tari_common::configuration::Network;
 
-use crate::configs::pools::{BasePoolData, PoolOrigin};
+use crate::configs::pools::{BasePoolData, PoolOrigin, custom_pools::unconfigured_custom_pool};
 
 fn global_tari_cpu_mining_pool_url() -> String {
     match Network::get_current_or_user_setting_or_default() {
@@ -56,6 +56,7 @@ static DEFAULT_CPU_SUPPORTXTM_RANDOMX: LazyLock<BasePoolData<CpuPool>> =
         stats_url: global_tari_cpu_mining_pool_status_url(),
         pool_type: CpuPool::SupportXTMPoolRANDOMX,
         pool_origin: PoolOrigin::SupportXTM,
+        custom: None,
     });
 
 static DEFAULT_CPU_LUCKYPOOL_RANDOMX: LazyLock<BasePoolData<CpuPool>> =
@@ -66,6 +67,7 @@ static DEFAULT_CPU_LUCKYPOOL_RANDOMX: LazyLock<BasePoolData<CpuPool>> =
             .to_string(),
         pool_type: CpuPool::LuckyPoolRANDOMX,
         pool_origin: PoolOrigin::LuckyPool,
+        custom: None,
     });
 
 static DEFAULT_CPU_KRYPTEX_RANDOMX: LazyLock<BasePoolData<CpuPool>> =
@@ -76,6 +78,7 @@ static DEFAULT_CPU_KRYPTEX_RANDOMX: LazyLock<BasePoolData<CpuPool>> =
             .to_string(),
         pool_type: CpuPool::KryptexPoolRANDOMX,
         pool_origin: PoolOrigin::Kryptex,
+        custom: None,
     });
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
@@ -84,6 +87,8 @@ pub enum CpuPool {
     #[default]
     LuckyPoolRANDOMX,
     KryptexPoolRANDOMX,
+    /// User defined pool, see `CustomPoolConfig`
+    Custom,
 }
 
 impl Display for CpuPool {
@@ -92,6 +97,7 @@ impl Display for CpuPool {
             CpuPool::SupportXTMPoolRANDOMX => "SupportXTMPoolRANDOMX",
             CpuPool::LuckyPoolRANDOMX => "LuckyPoolRANDOMX",
             CpuPool::KryptexPoolRANDOMX => "KryptexPoolRANDOMX",
+            CpuPool::Custom => "Custom",
         };
         write!(f, "{name}")
     }
@@ -103,6 +109,7 @@ impl CpuPool {
             "SupportXTMPoolRANDOMX" => Ok(CpuPool::SupportXTMPoolRANDOMX),
             "LuckyPoolRANDOMX" => Ok(CpuPool::LuckyPoolRANDOMX),
             "KryptexPoolRANDOMX" => Ok(CpuPool::KryptexPoolRANDOMX),
+            "Custom" => Ok(CpuPool::Custom),
             _ => Err(anyhow::anyhow!("Invalid CPU pool name")),
         }
     }
@@ -112,6 +119,7 @@ impl CpuPool {
             CpuPool::SupportXTMPoolRANDOMX => "SupportXTMPoolRANDOMX".to_string(),
             CpuPool::LuckyPoolRANDOMX => "LuckyPoolRANDOMX".to_string(),
             CpuPool::KryptexPoolRANDOMX => "KryptexPoolRANDOMX".to_string(),
+            CpuPool::Custom => "Custom".to_string(),
         }
     }
 
@@ -120,6 +128,7 @@ impl CpuPool {
             CpuPool::SupportXTMPoolRANDOMX => DEFAULT_CPU_SUPPORTXTM_RANDOMX.clone(),
             CpuPool::LuckyPoolRANDOMX => DEFAULT_CPU_LUCKYPOOL_RANDOMX.clone(),
             CpuPool::KryptexPoolRANDOMX => DEFAULT_CPU_KRYPTEX_RANDOMX.clone(),
+            CpuPool::Custom => unconfigured_custom_pool(CpuPool::Custom),
         }
     }
 
@@ -134,4 +143,13 @@ impl CpuPool {
         cpu_pools.insert(KryptexPoolRANDOMX, DEFAULT_CPU_KRYPTEX_RANDOMX.clone());
         cpu_pools
     }
+
+    /// Failover order used until the user sets their own, starting with the default pool
+    pub fn default_priority() -> Vec<Self> {
+        vec![
+            CpuPool::LuckyPoolRANDOMX,
+            CpuPool::KryptexPoolRANDOMX,
+            CpuPool::SupportXTMPoolRANDOMX,
+        ]
+    }
 }
@@ -0,0 +1,183 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use serde::{Deserialize, Serialize};
+
+use crate::configs::pools::{BasePoolData, PoolOrigin};
+
+/// Replaced in the worker name template with the name the built in pools use
+const WORKER_NAME_PLACEHOLDER: &str = "%WORKER_NAME%";
+const DEFAULT_WORKER_NAME: &str = "Tari-universe";
+const DEFAULT_CUSTOM_POOL_NAME: &str = "Custom pool";
+const MAX_WORKER_NAME_TEMPLATE_LENGTH: usize = 64;
+const SUPPORTED_STRATUM_SCHEMES: &[&str] = &["stratum+tcp", "stratum+ssl"];
+
+/// JSON pointers (RFC 6901) into the stats endpoint response, fields without a pointer are reported as zero
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct PoolStatsPointers {
+    pub accepted_shares: Option<String>,
+    pub unpaid: Option<String>,
+    pub balance: Option<String>,
+    pub min_payout: Option<String>,
+}
+
+impl PoolStatsPointers {
+    fn iter(&self) -> impl Iterator<Item = &String> {
+        [
+            &self.accepted_shares,
+            &self.unpaid,
+            &self.balance,
+            &self.min_payout,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct CustomPoolSettings {
+    /// Appended to the mining address, `%WORKER_NAME%` is replaced with `Tari-universe`
+    pub worker_name_template: String,
+    pub stats_pointers: PoolStatsPointers,
+}
+
+impl CustomPoolSettings {
+    pub fn worker_name(&self) -> Option<String> {
+        let worker_name = self
+            .worker_name_template
+            .replace(WORKER_NAME_PLACEHOLDER, DEFAULT_WORKER_NAME);
+        (!worker_name.is_empty()).then_some(worker_name)
+    }
+}
+
+/// Custom pool definition as entered by the user
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct CustomPoolConfig {
+    pub pool_name: Option<String>,
+    /// `host:port` with an optional `stratum+tcp://` or `stratum+ssl://` scheme
+    pub pool_url: String,
+    /// Optional http(s) endpoint for pool stats, `%TARI_ADDRESS%` is replaced with the mining address
+    pub stats_url: Option<String>,
+    pub worker_name_template: String,
+    pub stats_pointers: PoolStatsPointers,
+}
+
+impl CustomPoolConfig {
+    pub fn into_pool_data<T>(self, pool_type: T) -> Result<BasePoolData<T>, anyhow::Error> {
+        let pool_name = self
+            .pool_name
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| DEFAULT_CUSTOM_POOL_NAME.to_string());
+
+        let pool_data = BasePoolData {
+            pool_name,
+            pool_url: self.pool_url.trim().to_string(),
+            stats_url: self.stats_url.unwrap_or_default().trim().to_string(),
+            pool_type,
+            pool_origin: PoolOrigin::Custom,
+            custom: Some(CustomPoolSettings {
+                worker_name_template: self.worker_name_template.trim().to_string(),
+                stats_pointers: self.stats_pointers,
+            }),
+        };
+        validate_custom_pool(&pool_data)?;
+        Ok(pool_data)
+    }
+}
+
+/// Placeholder used for custom pools that were not configured yet, it does not pass validation
+pub fn unconfigured_custom_pool<T>(pool_type: T) -> BasePoolData<T> {
+    BasePoolData {
+        pool_name: DEFAULT_CUSTOM_POOL_NAME.to_string(),
+        pool_url: String::new(),
+        stats_url: String::new(),
+        pool_type,
+        pool_origin: PoolOrigin::Custom,
+        custom: Some(CustomPoolSettings::default()),
+    }
+}
+
+pub fn validate_custom_pool<T>(pool: &BasePoolData<T>) -> Result<(), anyhow::Error> {
+    let Some(custom) = &pool.custom else {
+        return Err(anyhow::anyhow!("Custom pool settings are missing"));
+    };
+
+    validate_stratum_url(&pool.pool_url)?;
+
+    if !pool.stats_url.is_empty()
+        && !pool.stats_url.starts_with("http://")
+        && !pool.stats_url.starts_with("https://")
+    {
+        return Err(anyhow::anyhow!("Stats endpoint has to be an http(s) url"));
+    }
+
+    let template = &custom.worker_name_template;
+    if template.len() > MAX_WORKER_NAME_TEMPLATE_LENGTH {
+        return Err(anyhow::anyhow!(
+            "Worker name template can not be longer than {MAX_WORKER_NAME_TEMPLATE_LENGTH} characters"
+        ));
+    }
+    if template.chars().any(char::is_whitespace) {
+        return Err(anyhow::anyhow!(
+            "Worker name template can not contain whitespace"
+        ));
+    }
+
+    // An empty pointer selects the whole response, which is never a number
+    if let Some(pointer) = custom
+        .stats_pointers
+        .iter()
+        .find(|pointer| !pointer.starts_with('/'))
+    {
+        return Err(anyhow::anyhow!(
+            "Invalid JSON pointer {pointer:?}, it has to start with '/'"
+        ));
+    }
+    Ok(())
+}
+
+fn validate_stratum_url(pool_url: &str) -> Result<(), anyhow::Error> {
+    let address = match pool_url.split_once("://") {
+        Some((scheme, address)) => {
+            if !SUPPORTED_STRATUM_SCHEMES.contains(&scheme) {
+                return Err(anyhow::anyhow!("Unsupported pool url scheme {scheme}"));
+            }
+            address
+        }
+        None => pool_url,
+    };
+
+    let Some((host, port)) = address.rsplit_once(':') else {
+        return Err(anyhow::anyhow!("Pool url has to be in host:port format"));
+    };
+    if host.is_empty() || host.contains(['/', ' ']) {
+        return Err(anyhow::anyhow!("Invalid pool host {host}"));
+    }
+    match port.parse::<u16>() {
+        Ok(port) if port > 0 => Ok(()),
+        _ => Err(anyhow::anyhow!("Invalid pool port {port}")),
+    }
+}
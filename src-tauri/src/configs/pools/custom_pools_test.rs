@@ -0,0 +1,144 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::configs::pools::{
+    PoolOrigin,
+    cpu_pools::CpuPool,
+    custom_pools::{CustomPoolConfig, PoolStatsPointers, validate_custom_pool},
+    gpu_pools::GpuPool,
+};
+use crate::mining::gpu::consts::{GpuMinerType, GpuMiningAlgorithm};
+
+fn custom_pool_config() -> CustomPoolConfig {
+    CustomPoolConfig {
+        pool_name: Some("  Team pool ".to_string()),
+        pool_url: "stratum+ssl://pool.example.com:4444".to_string(),
+        stats_url: Some("https://pool.example.com/api/%TARI_ADDRESS%".to_string()),
+        worker_name_template: ".%WORKER_NAME%-rig".to_string(),
+        stats_pointers: PoolStatsPointers {
+            unpaid: Some("/stats/unpaid".to_string()),
+            ..Default::default()
+        },
+    }
+}
+
+#[test]
+fn custom_pool_config_builds_pool_data() {
+    let pool = custom_pool_config()
+        .into_pool_data(CpuPool::Custom)
+        .unwrap();
+
+    assert_eq!(pool.pool_name, "Team pool");
+    assert_eq!(pool.pool_type, CpuPool::Custom);
+    assert!(matches!(pool.pool_origin, PoolOrigin::Custom));
+    assert_eq!(pool.worker_name(), Some(".Tari-universe-rig".to_string()));
+}
+
+#[test]
+fn custom_pool_without_template_has_no_worker_name() {
+    let config = CustomPoolConfig {
+        worker_name_template: String::new(),
+        ..custom_pool_config()
+    };
+    let pool = config.into_pool_data(CpuPool::Custom).unwrap();
+
+    assert_eq!(pool.worker_name(), None);
+}
+
+#[test]
+fn custom_pool_rejects_invalid_urls() {
+    for pool_url in [
+        "",
+        "pool.example.com",
+        "pool.example.com:0",
+        "pool.example.com:port",
+        "http://pool.example.com:4444",
+        ":4444",
+    ] {
+        let config = CustomPoolConfig {
+            pool_url: pool_url.to_string(),
+            ..custom_pool_config()
+        };
+        assert!(
+            config.into_pool_data(CpuPool::Custom).is_err(),
+            "{pool_url} should be rejected"
+        );
+    }
+
+    let config = CustomPoolConfig {
+        stats_url: Some("ftp://pool.example.com/stats".to_string()),
+        ..custom_pool_config()
+    };
+    assert!(config.into_pool_data(CpuPool::Custom).is_err());
+}
+
+#[test]
+fn custom_pool_rejects_invalid_template_and_pointers() {
+    let config = CustomPoolConfig {
+        worker_name_template: ".my rig".to_string(),
+        ..custom_pool_config()
+    };
+    assert!(config.into_pool_data(CpuPool::Custom).is_err());
+
+    let config = CustomPoolConfig {
+        stats_pointers: PoolStatsPointers {
+            balance: Some("stats.balance".to_string()),
+            ..Default::default()
+        },
+        ..custom_pool_config()
+    };
+    assert!(config.into_pool_data(CpuPool::Custom).is_err());
+
+    let config = CustomPoolConfig {
+        stats_pointers: PoolStatsPointers {
+            unpaid: Some(String::new()),
+            ..Default::default()
+        },
+        ..custom_pool_config()
+    };
+    assert!(config.into_pool_data(CpuPool::Custom).is_err());
+}
+
+#[test]
+fn unconfigured_custom_pool_is_invalid() {
+    assert!(validate_custom_pool(&CpuPool::Custom.default_content()).is_err());
+    assert!(validate_custom_pool(&CpuPool::LuckyPoolRANDOMX.default_content()).is_err());
+}
+
+#[test]
+fn custom_gpu_pools_are_kept_apart_per_algorithm() {
+    for miner in [GpuMinerType::LolMiner, GpuMinerType::Glytex] {
+        let custom_pool = GpuPool::custom_for(&miner.main_algorithm());
+        assert!(custom_pool.is_custom());
+        assert!(miner.is_pool_supported(&custom_pool));
+    }
+    assert_ne!(
+        GpuPool::custom_for(&GpuMiningAlgorithm::C29),
+        GpuPool::custom_for(&GpuMiningAlgorithm::Sha3x)
+    );
+    assert!(!GpuMinerType::Glytex.is_pool_supported(&GpuPool::Custom));
+    assert!(!GpuMinerType::LolMiner.is_pool_supported(&GpuPool::CustomSha3x));
+    assert_eq!(
+        GpuPool::from_string(&GpuPool::CustomSha3x.key_string()).unwrap(),
+        GpuPool::CustomSha3x
+    );
+}
@@ -24,7 +24,10 @@ use std::{collections::HashMap, fmt::Display, sync::LazyLock};
 
 use serde::{Deserialize, Serialize};
 
-use crate::configs::pools::{BasePoolData, PoolOrigin};
+use crate::{
+    configs::pools::{BasePoolData, PoolOrigin, custom_pools::unconfigured_custom_pool},
+    mining::gpu::consts::GpuMiningAlgorithm,
+};
 
 static DEFAULT_GPU_LUCKYPOOL_C29: LazyLock<BasePoolData<GpuPool>> =
     LazyLock::new(|| BasePoolData {
@@ -34,6 +37,7 @@ static DEFAULT_GPU_LUCKYPOOL_C29: LazyLock<BasePoolData<GpuPool>> =
             .to_string(),
         pool_type: GpuPool::LuckyPoolC29,
         pool_origin: PoolOrigin::LuckyPool,
+        custom: None,
     });
 
 static DEFAULT_GPU_KRYPTEX_C29: LazyLock<BasePoolData<GpuPool>> = LazyLock::new(|| BasePoolData {
@@ -42,6 +46,7 @@ static DEFAULT_GPU_KRYPTEX_C29: LazyLock<BasePoolData<GpuPool>> = LazyLock::new(
     stats_url: "https://pool.kryptex.com/xtm-c29/api/v1/miner/balance/%TARI_ADDRESS%".to_string(),
     pool_type: GpuPool::KryptexPoolC29,
     pool_origin: PoolOrigin::Kryptex,
+    custom: None,
 });
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
@@ -49,6 +54,10 @@ pub enum GpuPool {
     #[default]
     LuckyPoolC29,
     KryptexPoolC29,
+    /// User defined C29 pool, see `CustomPoolConfig`
+    Custom,
+    /// User defined SHA3X pool, kept apart from the C29 one so shares never go to a pool for the other algorithm
+    CustomSha3x,
 }
 
 impl Display for GpuPool {
@@ -56,6 +65,8 @@ impl Display for GpuPool {
         let name = match self {
             GpuPool::LuckyPoolC29 => "LuckyPoolC29",
             GpuPool::KryptexPoolC29 => "KryptexPoolC29",
+            GpuPool::Custom => "Custom",
+            GpuPool::CustomSha3x => "CustomSha3x",
         };
         write!(f, "{name}")
     }
@@ -66,6 +77,8 @@ impl GpuPool {
         match pool_name {
             "LuckyPoolC29" => Ok(GpuPool::LuckyPoolC29),
             "KryptexPoolC29" => Ok(GpuPool::KryptexPoolC29),
+            "Custom" => Ok(GpuPool::Custom),
+            "CustomSha3x" => Ok(GpuPool::CustomSha3x),
             // Backward compatibility: map old SHA3X pool names to C29 equivalents
             "LuckyPoolSHA3X" => Ok(GpuPool::LuckyPoolC29),
             "KryptexPoolSHA3X" => Ok(GpuPool::KryptexPoolC29),
@@ -78,6 +91,8 @@ impl GpuPool {
         match self {
             GpuPool::LuckyPoolC29 => "LuckyPoolC29".to_string(),
             GpuPool::KryptexPoolC29 => "KryptexPoolC29".to_string(),
+            GpuPool::Custom => "Custom".to_string(),
+            GpuPool::CustomSha3x => "CustomSha3x".to_string(),
         }
     }
 
@@ -85,9 +100,23 @@ impl GpuPool {
         match self {
             GpuPool::LuckyPoolC29 => DEFAULT_GPU_LUCKYPOOL_C29.clone(),
             GpuPool::KryptexPoolC29 => DEFAULT_GPU_KRYPTEX_C29.clone(),
+            GpuPool::Custom => unconfigured_custom_pool(GpuPool::Custom),
+            GpuPool::CustomSha3x => unconfigured_custom_pool(GpuPool::CustomSha3x),
         }
     }
 
+    /// The user defined pool for miners of the given algorithm
+    pub fn custom_for(algorithm: &GpuMiningAlgorithm) -> Self {
+        match algorithm {
+            GpuMiningAlgorithm::C29 => GpuPool::Custom,
+            GpuMiningAlgorithm::Sha3x => GpuPool::CustomSha3x,
+        }
+    }
+
+    pub fn is_custom(&self) -> bool {
+        matches!(self, GpuPool::Custom | GpuPool::CustomSha3x)
+    }
+
     pub fn load_default_pools_data() -> HashMap<Self, BasePoolData<GpuPool>> {
         use GpuPool::*;
         let mut gpu_pools = HashMap::new();
@@ -95,4 +124,9 @@ impl GpuPool {
         gpu_pools.insert(KryptexPoolC29, DEFAULT_GPU_KRYPTEX_C29.clone());
         gpu_pools
     }
+
+    /// Failover order used until the user sets their own, starting with the default pool
+    pub fn default_priority() -> Vec<Self> {
+        vec![GpuPool::LuckyPoolC29, GpuPool::KryptexPoolC29]
+    }
 }
@@ -22,7 +22,12 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::configs::pools::custom_pools::CustomPoolSettings;
+
 pub mod cpu_pools;
+pub mod custom_pools;
+#[cfg(test)]
+mod custom_pools_test;
 pub mod gpu_pools;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +35,7 @@ pub enum PoolOrigin {
     SupportXTM,
     LuckyPool,
     Kryptex,
+    Custom,
 }
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BasePoolData<T> {
@@ -38,4 +44,26 @@ pub struct BasePoolData<T> {
     pub stats_url: String,
     pub pool_type: T,
     pub pool_origin: PoolOrigin,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom: Option<CustomPoolSettings>,
+}
+
+impl<T> BasePoolData<T> {
+    /// Suffix appended to the mining address when logging in to the pool
+    pub fn worker_name(&self) -> Option<String> {
+        // Worker name format depends on the pool
+        // LuckyPool: .Tari-Universe
+        // Kryptex: /Tari-Universe
+        // SupportXTM: Not specified so we use None
+        // Custom: user defined template
+        match self.pool_origin {
+            PoolOrigin::LuckyPool => Some(".Tari-universe".to_string()),
+            PoolOrigin::SupportXTM => None,
+            PoolOrigin::Kryptex => Some("/Tari-universe".to_string()),
+            PoolOrigin::Custom => self
+                .custom
+                .as_ref()
+                .and_then(CustomPoolSettings::worker_name),
+        }
+    }
 }
@@ -45,9 +45,11 @@
 //!
 //! ## Key parts
 //!
-//! - `CronSchedule`: Handles recurring time windows with cron expressions
+//! - `CronSchedule`: Handles recurring time windows with cron expressions, optionally
+//!   limited to certain weekdays and a yearly date range
 //! - `SchedulerEventTiming`: Defines when events should trigger (In/Between patterns)
-//! - `SchedulerEventType`: Defines what actions to perform (ResumeMining/Mine)
+//! - `SchedulerEventType`: Defines what actions to perform (ResumeMining/Mine, or toggling
+//!   CPU/GPU mining, pools and the node type for the duration of a window)
 //! - Persistent storage: Your recurring events are saved and restored automatically
 //!
 //! ## Basic usage
@@ -71,7 +73,7 @@
 
 #![allow(dead_code, unused_variables, unused_must_use)]
 
-use chrono::{DateTime, Duration, Local};
+use chrono::{DateTime, Datelike, Duration, Local, NaiveDate};
 use croner::{self, Cron, parser::CronParser};
 use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
@@ -79,28 +81,48 @@ use std::{
     collections::HashMap,
     fmt::Display,
     sync::{
-        LazyLock,
+        Arc, LazyLock,
         atomic::{AtomicBool, AtomicU64},
     },
 };
+use tauri::{Manager, ipc::InvokeError};
 use tokio::{
     sync::{RwLock, mpsc},
     time::sleep,
 };
 
 use crate::{
-    LOG_TARGET_APP_LOGIC,
+    LOG_TARGET_APP_LOGIC, UniverseAppState, commands,
     configs::{
         config_core::{ConfigCore, ConfigCoreContent},
         config_mining::{ConfigMining, ConfigMiningContent},
+        config_pools::ConfigPools,
         trait_config::ConfigImpl,
     },
     events_emitter::EventsEmitter,
-    mining::{cpu::manager::CpuManager, gpu::manager::GpuManager},
+    mcp::tools::pools,
+    mining::{cpu::manager::CpuManager, gpu::manager::GpuManager, is_benchmark_running},
+    node::node_manager::NodeType,
     tasks_tracker::TasksTrackers,
+    utils::system_status::SystemStatus,
 };
 
 static ZERO_DURATION: std::time::Duration = std::time::Duration::from_secs(0);
+/// Upper bound on cron lookups when skipping occurrences outside a date range.
+const MAX_CALENDAR_SEARCH_STEPS: usize = 32;
+/// How many catch-up records to keep per event.
+const MAX_CATCH_UP_RECORDS: usize = 20;
+/// How many execution journal entries to keep per event.
+const MAX_JOURNAL_ENTRIES: usize = 50;
+/// Upper bound on how many activations `upcoming_activations` computes per event.
+pub const MAX_UPCOMING_ACTIVATIONS: usize = 100;
+/// How often to compare the wall clock against the monotonic clock.
+const CLOCK_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+/// Wall clock drift (relative to the monotonic clock) treated as a jump.
+const CLOCK_JUMP_THRESHOLD_SECS: i64 = 60;
+/// How far ahead to look for overlapping windows. A little over a year covers
+/// every combination of weekday set and yearly date range.
+const OVERLAP_HORIZON_DAYS: i64 = 400;
 
 static INSTANCE: LazyLock<EventScheduler> = LazyLock::new(EventScheduler::new);
 static EVENT_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
@@ -113,6 +135,7 @@ enum SchedulerMessage {
         event_type: SchedulerEventType,
         timing: SchedulerEventTiming,
         event_id: String,
+        priority: u32,
         response: tokio::sync::oneshot::Sender<Result<String, SchedulerError>>,
     },
     RemoveEvent {
@@ -136,9 +159,165 @@ enum SchedulerMessage {
     ListEvents {
         response: tokio::sync::oneshot::Sender<Vec<ScheduledEventInfo>>,
     },
+    UpcomingActivations {
+        count: usize,
+        response: tokio::sync::oneshot::Sender<Vec<EventActivations>>,
+    },
     CleanupSchedule {
         event_id: String,
     },
+    /// Re-evaluate every window against the current time
+    Reconcile {
+        reason: CatchUpReason,
+    },
+}
+
+/// Source of the current wall clock time. The scheduler takes its time from
+/// here so tests can control it.
+pub trait SchedulerClock: Send + Sync {
+    fn now(&self) -> DateTime<Local>;
+}
+
+/// The real system clock.
+pub struct SystemClock;
+
+impl SchedulerClock for SystemClock {
+    fn now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+}
+
+/// Detects wall clock jumps (DST, NTP corrections, manual changes, suspend)
+/// by comparing how far the wall clock moved against the monotonic clock.
+pub struct ClockJumpDetector {
+    clock: Arc<dyn SchedulerClock>,
+    last_wall: DateTime<Local>,
+    last_monotonic: std::time::Instant,
+}
+
+impl ClockJumpDetector {
+    pub fn new(clock: Arc<dyn SchedulerClock>, monotonic_now: std::time::Instant) -> Self {
+        Self {
+            last_wall: clock.now(),
+            clock,
+            last_monotonic: monotonic_now,
+        }
+    }
+
+    /// Takes a new reading and reports how far the wall clock jumped since the
+    /// previous one.
+    ///
+    /// ### Returns
+    /// * `Some(Duration)` - Wall clock drift beyond the threshold (negative for backwards jumps)
+    /// * `None` - Both clocks moved together
+    pub fn check(&mut self, monotonic_now: std::time::Instant) -> Option<Duration> {
+        let wall_now = self.clock.now();
+        let wall_elapsed = wall_now - self.last_wall;
+        let monotonic_elapsed =
+            Duration::from_std(monotonic_now.saturating_duration_since(self.last_monotonic))
+                .unwrap_or_default();
+        self.last_wall = wall_now;
+        self.last_monotonic = monotonic_now;
+
+        let drift = wall_elapsed - monotonic_elapsed;
+        (drift.num_seconds().abs() >= CLOCK_JUMP_THRESHOLD_SECS).then_some(drift)
+    }
+}
+
+/// Why the scheduler re-evaluated its windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CatchUpReason {
+    /// The app started, possibly in the middle of a window
+    Startup,
+    /// The system woke from sleep
+    Wake,
+    /// The wall clock jumped
+    ClockJump,
+    /// A miner benchmark held back the callbacks that were due while it ran
+    Benchmark,
+}
+
+/// What the scheduler did to bring an event in line with the current time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CatchUpAction {
+    /// Ran the enter callback for a window that should be open (or an overdue one-time event)
+    Enter,
+    /// Ran the exit callback for a window that should be closed
+    Exit,
+}
+
+/// A catch-up action taken for an event.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatchUpRecord {
+    /// Unix timestamp (seconds) when the catch-up ran
+    pub at: i64,
+    pub action: CatchUpAction,
+    pub reason: CatchUpReason,
+}
+
+/// Which side of a window a callback ran for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExecutionPhase {
+    /// The window opened (or a one-time event fired)
+    Enter,
+    /// The window closed
+    Exit,
+}
+
+/// One entry of an event's execution journal.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionRecord {
+    /// Unix timestamp (seconds) when the callback ran
+    pub triggered_at: i64,
+    pub phase: ExecutionPhase,
+    /// What the scheduler did, e.g. "Mining in Eco mode"
+    pub action: String,
+    /// Set when the callback ran to catch up with the clock rather than on its timer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catch_up: Option<CatchUpReason>,
+    /// Why the action failed; `None` means it succeeded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Upcoming window starts and ends of an event, as Unix timestamps (seconds).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventActivations {
+    pub event_id: String,
+    pub starts: Vec<i64>,
+    /// One-time events have no ends
+    pub ends: Vec<i64>,
+}
+
+/// Works out which recurring windows are out of step with the current time.
+///
+/// An event counts as entered while its state is `Triggered`. Windows that are
+/// open but not entered need an `Enter`; entered windows that have closed need
+/// an `Exit`. Exits come first so Mine windows fall back correctly. Paused events
+/// and one-time events are left alone.
+pub fn plan_catch_up(
+    events: &[ScheduledEventInfo],
+    clock: &dyn SchedulerClock,
+) -> Vec<(String, CatchUpAction)> {
+    let now = clock.now();
+    let mut plan: Vec<(String, CatchUpAction)> = events
+        .iter()
+        .filter(|event| event.state != SchedulerEventState::Paused)
+        .filter_map(|event| {
+            let SchedulerEventTiming::Between(payload) = &event.timing else {
+                return None;
+            };
+            let is_open = payload.to_cron_schedule().ok()?.is_active_at(now);
+            let is_entered = event.state == SchedulerEventState::Triggered;
+            match (is_entered, is_open) {
+                (false, true) => Some((event.id.clone(), CatchUpAction::Enter)),
+                (true, false) => Some((event.id.clone(), CatchUpAction::Exit)),
+                _ => None,
+            }
+        })
+        .collect();
+    plan.sort_by_key(|(id, action)| (*action != CatchUpAction::Exit, id.clone()));
+    plan
 }
 
 #[derive(Debug, Clone)]
@@ -157,6 +336,10 @@ pub enum SchedulerError {
     InternalError(String),
     /// The timing format provided is invalid
     InvalidTimingFormat(String),
+    /// The event's window overlaps an existing event that controls the same setting
+    OverlappingEvent(String),
+    /// The event's action ran but did not complete
+    ActionFailed(String),
 }
 
 impl Display for SchedulerError {
@@ -169,6 +352,10 @@ impl Display for SchedulerError {
             Self::SchedulerNotRunning => write!(f, "Scheduler is not running"),
             Self::InternalError(msg) => write!(f, "Internal error: {}", msg),
             Self::InvalidTimingFormat(format) => write!(f, "Invalid timing format: {}", format),
+            Self::OverlappingEvent(id) => {
+                write!(f, "Event window overlaps existing event: {:?}", id)
+            }
+            Self::ActionFailed(msg) => write!(f, "Scheduled action failed: {}", msg),
         }
     }
 }
@@ -182,6 +369,8 @@ pub struct CronSchedule {
     pub start_time: Cron,
     pub end_time: Cron,
     pub description: String,
+    /// Restricts the schedule to windows that start inside this yearly date range.
+    pub date_range: Option<ScheduleDateRange>,
 }
 
 impl<'de> Deserialize<'de> for CronSchedule {
@@ -193,10 +382,19 @@ impl<'de> Deserialize<'de> for CronSchedule {
         struct CronScheduleHelper {
             start_time: String,
             end_time: String,
+            #[serde(default)]
+            date_range: Option<ScheduleDateRange>,
         }
 
         let helper = CronScheduleHelper::deserialize(deserializer)?;
-        CronSchedule::new(&helper.start_time, &helper.end_time).map_err(serde::de::Error::custom)
+        let schedule = CronSchedule::new(&helper.start_time, &helper.end_time)
+            .map_err(serde::de::Error::custom)?;
+        match helper.date_range {
+            Some(range) => schedule
+                .with_date_range(range)
+                .map_err(serde::de::Error::custom),
+            None => Ok(schedule),
+        }
     }
 }
 
@@ -209,11 +407,14 @@ impl Serialize for CronSchedule {
         struct CronScheduleHelper<'a> {
             start_time: &'a str,
             end_time: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            date_range: &'a Option<ScheduleDateRange>,
         }
 
         let helper = CronScheduleHelper {
             start_time: &self.start_time.to_string(),
             end_time: &self.end_time.to_string(),
+            date_range: &self.date_range,
         };
         helper.serialize(serializer)
     }
@@ -249,9 +450,25 @@ impl CronSchedule {
             start_time: start_time_cron,
             end_time: end_time_cron,
             description: format!("From {} to {}", start_time, end_time),
+            date_range: None,
         })
     }
 
+    /// Limits the schedule to windows that start within a yearly date range.
+    ///
+    /// ### Returns
+    /// * `Ok(CronSchedule)` - Schedule restricted to the range
+    /// * `Err(SchedulerError::InvalidTimingFormat)` - If the range has an invalid month or day
+    pub fn with_date_range(
+        mut self,
+        date_range: ScheduleDateRange,
+    ) -> Result<Self, SchedulerError> {
+        date_range.validate()?;
+        self.description = format!("{} ({})", self.description, date_range);
+        self.date_range = Some(date_range);
+        Ok(self)
+    }
+
     /// Finds when this schedule starts next.
     ///
     /// ### Parameters
@@ -261,7 +478,28 @@ impl CronSchedule {
     /// * `Some(DateTime<Local>)` - When it starts next
     /// * `None` - If it never starts again
     pub fn find_next_start_time(&self, from: DateTime<Local>) -> Option<DateTime<Local>> {
-        self.start_time.find_next_occurrence(&from, false).ok()
+        let mut cursor = from;
+        let mut inclusive = false;
+        for _ in 0..MAX_CALENDAR_SEARCH_STEPS {
+            let next = self
+                .start_time
+                .find_next_occurrence(&cursor, inclusive)
+                .ok()?;
+            let Some(date_range) = &self.date_range else {
+                return Some(next);
+            };
+            if date_range.contains(next.date_naive()) {
+                return Some(next);
+            }
+            // Jump straight to the first day of the next range instead of walking day by day
+            cursor = date_range
+                .next_start_after(next.date_naive())?
+                .and_hms_opt(0, 0, 0)?
+                .and_local_timezone(Local)
+                .earliest()?;
+            inclusive = true;
+        }
+        None
     }
 
     /// How long to wait until this schedule starts next.
@@ -283,6 +521,9 @@ impl CronSchedule {
 
     /// Finds the next occurrence of the end time from a given moment.
     ///
+    /// With a date range, only ends of windows that started inside the range count,
+    /// so a window that begins on the last day of the range still runs to its end.
+    ///
     /// ### Parameters
     /// * `from` - The reference time to search from
     ///
@@ -290,7 +531,91 @@ impl CronSchedule {
     /// * `Some(DateTime<Local>)` - The next end time occurrence
     /// * `None` - If no future occurrence can be found
     pub fn find_next_end_time(&self, from: DateTime<Local>) -> Option<DateTime<Local>> {
-        self.end_time.find_next_occurrence(&from, false).ok()
+        let mut cursor = from;
+        for _ in 0..MAX_CALENDAR_SEARCH_STEPS {
+            let next_end = self.end_time.find_next_occurrence(&cursor, false).ok()?;
+            let Some(date_range) = &self.date_range else {
+                return Some(next_end);
+            };
+            if self
+                .window_start_for_end(next_end)
+                .is_some_and(|window_start| date_range.contains(window_start.date_naive()))
+            {
+                return Some(next_end);
+            }
+            cursor = self.find_next_start_time(next_end)?;
+        }
+        None
+    }
+
+    /// Lists the next `count` start times after `from`.
+    pub fn next_start_times(&self, from: DateTime<Local>, count: usize) -> Vec<DateTime<Local>> {
+        std::iter::successors(self.find_next_start_time(from), |last| {
+            self.find_next_start_time(*last)
+        })
+        .take(count)
+        .collect()
+    }
+
+    /// Lists the next `count` end times after `from`, including the end of a
+    /// window that is open at `from`.
+    pub fn next_end_times(&self, from: DateTime<Local>, count: usize) -> Vec<DateTime<Local>> {
+        std::iter::successors(self.find_next_end_time(from), |last| {
+            self.find_next_end_time(*last)
+        })
+        .take(count)
+        .collect()
+    }
+
+    /// Finds when the window closing at `end` opened.
+    fn window_start_for_end(&self, end: DateTime<Local>) -> Option<DateTime<Local>> {
+        // Windows are at most a day long, so the one closing at `end` started within the previous 24h
+        self.start_time
+            .find_next_occurrence(&(end - Duration::days(1)), true)
+            .ok()
+            .filter(|start| *start < end)
+    }
+
+    /// Checks whether a window is open at the given moment. Windows include
+    /// their start time but not their end time.
+    pub fn is_active_at(&self, at: DateTime<Local>) -> bool {
+        self.find_next_end_time(at)
+            .and_then(|end| self.window_start_for_end(end))
+            .is_some_and(|start| start <= at)
+    }
+
+    /// Checks whether any window of this schedule overlaps a window of `other`
+    /// between `from` and `until`.
+    pub fn overlaps_within(
+        &self,
+        other: &CronSchedule,
+        from: DateTime<Local>,
+        until: DateTime<Local>,
+    ) -> bool {
+        if self.is_active_at(from) && other.is_active_at(from) {
+            return true;
+        }
+        // Two windows overlap exactly when one of them opens while the other is open
+        self.any_start_while_active(other, from, until)
+            || other.any_start_while_active(self, from, until)
+    }
+
+    fn any_start_while_active(
+        &self,
+        other: &CronSchedule,
+        from: DateTime<Local>,
+        until: DateTime<Local>,
+    ) -> bool {
+        let mut cursor = from;
+        while let Some(start) = self.find_next_start_time(cursor)
+            && start < until
+        {
+            if other.is_active_at(start) {
+                return true;
+            }
+            cursor = start;
+        }
+        false
     }
 
     /// Calculates how long to wait until the next end time from a given moment.
@@ -324,6 +649,131 @@ pub enum TimePeriod {
     PM,
 }
 
+/// A day of the week a recurring window may start on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+pub enum DayOfWeek {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl DayOfWeek {
+    /// Day number used in the cron day-of-week field (Sunday = 0).
+    fn cron_value(self) -> u32 {
+        match self {
+            DayOfWeek::Sunday => 0,
+            DayOfWeek::Monday => 1,
+            DayOfWeek::Tuesday => 2,
+            DayOfWeek::Wednesday => 3,
+            DayOfWeek::Thursday => 4,
+            DayOfWeek::Friday => 5,
+            DayOfWeek::Saturday => 6,
+        }
+    }
+
+    /// Parses a day name, full or abbreviated, in any case (e.g. "Monday", "mon").
+    pub fn parse(day: &str) -> Option<Self> {
+        match day.trim().to_lowercase().as_str() {
+            "mon" | "monday" => Some(DayOfWeek::Monday),
+            "tue" | "tuesday" => Some(DayOfWeek::Tuesday),
+            "wed" | "wednesday" => Some(DayOfWeek::Wednesday),
+            "thu" | "thursday" => Some(DayOfWeek::Thursday),
+            "fri" | "friday" => Some(DayOfWeek::Friday),
+            "sat" | "saturday" => Some(DayOfWeek::Saturday),
+            "sun" | "sunday" => Some(DayOfWeek::Sunday),
+            _ => None,
+        }
+    }
+
+    /// Builds the cron day-of-week field for a set of days, shifted forward by
+    /// `offset_days`. An empty set means every day.
+    fn cron_field(days: &[DayOfWeek], offset_days: u32) -> String {
+        if days.is_empty() {
+            return "*".to_string();
+        }
+        let mut values: Vec<u32> = days
+            .iter()
+            .map(|day| (day.cron_value() + offset_days) % 7)
+            .collect();
+        values.sort_unstable();
+        values.dedup();
+        values
+            .iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// A yearly, inclusive range of calendar days, e.g. December 1st to December 31st.
+/// The range may wrap around the new year (e.g. December 15th to January 15th).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ScheduleDateRange {
+    pub start_month: u32,
+    pub start_day: u32,
+    pub end_month: u32,
+    pub end_day: u32,
+}
+
+impl ScheduleDateRange {
+    /// Checks that both ends are real calendar days (February 29th is allowed).
+    pub fn validate(&self) -> Result<(), SchedulerError> {
+        for (month, day) in [
+            (self.start_month, self.start_day),
+            (self.end_month, self.end_day),
+        ] {
+            // 2024 is a leap year, so every possible month/day combination exists in it
+            if NaiveDate::from_ymd_opt(2024, month, day).is_none() {
+                return Err(SchedulerError::InvalidTimingFormat(format!(
+                    "Invalid date range day: month {} day {}",
+                    month, day
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks whether the given date falls inside the range.
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        let day = (date.month(), date.day());
+        let start = (self.start_month, self.start_day);
+        let end = (self.end_month, self.end_day);
+        if start <= end {
+            start <= day && day <= end
+        } else {
+            day >= start || day <= end
+        }
+    }
+
+    /// Finds the first day of the range strictly after the given date.
+    fn next_start_after(&self, date: NaiveDate) -> Option<NaiveDate> {
+        // A February 29th start can be up to eight years away
+        (date.year()..=date.year() + 8)
+            .filter_map(|year| NaiveDate::from_ymd_opt(year, self.start_month, self.start_day))
+            .find(|start| *start > date)
+    }
+}
+
+impl Display for ScheduleDateRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:02}-{:02} to {:02}-{:02}",
+            self.start_month, self.start_day, self.end_month, self.end_day
+        )
+    }
+}
+
+/// A recurring daily time window, optionally limited to certain weekdays and dates.
+///
+/// A window belongs to the day it starts on. When the end time is at or before the
+/// start time the window crosses midnight and runs until the end time on the next day,
+/// even if that next day is not in `weekdays` or `date_range`. For example, a
+/// Friday-only 22:00-07:00 window runs from Friday 22:00 until Saturday 07:00.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BetweenTimeVariantPayload {
     pub start_hour: i64,
@@ -332,6 +782,12 @@ pub struct BetweenTimeVariantPayload {
     pub end_hour: i64,
     pub end_minute: i64,
     pub end_period: TimePeriod,
+    /// Days the window may start on. Empty means every day.
+    #[serde(default)]
+    pub weekdays: Vec<DayOfWeek>,
+    /// Yearly date range the window may start in. `None` means all year.
+    #[serde(default)]
+    pub date_range: Option<ScheduleDateRange>,
 }
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct InVariantPayload {
@@ -357,17 +813,29 @@ impl BetweenTimeVariantPayload {
     /// * `Ok(CronSchedule)` - Schedule created successfully
     /// * `Err(SchedulerError::InvalidCronPattern)` - If cron generation fails
     pub fn to_cron_schedule(&self) -> Result<CronSchedule, SchedulerError> {
+        let start_hour_24 =
+            SchedulerEventTiming::to_24_hour(self.start_hour, self.start_period.clone())?;
+        let end_hour_24 = SchedulerEventTiming::to_24_hour(self.end_hour, self.end_period.clone())?;
+        // Windows ending at or before their start time finish on the following day
+        let crosses_midnight = (end_hour_24, self.end_minute) <= (start_hour_24, self.start_minute);
+
         let start_cron = SchedulerEventTiming::parse_cron(
             self.start_hour,
             self.start_minute,
             self.start_period.clone(),
+            &DayOfWeek::cron_field(&self.weekdays, 0),
         )?;
         let end_cron = SchedulerEventTiming::parse_cron(
             self.end_hour,
             self.end_minute,
             self.end_period.clone(),
+            &DayOfWeek::cron_field(&self.weekdays, u32::from(crosses_midnight)),
         )?;
-        CronSchedule::new(&start_cron, &end_cron)
+        let schedule = CronSchedule::new(&start_cron, &end_cron)?;
+        match &self.date_range {
+            Some(date_range) => schedule.with_date_range(date_range.clone()),
+            None => Ok(schedule),
+        }
     }
 }
 
@@ -479,26 +947,127 @@ impl SchedulerEventTiming {
             end_hour,
             end_minute,
             end_period,
+            weekdays: Vec::new(),
+            date_range: None,
         };
         // Validate by trying to convert to CronSchedule
         payload.to_cron_schedule()?;
         Ok(SchedulerEventTiming::Between(payload))
     }
 
-    /// Converts 12-hour format time to a cron expression.
-    fn parse_cron(hour: i64, minute: i64, period: TimePeriod) -> Result<String, SchedulerError> {
-        if !(1..=12).contains(&hour) {
+    /// Create a recurring time window timing from 24-hour clock times.
+    ///
+    /// The window is stored in the same 12-hour payload as
+    /// [`parse_between_variant`](Self::parse_between_variant), so events created
+    /// this way are indistinguishable from ones created through the UI.
+    ///
+    /// ### Parameters
+    /// * `start_hour` - When to start (0-23)
+    /// * `start_minute` - Start minute (0-59)
+    /// * `end_hour` - When to end (0-23)
+    /// * `end_minute` - End minute (0-59)
+    ///
+    /// ### Returns
+    /// * `Ok(SchedulerEventTiming::Between)` - Timing created
+    /// * `Err(SchedulerError::InvalidTimingFormat)` - Hours or minutes out of range
+    ///
+    /// ### Example
+    /// ```
+    /// // Run between 22:00 and 06:30 daily
+    /// let timing = SchedulerEventTiming::parse_between_variant_24h(22, 0, 6, 30)?;
+    /// ```
+    pub fn parse_between_variant_24h(
+        start_hour: i64,
+        start_minute: i64,
+        end_hour: i64,
+        end_minute: i64,
+    ) -> Result<Self, SchedulerError> {
+        let (start_hour, start_period) = Self::to_12_hour(start_hour)?;
+        let (end_hour, end_period) = Self::to_12_hour(end_hour)?;
+        Self::parse_between_variant(
+            start_hour,
+            start_minute,
+            start_period,
+            end_hour,
+            end_minute,
+            end_period,
+        )
+    }
+
+    /// Restricts a recurring window to certain weekdays and/or a yearly date range.
+    ///
+    /// ### Parameters
+    /// * `weekdays` - Days the window may start on (empty for every day)
+    /// * `date_range` - Yearly date range the window may start in (`None` for all year)
+    ///
+    /// ### Returns
+    /// * `Ok(SchedulerEventTiming::Between)` - Restricted timing
+    /// * `Err(SchedulerError::InvalidTimingFormat)` - If this is an In timing or the range is invalid
+    ///
+    /// ### Example
+    /// ```
+    /// // Weekday nights, only during December
+    /// let timing = SchedulerEventTiming::parse_between_variant_24h(22, 0, 7, 0)?
+    ///     .with_calendar(
+    ///         vec![DayOfWeek::Monday, DayOfWeek::Tuesday, DayOfWeek::Wednesday, DayOfWeek::Thursday, DayOfWeek::Friday],
+    ///         Some(ScheduleDateRange { start_month: 12, start_day: 1, end_month: 12, end_day: 31 }),
+    ///     )?;
+    /// ```
+    pub fn with_calendar(
+        self,
+        weekdays: Vec<DayOfWeek>,
+        date_range: Option<ScheduleDateRange>,
+    ) -> Result<Self, SchedulerError> {
+        let SchedulerEventTiming::Between(mut payload) = self else {
             return Err(SchedulerError::InvalidTimingFormat(
-                "Hour must be between 1 and 12".to_string(),
+                "Weekdays and date ranges only apply to recurring windows".to_string(),
             ));
+        };
+        payload.weekdays = weekdays;
+        payload.date_range = date_range;
+        payload.to_cron_schedule()?;
+        Ok(SchedulerEventTiming::Between(payload))
+    }
+
+    /// Converts a 24-hour clock hour into its 12-hour equivalent.
+    fn to_12_hour(hour: i64) -> Result<(i64, TimePeriod), SchedulerError> {
+        match hour {
+            0 => Ok((12, TimePeriod::AM)),
+            1..=11 => Ok((hour, TimePeriod::AM)),
+            12 => Ok((12, TimePeriod::PM)),
+            13..=23 => Ok((hour - 12, TimePeriod::PM)),
+            _ => Err(SchedulerError::InvalidTimingFormat(
+                "Hour must be between 0 and 23".to_string(),
+            )),
         }
+    }
 
+    /// Converts 12-hour format time to a cron expression firing on the given
+    /// day-of-week field.
+    fn parse_cron(
+        hour: i64,
+        minute: i64,
+        period: TimePeriod,
+        days_of_week: &str,
+    ) -> Result<String, SchedulerError> {
         if !(0..=59).contains(&minute) {
             return Err(SchedulerError::InvalidTimingFormat(
                 "Minute must be between 0 and 59".to_string(),
             ));
         }
 
+        let hour_24 = Self::to_24_hour(hour, period)?;
+        Ok(format!("{} {} * * {}", minute, hour_24, days_of_week))
+    }
+
+    /// Converts a 12-hour clock hour into its 24-hour equivalent.
+    fn to_24_hour(hour: i64, period: TimePeriod) -> Result<i64, SchedulerError> {
+        if !(1..=12).contains(&hour) {
+            return Err(SchedulerError::InvalidTimingFormat(
+                "Hour must be between 1 and 12".to_string(),
+            ));
+        }
+
         let hour_24 = match period {
             TimePeriod::AM => {
                 if hour == 12 {
@@ -515,8 +1084,7 @@ impl SchedulerEventTiming {
                 }
             }
         };
-        // Cron pattern for every day at the specified hour and minute
-        Ok(format!("{} {} * * *", minute, hour_24))
+        Ok(hour_24)
     }
 
     /// Checks if this timing represents a recurring event.
@@ -527,6 +1095,31 @@ impl SchedulerEventTiming {
     pub fn is_persistent(&self) -> bool {
         matches!(self, SchedulerEventTiming::Between { .. })
     }
+
+    /// Finds when this timing will next trigger its event.
+    ///
+    /// ### Parameters
+    /// * `scheduled_at` - When the event's timer was (re)started; only used by In timing
+    /// * `now` - Reference time to search from
+    ///
+    /// ### Returns
+    /// * `Some(DateTime<Local>)` - The next activation time
+    /// * `None` - If the In delay has already elapsed or the timing is invalid
+    pub fn next_activation(
+        &self,
+        scheduled_at: DateTime<Local>,
+        now: DateTime<Local>,
+    ) -> Option<DateTime<Local>> {
+        match self {
+            SchedulerEventTiming::In(payload) => {
+                let fires_at = scheduled_at + payload.to_duration().ok()?;
+                (fires_at > now).then_some(fires_at)
+            }
+            SchedulerEventTiming::Between(payload) => {
+                payload.to_cron_schedule().ok()?.find_next_start_time(now)
+            }
+        }
+    }
 }
 
 /// Basic info about a scheduled event.
@@ -537,6 +1130,76 @@ pub struct ScheduledEventInfo {
     pub event_type: SchedulerEventType,
     pub timing: SchedulerEventTiming,
     pub state: SchedulerEventState,
+    /// Unix timestamp (seconds) of the next activation, filled in when listing events.
+    /// Paused events and elapsed one-time events have none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_activation: Option<i64>,
+    /// Higher priority Mine windows win over lower ones while both are open.
+    #[serde(default)]
+    pub priority: u32,
+    /// Most recent catch-up actions, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub catch_ups: Vec<CatchUpRecord>,
+    /// Most recent callback runs, oldest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub journal: Vec<ExecutionRecord>,
+}
+
+/// Finds an existing event whose window would overlap the candidate's window
+/// without a way to resolve it.
+///
+/// Mine windows only conflict at equal priority, since overlapping Mine windows
+/// with different priorities are settled by [`resolve_active_mine_event`].
+/// Action events remember and restore the state they changed, so two overlapping
+/// windows of the same action always conflict. One-time In events never conflict.
+///
+/// ### Returns
+/// * `Some(&ScheduledEventInfo)` - The first conflicting event
+/// * `None` - If the candidate can be added
+pub fn find_overlapping_event<'a>(
+    events: &'a [ScheduledEventInfo],
+    candidate: &ScheduledEventInfo,
+    now: DateTime<Local>,
+) -> Option<&'a ScheduledEventInfo> {
+    let SchedulerEventTiming::Between(candidate_payload) = &candidate.timing else {
+        return None;
+    };
+    let candidate_schedule = candidate_payload.to_cron_schedule().ok()?;
+    let until = now + Duration::days(OVERLAP_HORIZON_DAYS);
+
+    events
+        .iter()
+        .filter(|event| event.id != candidate.id)
+        .filter(|event| event.event_type.conflicts_with(&candidate.event_type))
+        .filter(|event| {
+            !candidate.event_type.is_resolved_by_priority() || event.priority == candidate.priority
+        })
+        .find(|event| match &event.timing {
+            SchedulerEventTiming::Between(payload) => payload
+                .to_cron_schedule()
+                .is_ok_and(|schedule| schedule.overlaps_within(&candidate_schedule, now, until)),
+            SchedulerEventTiming::In(_) => false,
+        })
+}
+
+/// Picks the Mine window that should be in effect at `now`: the open, unpaused
+/// window with the highest priority. Ties go to the lowest event ID so the
+/// result is stable.
+pub fn resolve_active_mine_event(
+    events: &[ScheduledEventInfo],
+    now: DateTime<Local>,
+) -> Option<&ScheduledEventInfo> {
+    events
+        .iter()
+        .filter(|event| event.state != SchedulerEventState::Paused)
+        .filter(|event| matches!(event.event_type, SchedulerEventType::Mine { .. }))
+        .filter(|event| match &event.timing {
+            SchedulerEventTiming::Between(payload) => payload
+                .to_cron_schedule()
+                .is_ok_and(|schedule| schedule.is_active_at(now)),
+            SchedulerEventTiming::In(_) => false,
+        })
+        .max_by(|a, b| a.priority.cmp(&b.priority).then_with(|| b.id.cmp(&a.id)))
 }
 
 /// Defines the types of actions that can be scheduled.
@@ -553,6 +1216,33 @@ pub enum SchedulerEventType {
         /// The specific mining mode configuration to use
         mining_mode: String,
     },
+    /// Turn CPU mining on or off without touching GPU mining.
+    CpuMining { enabled: bool },
+    /// Turn GPU mining on or off without touching CPU mining.
+    GpuMining { enabled: bool },
+    /// Switch the active CPU pool.
+    SwitchCpuPool { pool_name: String },
+    /// Switch the active GPU pool.
+    SwitchGpuPool { pool_name: String },
+    /// Turn CPU pool mining on (pool) or off (solo).
+    CpuPoolMining { enabled: bool },
+    /// Turn GPU pool mining on (pool) or off (solo).
+    GpuPoolMining { enabled: bool },
+    /// Switch between the local and a remote base node.
+    SwitchNodeType { node_type: NodeType },
+}
+
+/// What an action event changed when its window opened, captured so the
+/// exit callback can put it back.
+#[derive(Debug, Clone)]
+enum PreviousState {
+    CpuMining { enabled: bool, running: bool },
+    GpuMining { enabled: bool, running: bool },
+    CpuPool(String),
+    GpuPool(String),
+    CpuPoolMining(bool),
+    GpuPoolMining(bool),
+    NodeType(NodeType),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -563,6 +1253,21 @@ pub enum SchedulerEventState {
     Completed,
 }
 
+impl SchedulerEventState {
+    /// The state a saved event comes back in after a restart. Paused events
+    /// stay paused and windows that were open stay entered so catch-up can
+    /// close them; everything else starts over as `Active`.
+    pub fn restored(&self) -> Self {
+        match self {
+            SchedulerEventState::Paused => SchedulerEventState::Paused,
+            SchedulerEventState::Triggered => SchedulerEventState::Triggered,
+            SchedulerEventState::Active | SchedulerEventState::Completed => {
+                SchedulerEventState::Active
+            }
+        }
+    }
+}
+
 impl SchedulerEventType {
     /// Checks if only one of this event type can exist at once.
     ///
@@ -572,6 +1277,31 @@ impl SchedulerEventType {
     pub fn is_unique(&self) -> bool {
         matches!(self, SchedulerEventType::ResumeMining)
     }
+
+    /// Checks if both events drive the same setting, so their windows must not
+    /// overlap unresolved.
+    ///
+    /// ### Returns
+    /// * `true` - Two Mine events, or two action events of the same kind
+    /// * `false` - Otherwise
+    pub fn conflicts_with(&self, other: &SchedulerEventType) -> bool {
+        match (self, other) {
+            (SchedulerEventType::ResumeMining, _) | (_, SchedulerEventType::ResumeMining) => false,
+            (SchedulerEventType::Mine { .. }, SchedulerEventType::Mine { .. }) => true,
+            (SchedulerEventType::Mine { .. }, _) | (_, SchedulerEventType::Mine { .. }) => false,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+
+    /// Checks if overlapping windows of this type are settled by priority
+    /// instead of being rejected.
+    pub fn is_resolved_by_priority(&self) -> bool {
+        matches!(self, SchedulerEventType::Mine { .. })
+    }
+}
+
+fn on_off(enabled: bool) -> &'static str {
+    if enabled { "On" } else { "Off" }
 }
 
 impl Display for SchedulerEventType {
@@ -581,10 +1311,35 @@ impl Display for SchedulerEventType {
             SchedulerEventType::Mine { mining_mode } => {
                 write!(f, "Mine ({})", mining_mode)
             }
+            SchedulerEventType::CpuMining { enabled } => {
+                write!(f, "CPU Mining {}", on_off(*enabled))
+            }
+            SchedulerEventType::GpuMining { enabled } => {
+                write!(f, "GPU Mining {}", on_off(*enabled))
+            }
+            SchedulerEventType::SwitchCpuPool { pool_name } => {
+                write!(f, "Switch CPU Pool ({})", pool_name)
+            }
+            SchedulerEventType::SwitchGpuPool { pool_name } => {
+                write!(f, "Switch GPU Pool ({})", pool_name)
+            }
+            SchedulerEventType::CpuPoolMining { enabled } => {
+                write!(f, "CPU Pool Mining {}", on_off(*enabled))
+            }
+            SchedulerEventType::GpuPoolMining { enabled } => {
+                write!(f, "GPU Pool Mining {}", on_off(*enabled))
+            }
+            SchedulerEventType::SwitchNodeType { node_type } => {
+                write!(f, "Switch Node ({})", node_type)
+            }
         }
     }
 }
 
+fn timestamps(times: Vec<DateTime<Local>>) -> Vec<i64> {
+    times.iter().map(DateTime::timestamp).collect()
+}
+
 /// Internal event data with runtime info.
 /// Contains everything needed to manage an event while it's running.
 #[derive(Debug)]
@@ -593,36 +1348,138 @@ struct ScheduledEvent {
     event_type: SchedulerEventType,
     timing: SchedulerEventTiming,
     state: SchedulerEventState,
+    /// When the event's scheduling task was last started
+    scheduled_at: DateTime<Local>,
+    /// State to restore when the current window of an action event closes
+    previous_state: Option<PreviousState>,
+    priority: u32,
+    catch_ups: Vec<CatchUpRecord>,
+    journal: Vec<ExecutionRecord>,
     task_handle: Option<tokio::task::JoinHandle<()>>,
 }
 
+impl ScheduledEvent {
+    fn to_info(&self, next_activation: Option<i64>) -> ScheduledEventInfo {
+        ScheduledEventInfo {
+            id: self.id.clone(),
+            event_type: self.event_type.clone(),
+            timing: self.timing.clone(),
+            state: self.state.clone(),
+            next_activation,
+            priority: self.priority,
+            catch_ups: self.catch_ups.clone(),
+            journal: self.journal.clone(),
+        }
+    }
+
+    fn record_execution(&mut self, record: ExecutionRecord) {
+        if self.journal.len() >= MAX_JOURNAL_ENTRIES {
+            self.journal.remove(0);
+        }
+        self.journal.push(record);
+    }
+
+    fn record_catch_up(
+        &mut self,
+        action: CatchUpAction,
+        reason: CatchUpReason,
+        at: DateTime<Local>,
+    ) {
+        if self.catch_ups.len() >= MAX_CATCH_UP_RECORDS {
+            self.catch_ups.remove(0);
+        }
+        self.catch_ups.push(CatchUpRecord {
+            at: at.timestamp(),
+            action,
+            reason,
+        });
+    }
+
+    /// Next `count` window starts and ends of this event. Paused events have none.
+    fn upcoming_activations(&self, now: DateTime<Local>, count: usize) -> EventActivations {
+        let (starts, ends) = match &self.timing {
+            _ if self.state == SchedulerEventState::Paused => (Vec::new(), Vec::new()),
+            SchedulerEventTiming::In(_) => (
+                self.next_activation(now)
+                    .map(|at| at.timestamp())
+                    .into_iter()
+                    .collect(),
+                Vec::new(),
+            ),
+            SchedulerEventTiming::Between(payload) => match payload.to_cron_schedule() {
+                Ok(schedule) => (
+                    timestamps(schedule.next_start_times(now, count)),
+                    timestamps(schedule.next_end_times(now, count)),
+                ),
+                Err(e) => {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "Cannot compute activations for event {:?}: {}", self.id, e);
+                    (Vec::new(), Vec::new())
+                }
+            },
+        };
+        EventActivations {
+            event_id: self.id.clone(),
+            starts,
+            ends,
+        }
+    }
+
+    /// Next time this event will trigger, or `None` while it is paused.
+    fn next_activation(&self, now: DateTime<Local>) -> Option<DateTime<Local>> {
+        if self.state == SchedulerEventState::Paused {
+            return None;
+        }
+        self.timing.next_activation(self.scheduled_at, now)
+    }
+}
+
 /// The main scheduler that handles all your scheduled events.
 /// It's a singleton that uses message passing to stay thread-safe.
 pub struct EventScheduler {
     message_sender: mpsc::UnboundedSender<SchedulerMessage>,
     message_receiver: RwLock<mpsc::UnboundedReceiver<SchedulerMessage>>,
     is_running: AtomicBool,
+    clock: Arc<dyn SchedulerClock>,
 }
 
 impl EventScheduler {
     /// Creates a new scheduler instance.
     /// Call `spawn_listener()` to actually start it.
     pub fn new() -> Self {
+        Self::with_clock(Arc::new(SystemClock))
+    }
+
+    /// Creates a new scheduler instance that reads the time from `clock`.
+    pub fn with_clock(clock: Arc<dyn SchedulerClock>) -> Self {
         let (message_sender, message_receiver) = mpsc::unbounded_channel::<SchedulerMessage>();
 
         EventScheduler {
             message_sender,
             message_receiver: RwLock::new(message_receiver),
             is_running: AtomicBool::new(false),
+            clock,
         }
     }
 
+    /// Current time according to the global scheduler's clock.
+    fn now() -> DateTime<Local> {
+        INSTANCE.clock.now()
+    }
+
     /// Gets the global scheduler instance.
     /// Returns the global EventScheduler singleton.
     pub fn instance() -> &'static Self {
         &INSTANCE
     }
 
+    /// Asks the scheduler to catch up on the callbacks it held back while a
+    /// miner benchmark was running.
+    pub fn catch_up_after_benchmark(&self) {
+        let _unused = self.message_sender.send(SchedulerMessage::Reconcile {
+            reason: CatchUpReason::Benchmark,
+        });
+    }
+
     /// Schedules a new event.
     ///
     /// This is the main way to add new scheduled events. The scheduler will
@@ -649,6 +1506,27 @@ impl EventScheduler {
         event_type: SchedulerEventType,
         event_id: String,
         timing: SchedulerEventTiming,
+    ) -> Result<String, SchedulerError> {
+        self.schedule_event_with_priority(event_type, event_id, timing, 0)
+            .await
+    }
+
+    /// Schedules a new event with an explicit priority.
+    ///
+    /// Overlapping Mine windows are allowed when their priorities differ; while
+    /// both are open the higher priority one is in effect, and when it closes
+    /// mining falls back to the next-highest open window instead of stopping.
+    ///
+    /// ### Returns
+    /// * `Ok(String)` - Event ID of the scheduled event
+    /// * `Err(SchedulerError::OverlappingEvent)` - If the window overlaps a conflicting event
+    /// * `Err(SchedulerError)` - If scheduling fails for another reason
+    pub async fn schedule_event_with_priority(
+        &self,
+        event_type: SchedulerEventType,
+        event_id: String,
+        timing: SchedulerEventTiming,
+        priority: u32,
     ) -> Result<String, SchedulerError> {
         let (response_tx, response_rx) = tokio::sync::oneshot::channel();
 
@@ -657,6 +1535,7 @@ impl EventScheduler {
                 event_type,
                 timing,
                 event_id,
+                priority,
                 response: response_tx,
             })
             .map_err(|_| SchedulerError::SchedulerNotRunning)?;
@@ -714,6 +1593,26 @@ impl EventScheduler {
             .map_err(|_| SchedulerError::InternalError("Response channel closed".to_string()))?
     }
 
+    /// Lists the next `count` window starts and ends of every event, capped
+    /// at `MAX_UPCOMING_ACTIVATIONS`.
+    pub async fn upcoming_activations(
+        &self,
+        count: usize,
+    ) -> Result<Vec<EventActivations>, SchedulerError> {
+        let (response_tx, response_rx) = tokio::sync::oneshot::channel();
+
+        self.message_sender
+            .send(SchedulerMessage::UpcomingActivations {
+                count: count.min(MAX_UPCOMING_ACTIVATIONS),
+                response: response_tx,
+            })
+            .map_err(|_| SchedulerError::SchedulerNotRunning)?;
+
+        response_rx
+            .await
+            .map_err(|_| SchedulerError::InternalError("Response channel closed".to_string()))
+    }
+
     /// Lists all events currently in the scheduler (both persistent and one-time).
     pub async fn list_events(&self) -> Result<Vec<ScheduledEventInfo>, SchedulerError> {
         let (response_tx, response_rx) = tokio::sync::oneshot::channel();
@@ -741,17 +1640,7 @@ impl EventScheduler {
             scheduled_events
                 .iter()
                 .filter(|(_, event)| event.timing.is_persistent())
-                .map(|(id, event)| {
-                    (
-                        id.clone(),
-                        ScheduledEventInfo {
-                            id: id.clone(),
-                            event_type: event.event_type.clone(),
-                            timing: event.timing.clone(),
-                            state: event.state.clone(),
-                        },
-                    )
-                }),
+                .map(|(id, event)| (id.clone(), event.to_info(None))),
         );
 
         ConfigCore::update_field(ConfigCoreContent::set_scheduler_events, persistent_events)
@@ -779,7 +1668,12 @@ impl EventScheduler {
                         id: info.id,
                         event_type: info.event_type,
                         timing: info.timing,
-                        state: SchedulerEventState::Active,
+                        state: info.state,
+                        scheduled_at: Self::now(),
+                        previous_state: None,
+                        priority: info.priority,
+                        catch_ups: info.catch_ups,
+                        journal: info.journal,
                         task_handle: None,
                     },
                 )
@@ -818,13 +1712,10 @@ impl EventScheduler {
         info!(target: LOG_TARGET_APP_LOGIC, "Starting Event Scheduler Listener");
 
         task_tracker.spawn(async move {
-            let mut internal_events: HashMap<String, ScheduledEvent> = HashMap::new();
-            for (id, event) in persistent_events_from_config {
-                Self::handle_add_event(&mut internal_events, event.event_type.clone(), id.clone(), event.timing.clone()).await.unwrap_or_else(|e| {
-                    error!(target: LOG_TARGET_APP_LOGIC, "Failed to reschedule persistent event {:?}: {}", id, e);
-                    e.to_string()
-                });
-            }
+            let mut internal_events =
+                Self::restore_persistent_events(persistent_events_from_config).await;
+            // The app may have started in the middle of a window
+            Self::handle_reconcile(&mut internal_events, CatchUpReason::Startup).await;
 
             let message_receiver = &mut *INSTANCE.message_receiver.write().await;
 
@@ -843,12 +1734,15 @@ impl EventScheduler {
                     },
                     message = message_receiver.recv() => {
                         match message {
-                            Some(SchedulerMessage::AddEvent { event_type, timing, event_id,response }) => {
-                                let result = Self::handle_add_event(&mut internal_events, event_type, event_id, timing).await;
+                            Some(SchedulerMessage::AddEvent { event_type, timing, event_id, priority, response }) => {
+                                let result = match Self::check_for_overlap(&internal_events, &event_type, &event_id, &timing, priority) {
+                                    Ok(()) => Self::handle_add_event(&mut internal_events, event_type, event_id, timing, priority).await,
+                                    Err(e) => Err(e),
+                                };
                                 let _unused = response.send(result);
                             },
                             Some(SchedulerMessage::RemoveEvent { event_id, response }) => {
-                                let result = Self::handle_remove_event(&mut internal_events, event_id);
+                                let result = Self::handle_remove_event(&mut internal_events, event_id).await;
                                 let should_persist = result.is_ok();
                                 let _unused = response.send(result);
                                 if should_persist {
@@ -856,36 +1750,55 @@ impl EventScheduler {
                                 }
                             },
                             Some(SchedulerMessage::PauseEvent { event_id, response }) => {
-                                let result = Self::handle_pause_event(&mut internal_events, event_id);
+                                let result = Self::handle_pause_event(&mut internal_events, event_id).await;
                                 let _unused = response.send(result);
                             },
                             Some(SchedulerMessage::ResumeEvent { event_id, response }) => {
                                 let result = Self::handle_resume_event(&mut internal_events, event_id).await;
                                 let _unused = response.send(result);
                             },
+                            Some(SchedulerMessage::TriggerEnterCallback { event_id }) if is_benchmark_running() => {
+                                // Left Active, so the catch-up after the benchmark enters it
+                                info!(target: LOG_TARGET_APP_LOGIC, "Holding back enter callback for event {:?} while a miner benchmark runs", event_id);
+                            },
                             Some(SchedulerMessage::TriggerEnterCallback { event_id }) => {
-                                Self::handle_enter_callback(&internal_events, event_id).await;
-
+                                Self::run_callback(&mut internal_events, event_id.clone(), ExecutionPhase::Enter, None).await;
+                                // One-time events are done once they have run
+                                Self::handle_cleanup_schedule_events(&mut internal_events, event_id).await;
+                                Self::save_persistent_events_to_config(&internal_events).await;
+                            },
+                            Some(SchedulerMessage::TriggerExitCallback { event_id }) if is_benchmark_running() => {
+                                // Left Triggered, so the catch-up after the benchmark exits it
+                                info!(target: LOG_TARGET_APP_LOGIC, "Holding back exit callback for event {:?} while a miner benchmark runs", event_id);
                             },
                             Some(SchedulerMessage::TriggerExitCallback { event_id }) => {
-                                Self::handle_exit_callback(&internal_events, event_id).await;
-
+                                Self::run_callback(&mut internal_events, event_id, ExecutionPhase::Exit, None).await;
+                                Self::save_persistent_events_to_config(&internal_events).await;
                             }
                             Some(SchedulerMessage::ListEvents { response }) => {
+                                let now = Self::now();
                                 let events: Vec<ScheduledEventInfo> = internal_events
-                                    .iter()
-                                    .map(|(id, event)| ScheduledEventInfo {
-                                        id: id.clone(),
-                                        event_type: event.event_type.clone(),
-                                        timing: event.timing.clone(),
-                                        state: event.state.clone(),
+                                    .values()
+                                    .map(|event| {
+                                        event.to_info(event.next_activation(now).map(|at| at.timestamp()))
                                     })
                                     .collect();
                                 let _unused = response.send(events);
                             },
+                            Some(SchedulerMessage::UpcomingActivations { count, response }) => {
+                                let now = Self::now();
+                                let activations: Vec<EventActivations> = internal_events
+                                    .values()
+                                    .map(|event| event.upcoming_activations(now, count))
+                                    .collect();
+                                let _unused = response.send(activations);
+                            },
                             Some(SchedulerMessage::CleanupSchedule { event_id }) => {
                                 Self::handle_cleanup_schedule_events(&mut internal_events, event_id).await;
                             }
+                            Some(SchedulerMessage::Reconcile { reason }) => {
+                                Self::handle_reconcile(&mut internal_events, reason).await;
+                            }
 
                             None => {
                                 warn!(target: LOG_TARGET_APP_LOGIC, "Message channel closed, stopping scheduler");
@@ -899,9 +1812,160 @@ impl EventScheduler {
             info!(target: LOG_TARGET_APP_LOGIC, "Event Scheduler Listener stopped");
         });
 
+        Self::spawn_catch_up_watcher().await;
+
         Ok(())
     }
 
+    /// Reschedules the events loaded from config, keeping their history,
+    /// whether they were paused and whether their window was open at shutdown.
+    async fn restore_persistent_events(
+        persistent_events: HashMap<String, ScheduledEvent>,
+    ) -> HashMap<String, ScheduledEvent> {
+        let mut events: HashMap<String, ScheduledEvent> = HashMap::new();
+        for (id, event) in persistent_events {
+            // Events saved before overlap checks existed are restored as-is rather than dropped
+            if let Err(e) = Self::handle_add_event(
+                &mut events,
+                event.event_type.clone(),
+                id.clone(),
+                event.timing.clone(),
+                event.priority,
+            )
+            .await
+            {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to reschedule persistent event {:?}: {}", id, e);
+                continue;
+            }
+            if let Some(restored) = events.get_mut(&id) {
+                restored.catch_ups = event.catch_ups;
+                restored.journal = event.journal;
+                restored.state = event.state.restored();
+                // Paused events get a fresh timer when they are resumed
+                if restored.state == SchedulerEventState::Paused
+                    && let Some(handle) = restored.task_handle.take()
+                {
+                    handle.abort();
+                }
+            }
+        }
+        events
+    }
+
+    /// Spawns the task that asks the scheduler to re-evaluate its windows when
+    /// the system wakes from sleep or the wall clock jumps. Timers sleep on the
+    /// monotonic clock, so after either of those they no longer line up with
+    /// the windows they were computed for.
+    async fn spawn_catch_up_watcher() {
+        let task_tracker = TasksTrackers::current().common.get_task_tracker().await;
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+
+        task_tracker.spawn(async move {
+            let mut detector =
+                ClockJumpDetector::new(INSTANCE.clock.clone(), std::time::Instant::now());
+            let mut sleep_mode_watcher = SystemStatus::current().get_sleep_mode_watcher();
+            let mut was_sleeping = *sleep_mode_watcher.borrow();
+            let mut clock_check = tokio::time::interval(CLOCK_CHECK_INTERVAL);
+
+            loop {
+                tokio::select! {
+                    _ = shutdown_signal.wait() => break,
+                    _ = clock_check.tick() => {
+                        if let Some(jump) = detector.check(std::time::Instant::now()) {
+                            info!(target: LOG_TARGET_APP_LOGIC, "Wall clock jumped by {}s, re-evaluating scheduled events", jump.num_seconds());
+                            let _unused = INSTANCE.message_sender.send(SchedulerMessage::Reconcile {
+                                reason: CatchUpReason::ClockJump,
+                            });
+                        }
+                    }
+                    changed = sleep_mode_watcher.changed() => {
+                        if changed.is_err() {
+                            break;
+                        }
+                        let is_sleeping = *sleep_mode_watcher.borrow();
+                        if was_sleeping && !is_sleeping {
+                            info!(target: LOG_TARGET_APP_LOGIC, "System woke up, re-evaluating scheduled events");
+                            // Suspend time also shows up as a clock jump; skip it so we only catch up once
+                            detector.check(std::time::Instant::now());
+                            let _unused = INSTANCE.message_sender.send(SchedulerMessage::Reconcile {
+                                reason: CatchUpReason::Wake,
+                            });
+                        }
+                        was_sleeping = is_sleeping;
+                    }
+                }
+            }
+        });
+    }
+
+    /// Brings every event in line with the current time.
+    ///
+    /// Enters windows that should be open, exits windows that should be closed,
+    /// fires one-time events whose delay already elapsed, then restarts all
+    /// timers from the current time. Every catch-up action is recorded on its event.
+    async fn handle_reconcile(events: &mut HashMap<String, ScheduledEvent>, reason: CatchUpReason) {
+        if is_benchmark_running() {
+            info!(target: LOG_TARGET_APP_LOGIC, "A miner benchmark is running, catching up after {:?} once it finishes", reason);
+            return;
+        }
+        let now = Self::now();
+        let mut plan = plan_catch_up(&Self::event_infos(events), INSTANCE.clock.as_ref());
+        let mut overdue: Vec<String> = events
+            .values()
+            .filter(|event| event.state == SchedulerEventState::Active)
+            .filter(|event| matches!(event.timing, SchedulerEventTiming::In(_)))
+            .filter(|event| event.next_activation(now).is_none())
+            .map(|event| event.id.clone())
+            .collect();
+        overdue.sort();
+        plan.extend(overdue.into_iter().map(|id| (id, CatchUpAction::Enter)));
+
+        for (event_id, action) in plan {
+            info!(target: LOG_TARGET_APP_LOGIC, "Catching up event {:?} after {:?}: {:?}", event_id, reason, action);
+            if let Some(event) = events.get_mut(&event_id) {
+                event.record_catch_up(action, reason, now);
+            }
+            match action {
+                CatchUpAction::Enter => {
+                    Self::run_callback(
+                        events,
+                        event_id.clone(),
+                        ExecutionPhase::Enter,
+                        Some(reason),
+                    )
+                    .await;
+                    Self::handle_cleanup_schedule_events(events, event_id).await;
+                }
+                CatchUpAction::Exit => {
+                    Self::run_callback(events, event_id, ExecutionPhase::Exit, Some(reason)).await;
+                }
+            }
+        }
+
+        for event in events
+            .values_mut()
+            .filter(|event| event.state != SchedulerEventState::Paused)
+        {
+            if let Some(handle) = event.task_handle.take() {
+                handle.abort();
+            }
+            match Self::create_scheduling_task(
+                event.id.clone(),
+                event.event_type.clone(),
+                event.timing.clone(),
+                event.scheduled_at,
+            )
+            .await
+            {
+                Ok(handle) => event.task_handle = Some(handle),
+                Err(e) => {
+                    error!(target: LOG_TARGET_APP_LOGIC, "Failed to restart timer for event {:?}: {}", event.id, e);
+                }
+            }
+        }
+        Self::save_persistent_events_to_config(events).await;
+    }
+
     /// Internal handler for adding new events to the scheduler.
     ///
     /// Processes AddEvent messages from the public API, manages unique event types,
@@ -912,6 +1976,7 @@ impl EventScheduler {
     /// * `event_type` - Type of event to add
     /// * `event_id` - Unique identifier for the event
     /// * `timing` - When the event should trigger
+    /// * `priority` - Priority used to resolve overlapping Mine windows
     ///
     /// ### Returns
     /// * `Ok(String)` - The event ID of the added event
@@ -921,7 +1986,13 @@ impl EventScheduler {
         event_type: SchedulerEventType,
         event_id: String,
         timing: SchedulerEventTiming,
+        priority: u32,
     ) -> Result<String, SchedulerError> {
+        // Replacing an event must stop its old timers, or both would keep firing
+        if events.contains_key(&event_id) {
+            Self::handle_remove_event(events, event_id.clone()).await?;
+        }
+
         if event_type.is_unique() {
             info!(target: LOG_TARGET_APP_LOGIC, "Ensuring uniqueness for event type {:?}", event_type);
             let to_remove: Vec<String> = events
@@ -931,7 +2002,7 @@ impl EventScheduler {
                 .collect();
 
             for id in to_remove {
-                if let Err(e) = Self::handle_remove_event(events, id.clone()) {
+                if let Err(e) = Self::handle_remove_event(events, id.clone()).await {
                     warn!(target: LOG_TARGET_APP_LOGIC, "Failed to remove duplicate event {:?}: {}", id, e);
                 }
             }
@@ -942,11 +2013,21 @@ impl EventScheduler {
             event_type: event_type.clone(),
             timing: timing.clone(),
             state: SchedulerEventState::Active,
+            scheduled_at: Self::now(),
+            previous_state: None,
+            priority,
+            catch_ups: Vec::new(),
+            journal: Vec::new(),
             task_handle: None,
         };
 
-        let task_handle =
-            Self::create_scheduling_task(event_id.clone(), event_type, timing).await?;
+        let task_handle = Self::create_scheduling_task(
+            event_id.clone(),
+            event_type,
+            timing,
+            scheduled_event.scheduled_at,
+        )
+        .await?;
         scheduled_event.task_handle = Some(task_handle);
         events.insert(event_id.clone(), scheduled_event);
         Self::save_persistent_events_to_config(events).await;
@@ -954,10 +2035,47 @@ impl EventScheduler {
         Ok(event_id)
     }
 
+    /// Rejects a new event whose window overlaps a conflicting existing event.
+    ///
+    /// ### Returns
+    /// * `Ok(())` - No unresolved overlap
+    /// * `Err(SchedulerError::OverlappingEvent)` - ID of the event it overlaps
+    fn check_for_overlap(
+        events: &HashMap<String, ScheduledEvent>,
+        event_type: &SchedulerEventType,
+        event_id: &str,
+        timing: &SchedulerEventTiming,
+        priority: u32,
+    ) -> Result<(), SchedulerError> {
+        let existing = Self::event_infos(events);
+        let candidate = ScheduledEventInfo {
+            id: event_id.to_string(),
+            event_type: event_type.clone(),
+            timing: timing.clone(),
+            state: SchedulerEventState::Active,
+            next_activation: None,
+            priority,
+            catch_ups: Vec::new(),
+            journal: Vec::new(),
+        };
+        match find_overlapping_event(&existing, &candidate, Self::now()) {
+            Some(overlapping) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Rejecting event {:?}: overlaps {:?}", event_id, overlapping.id);
+                Err(SchedulerError::OverlappingEvent(overlapping.id.clone()))
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn event_infos(events: &HashMap<String, ScheduledEvent>) -> Vec<ScheduledEventInfo> {
+        events.values().map(|event| event.to_info(None)).collect()
+    }
+
     /// Internal handler for removing events from the scheduler.
     ///
     /// Processes RemoveEvent messages, cancels the event's execution task,
-    /// and removes it from the events map.
+    /// and removes it from the events map. Removing a window that is currently
+    /// open closes it first, so whatever it changed is put back.
     ///
     /// ### Parameters
     /// * `events` - Mutable reference to the events map
@@ -965,12 +2083,13 @@ impl EventScheduler {
     ///
     /// ### Returns
     /// * `Ok(())` - Event successfully removed
-    /// * `Err(SchedulerError::EventNotFound)` - No event with the given ID
-    fn handle_remove_event(
+    /// * `Err(SchedulerError)` - No event with the given ID, or its window can not be closed now
+    async fn handle_remove_event(
         events: &mut HashMap<String, ScheduledEvent>,
         event_id: String,
     ) -> Result<(), SchedulerError> {
         info!(target: LOG_TARGET_APP_LOGIC, "Removing event with ID {:?}", event_id);
+        Self::close_open_window(events, &event_id).await?;
         match events.remove(&event_id) {
             Some(mut event) => {
                 if let Some(handle) = event.task_handle.take() {
@@ -983,10 +2102,35 @@ impl EventScheduler {
         }
     }
 
+    /// Runs the exit callback of a window that is currently open, so whatever
+    /// it changed is put back before the event is paused, removed or replaced.
+    /// Refused while a miner benchmark runs, as the callback would switch miners
+    /// in the middle of a measurement.
+    async fn close_open_window(
+        events: &mut HashMap<String, ScheduledEvent>,
+        event_id: &str,
+    ) -> Result<(), SchedulerError> {
+        let is_open = events
+            .get(event_id)
+            .is_some_and(|event| event.state == SchedulerEventState::Triggered);
+        if !is_open {
+            return Ok(());
+        }
+        if is_benchmark_running() {
+            return Err(SchedulerError::ActionFailed(
+                "Can not close the window while a miner benchmark runs".to_string(),
+            ));
+        }
+        Self::run_callback(events, event_id.to_string(), ExecutionPhase::Exit, None).await;
+        Ok(())
+    }
+
     /// Internal handler for pausing events.
     ///
     /// Processes PauseEvent messages, changes the event state to Paused,
     /// and cancels the event's execution task while keeping the event data.
+    /// Pausing a window that is currently open closes it first, so whatever
+    /// it changed is put back.
     ///
     /// ### Parameters
     /// * `events` - Mutable reference to the events map
@@ -995,25 +2139,28 @@ impl EventScheduler {
     /// ### Returns
     /// * `Ok(())` - Event successfully paused
     /// * `Err(SchedulerError)` - If pausing fails
-    fn handle_pause_event(
+    async fn handle_pause_event(
         events: &mut HashMap<String, ScheduledEvent>,
         event_id: String,
     ) -> Result<(), SchedulerError> {
-        if let Some(event) = events.get_mut(&event_id) {
-            if event.state == SchedulerEventState::Paused {
+        match events.get(&event_id).map(|event| event.state.clone()) {
+            None => return Err(SchedulerError::EventNotFound(event_id)),
+            Some(SchedulerEventState::Paused) => {
                 return Err(SchedulerError::EventAlreadyPaused(event_id));
             }
+            Some(_) => Self::close_open_window(events, &event_id).await?,
+        }
 
+        if let Some(event) = events.get_mut(&event_id) {
             event.state = SchedulerEventState::Paused;
             if let Some(handle) = event.task_handle.take() {
                 handle.abort();
             }
-
-            info!(target: LOG_TARGET_APP_LOGIC, "Paused event with ID {:?}", event_id);
-            Ok(())
-        } else {
-            Err(SchedulerError::EventNotFound(event_id))
         }
+        Self::save_persistent_events_to_config(events).await;
+
+        info!(target: LOG_TARGET_APP_LOGIC, "Paused event with ID {:?}", event_id);
+        Ok(())
     }
 
     /// Internal handler for resuming paused events.
@@ -1038,15 +2185,18 @@ impl EventScheduler {
             }
 
             event.state = SchedulerEventState::Active;
+            event.scheduled_at = Self::now();
 
             let task_handle = Self::create_scheduling_task(
                 event_id.clone(),
                 event.event_type.clone(),
                 event.timing.clone(),
+                event.scheduled_at,
             )
             .await?;
 
             event.task_handle = Some(task_handle);
+            Self::save_persistent_events_to_config(events).await;
 
             info!(target: LOG_TARGET_APP_LOGIC, "Resumed event with ID {:?}", event_id);
             Ok(())
@@ -1055,94 +2205,410 @@ impl EventScheduler {
         }
     }
 
+    /// Runs an event's enter or exit callback and records the outcome in the
+    /// event's execution journal.
+    ///
+    /// ### Parameters
+    /// * `events` - Mutable reference to the events map
+    /// * `event_id` - ID of the event to run
+    /// * `phase` - Whether the window is opening or closing
+    /// * `catch_up` - Set when the callback runs to catch up with the clock rather than on its timer
+    async fn run_callback(
+        events: &mut HashMap<String, ScheduledEvent>,
+        event_id: String,
+        phase: ExecutionPhase,
+        catch_up: Option<CatchUpReason>,
+    ) {
+        let triggered_at = Self::now();
+        let result = match phase {
+            ExecutionPhase::Enter => Self::handle_enter_callback(events, event_id.clone()).await,
+            ExecutionPhase::Exit => Self::handle_exit_callback(events, event_id.clone()).await,
+        };
+        let Some(event) = events.get_mut(&event_id) else {
+            return;
+        };
+        let (action, error) = match result {
+            Ok(Some(action)) => (action, None),
+            // Nothing ran, e.g. the event was paused or its window was never entered
+            Ok(None) => return,
+            Err(e) => {
+                error!(target: LOG_TARGET_APP_LOGIC, "{:?} callback for event {:?} failed: {}", phase, event_id, e);
+                (event.event_type.to_string(), Some(e.to_string()))
+            }
+        };
+        event.record_execution(ExecutionRecord {
+            triggered_at: triggered_at.timestamp(),
+            phase,
+            action,
+            catch_up,
+            error,
+        });
+    }
+
     /// Internal handler for event enter callbacks.
     ///
     /// Executes the actions associated with an event when it triggers.
-    /// This starts mining operations based on the event type.
+    /// This starts mining operations based on the event type. Action events
+    /// with a recurring window remember the state they replaced so the exit
+    /// callback can restore it.
     ///
     /// ### Parameters
-    /// * `events` - Reference to the events map
+    /// * `events` - Mutable reference to the events map
     /// * `event_id` - ID of the event that triggered
     ///
     /// ### Returns
-    /// * `Ok(())` - Callback executed successfully
-    /// * `Err(SchedulerError)` - If execution fails
+    /// * `Ok(Some(String))` - Description of what was done
+    /// * `Ok(None)` - The event is missing or paused, so nothing ran
+    /// * `Err(SchedulerError)` - If any part of the action failed
     async fn handle_enter_callback(
-        events: &HashMap<String, ScheduledEvent>,
+        events: &mut HashMap<String, ScheduledEvent>,
         event_id: String,
-    ) -> Result<(), SchedulerError> {
-        if let Some(event) = events.get(&event_id)
-            && event.state == SchedulerEventState::Active
-        {
-            match event.event_type.clone() {
-                SchedulerEventType::ResumeMining => {
-                    let config = ConfigMining::content().await;
-                    if *config.gpu_mining_enabled() {
-                        GpuManager::write().await.start_mining().await.unwrap_or_else(|e| {
-                            error!(target: LOG_TARGET_APP_LOGIC, "Failed to start GPU mining during ResumeMining event {:?}: {}", event_id, e);
-                        });
-                    }
-                    if *config.cpu_mining_enabled() {
-                        CpuManager::write().await.start_mining().await.unwrap_or_else(|e| {
-                            error!(target: LOG_TARGET_APP_LOGIC, "Failed to start CPU mining during ResumeMining event {:?}: {}", event_id, e);
-                        });
-                    }
+    ) -> Result<Option<String>, SchedulerError> {
+        let effective_mine_event_id =
+            resolve_active_mine_event(&Self::event_infos(events), Self::now())
+                .map(|event| event.id.clone());
+        let Some(event) = events.get_mut(&event_id) else {
+            return Ok(None);
+        };
+        if event.state == SchedulerEventState::Paused {
+            return Ok(None);
+        }
+        // Recurring windows stay Triggered until they close
+        if event.timing.is_persistent() {
+            event.state = SchedulerEventState::Triggered;
+        }
+        match event.event_type.clone() {
+            SchedulerEventType::ResumeMining => {
+                let config = ConfigMining::content().await;
+                let mut errors = Vec::new();
+                if *config.gpu_mining_enabled()
+                    && let Err(e) = GpuManager::write().await.start_mining().await
+                {
+                    errors.push(format!("Failed to start GPU mining: {}", e));
                 }
-                SchedulerEventType::Mine { mining_mode } => {
-                    ConfigMining::update_field(ConfigMiningContent::set_selected_mining_mode, mining_mode.clone()).await.unwrap_or_else(|e| {
-                        error!(target: LOG_TARGET_APP_LOGIC, "Failed to set mining mode during Mine event {:?}: {}", event_id, e);
-                    });
-                    // TODO: Replace with emiting specific value only
-                    let config = ConfigMining::content().await;
-                    EventsEmitter::emit_mining_config_loaded(&config).await;
-                    if *config.gpu_mining_enabled() {
-                        GpuManager::write().await.start_mining().await.unwrap_or_else(|e| {
-                            error!(target: LOG_TARGET_APP_LOGIC, "Failed to start GPU mining during Mine event {:?}: {}", event_id, e);
-                        });
-                    }
-                    if *config.cpu_mining_enabled() {
-                        CpuManager::write().await.start_mining().await.unwrap_or_else(|e| {
-                            error!(target: LOG_TARGET_APP_LOGIC, "Failed to start CPU mining during Mine event {:?}: {}", event_id, e);
-                        });
-                    }
+                if *config.cpu_mining_enabled()
+                    && let Err(e) = CpuManager::write().await.start_mining().await
+                {
+                    errors.push(format!("Failed to start CPU mining: {}", e));
+                }
+                Self::errors_to_result(errors)?;
+                Ok(Some("Started mining".to_string()))
+            }
+            SchedulerEventType::Mine { mining_mode } => {
+                if event.timing.is_persistent()
+                    && let Some(effective_id) = effective_mine_event_id
+                    && effective_id != event_id
+                {
+                    info!(target: LOG_TARGET_APP_LOGIC, "Mine event {:?} opened while higher priority event {:?} is in effect", event_id, effective_id);
+                    return Ok(Some(format!(
+                        "Skipped, higher priority event {:?} is in effect",
+                        effective_id
+                    )));
+                }
+                Self::mine_in_mode(mining_mode.clone(), &event_id).await?;
+                Ok(Some(format!("Mining in {} mode", mining_mode)))
+            }
+            action => {
+                let previous_state = Self::capture_previous_state(&action).await;
+                Self::apply_action(&action).await?;
+                // Keep the state from before the first enter if the window is re-entered without an exit
+                if event.timing.is_persistent() && event.previous_state.is_none() {
+                    event.previous_state = previous_state;
                 }
+                Ok(Some(action.to_string()))
             }
         }
-        Ok(())
     }
 
     /// Internal handler for event exit callbacks.
     ///
     /// Executes cleanup actions when a time window ends (for Between events).
-    /// This typically stops mining operations.
+    /// This typically stops mining operations. Action events restore whatever
+    /// they changed when the window opened. Only runs for windows that were
+    /// entered (`Triggered`); the event goes back to `Active` afterwards.
     ///
     /// ### Parameters
-    /// * `events` - Reference to the events map
+    /// * `events` - Mutable reference to the events map
     /// * `event_id` - ID of the event that is ending
     ///
     /// ### Returns
-    /// * `Ok(())` - Callback executed successfully
-    /// * `Err(SchedulerError)` - If execution fails
+    /// * `Ok(Some(String))` - Description of what was done
+    /// * `Ok(None)` - The window was never entered, so nothing ran
+    /// * `Err(SchedulerError)` - If any part of the cleanup failed
     async fn handle_exit_callback(
-        events: &HashMap<String, ScheduledEvent>,
+        events: &mut HashMap<String, ScheduledEvent>,
         event_id: String,
-    ) -> Result<(), SchedulerError> {
-        if let Some(event) = events.get(&event_id) {
-            match event.event_type.clone() {
-                SchedulerEventType::ResumeMining => {}
-                SchedulerEventType::Mine { mining_mode } => {
-                    GpuManager::write().await.stop_mining().await.unwrap_or_else(|e| {
-                        error!(target: LOG_TARGET_APP_LOGIC, "Failed to stop mining during cleanup of event {:?}: {}", event_id, e);
-                    });
-                    CpuManager::write().await.stop_mining().await.unwrap_or_else(|e| {
-                        error!(target: LOG_TARGET_APP_LOGIC, "Failed to stop mining during cleanup of event {:?}: {}", event_id, e);
-                    });
+    ) -> Result<Option<String>, SchedulerError> {
+        // The closing window may still be open when it is paused, so leave it out of the fallback
+        let mut other_events = Self::event_infos(events);
+        other_events.retain(|event| event.id != event_id);
+        let fallback_mine_event = resolve_active_mine_event(&other_events, Self::now())
+            .map(|event| (event.id.clone(), event.event_type.clone()));
+        // Only windows that were entered have anything to undo
+        let Some(event) = events.get_mut(&event_id) else {
+            return Ok(None);
+        };
+        if event.state != SchedulerEventState::Triggered {
+            return Ok(None);
+        }
+        event.state = SchedulerEventState::Active;
+        match event.event_type.clone() {
+            SchedulerEventType::ResumeMining => Ok(Some("Window closed".to_string())),
+            SchedulerEventType::Mine { .. } => {
+                if let Some((fallback_id, SchedulerEventType::Mine { mining_mode })) =
+                    fallback_mine_event
+                {
+                    info!(target: LOG_TARGET_APP_LOGIC, "Mine event {:?} closed, falling back to {:?}", event_id, fallback_id);
+                    Self::mine_in_mode(mining_mode.clone(), &fallback_id).await?;
+                    return Ok(Some(format!(
+                        "Fell back to {:?} in {} mode",
+                        fallback_id, mining_mode
+                    )));
                 }
+                let mut errors = Vec::new();
+                if let Err(e) = GpuManager::write().await.stop_mining().await {
+                    errors.push(format!("Failed to stop GPU mining: {}", e));
+                }
+                if let Err(e) = CpuManager::write().await.stop_mining().await {
+                    errors.push(format!("Failed to stop CPU mining: {}", e));
+                }
+                Self::errors_to_result(errors)?;
+                Ok(Some("Stopped mining".to_string()))
+            }
+            action => match event.previous_state.take() {
+                Some(previous_state) => {
+                    let restored = format!("Restored state after {}", action);
+                    Self::restore_previous_state(previous_state).await?;
+                    Ok(Some(restored))
+                }
+                None => Ok(Some(format!("Nothing to restore after {}", action))),
+            },
+        }
+    }
+
+    /// Switches to the given mining mode and starts the enabled miners. Miners
+    /// that are already running in another mode are restarted so the new mode applies.
+    async fn mine_in_mode(mining_mode: String, event_id: &str) -> Result<(), SchedulerError> {
+        let mut errors = Vec::new();
+        let mode_changed = *ConfigMining::content().await.selected_mining_mode() != mining_mode;
+        if mode_changed {
+            if GpuManager::read().await.is_running()
+                && let Err(e) = GpuManager::write().await.stop_mining().await
+            {
+                errors.push(format!("Failed to stop GPU mining for mode change: {}", e));
+            }
+        }
+        if let Err(e) = ConfigMining::update_field(
+            ConfigMiningContent::set_selected_mining_mode,
+            mining_mode.clone(),
+        )
+        .await
+        {
+            errors.push(format!("Failed to set mining mode: {}", e));
+        }
+        // A running cpu miner picks up the new mode without being stopped
+        if mode_changed && let Err(e) = CpuManager::write().await.update_mining_mode().await {
+            errors.push(format!("Failed to update CPU mining mode: {}", e));
+        }
+        // TODO: Replace with emiting specific value only
+        let config = ConfigMining::content().await;
+        EventsEmitter::emit_mining_config_loaded(&config).await;
+        if *config.gpu_mining_enabled()
+            && let Err(e) = GpuManager::write().await.start_mining().await
+        {
+            errors.push(format!("Failed to start GPU mining: {}", e));
+        }
+        if *config.cpu_mining_enabled()
+            && let Err(e) = CpuManager::write().await.start_mining().await
+        {
+            errors.push(format!("Failed to start CPU mining: {}", e));
+        }
+        if !errors.is_empty() {
+            error!(target: LOG_TARGET_APP_LOGIC, "Mine event {:?} could not switch to {} mode: {}", event_id, mining_mode, errors.join("; "));
+        }
+        Self::errors_to_result(errors)
+    }
+
+    /// Combines the failures of a multi-step action into one error.
+    fn errors_to_result(errors: Vec<String>) -> Result<(), SchedulerError> {
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(SchedulerError::ActionFailed(errors.join("; ")))
+        }
+    }
+
+    /// Captures the state an action event is about to change.
+    ///
+    /// ### Returns
+    /// * `Some(PreviousState)` - Current value of whatever the action changes
+    /// * `None` - For ResumeMining and Mine, which have their own exit behaviour
+    async fn capture_previous_state(event_type: &SchedulerEventType) -> Option<PreviousState> {
+        match event_type {
+            SchedulerEventType::ResumeMining | SchedulerEventType::Mine { .. } => None,
+            SchedulerEventType::CpuMining { .. } => Some(PreviousState::CpuMining {
+                enabled: *ConfigMining::content().await.cpu_mining_enabled(),
+                running: CpuManager::read().await.is_running(),
+            }),
+            SchedulerEventType::GpuMining { .. } => Some(PreviousState::GpuMining {
+                enabled: *ConfigMining::content().await.gpu_mining_enabled(),
+                running: GpuManager::read().await.is_running(),
+            }),
+            SchedulerEventType::SwitchCpuPool { .. } => Some(PreviousState::CpuPool(
+                ConfigPools::content()
+                    .await
+                    .current_cpu_pool()
+                    .pool_type
+                    .to_string(),
+            )),
+            SchedulerEventType::SwitchGpuPool { .. } => Some(PreviousState::GpuPool(
+                ConfigPools::content()
+                    .await
+                    .current_gpu_pool()
+                    .pool_type
+                    .to_string(),
+            )),
+            SchedulerEventType::CpuPoolMining { .. } => Some(PreviousState::CpuPoolMining(
+                *ConfigPools::content().await.cpu_pool_enabled(),
+            )),
+            SchedulerEventType::GpuPoolMining { .. } => Some(PreviousState::GpuPoolMining(
+                *ConfigPools::content().await.gpu_pool_enabled(),
+            )),
+            SchedulerEventType::SwitchNodeType { .. } => Some(PreviousState::NodeType(
+                ConfigCore::content().await.node_type().clone(),
+            )),
+        }
+    }
+
+    /// Applies an action event. ResumeMining and Mine are handled directly by the callbacks.
+    async fn apply_action(event_type: &SchedulerEventType) -> Result<(), SchedulerError> {
+        match event_type {
+            SchedulerEventType::ResumeMining | SchedulerEventType::Mine { .. } => Ok(()),
+            SchedulerEventType::CpuMining { enabled } => {
+                Self::set_cpu_mining(*enabled, *enabled).await
+            }
+            SchedulerEventType::GpuMining { enabled } => {
+                Self::set_gpu_mining(*enabled, *enabled).await
+            }
+            SchedulerEventType::SwitchCpuPool { pool_name } => {
+                Self::switch_cpu_pool(pool_name.clone()).await
+            }
+            SchedulerEventType::SwitchGpuPool { pool_name } => {
+                Self::switch_gpu_pool(pool_name.clone()).await
+            }
+            SchedulerEventType::CpuPoolMining { enabled } => {
+                Self::set_cpu_pool_mining(*enabled).await
+            }
+            SchedulerEventType::GpuPoolMining { enabled } => {
+                Self::set_gpu_pool_mining(*enabled).await
+            }
+            SchedulerEventType::SwitchNodeType { node_type } => {
+                Self::switch_node_type(node_type.clone()).await
             }
         }
+    }
+
+    /// Puts back the state captured by `capture_previous_state`.
+    async fn restore_previous_state(previous_state: PreviousState) -> Result<(), SchedulerError> {
+        info!(target: LOG_TARGET_APP_LOGIC, "Restoring state after scheduled window: {:?}", previous_state);
+        match previous_state {
+            PreviousState::CpuMining { enabled, running } => {
+                Self::set_cpu_mining(enabled, running).await
+            }
+            PreviousState::GpuMining { enabled, running } => {
+                Self::set_gpu_mining(enabled, running).await
+            }
+            PreviousState::CpuPool(pool_name) => Self::switch_cpu_pool(pool_name).await,
+            PreviousState::GpuPool(pool_name) => Self::switch_gpu_pool(pool_name).await,
+            PreviousState::CpuPoolMining(enabled) => Self::set_cpu_pool_mining(enabled).await,
+            PreviousState::GpuPoolMining(enabled) => Self::set_gpu_pool_mining(enabled).await,
+            PreviousState::NodeType(node_type) => Self::switch_node_type(node_type).await,
+        }
+    }
+
+    async fn set_cpu_mining(enabled: bool, running: bool) -> Result<(), SchedulerError> {
+        commands::set_cpu_mining_enabled(enabled)
+            .await
+            .map_err(Self::map_invoke_error)?;
+        let mut cpu_manager = CpuManager::write().await;
+        let result = if running {
+            cpu_manager.start_mining().await
+        } else {
+            cpu_manager.stop_mining().await
+        };
+        drop(cpu_manager);
+        result.map_err(|e| SchedulerError::InternalError(e.to_string()))?;
+        EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+        Ok(())
+    }
+
+    async fn set_gpu_mining(enabled: bool, running: bool) -> Result<(), SchedulerError> {
+        commands::set_gpu_mining_enabled(enabled)
+            .await
+            .map_err(Self::map_invoke_error)?;
+        let mut gpu_manager = GpuManager::write().await;
+        let result = if running {
+            gpu_manager.start_mining().await
+        } else {
+            gpu_manager.stop_mining().await
+        };
+        drop(gpu_manager);
+        result.map_err(|e| SchedulerError::InternalError(e.to_string()))?;
+        EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+        Ok(())
+    }
+
+    /// Running miners are restarted so they mine on the new pool, also when the
+    /// window closes and the previous pool is restored.
+    async fn switch_cpu_pool(pool_name: String) -> Result<(), SchedulerError> {
+        pools::change_cpu_pool(pool_name)
+            .await
+            .map_err(SchedulerError::ActionFailed)?;
+        EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await).await;
+        Ok(())
+    }
+
+    async fn switch_gpu_pool(pool_name: String) -> Result<(), SchedulerError> {
+        pools::change_gpu_pool(pool_name)
+            .await
+            .map_err(SchedulerError::ActionFailed)?;
+        EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await).await;
+        Ok(())
+    }
+
+    async fn set_cpu_pool_mining(enabled: bool) -> Result<(), SchedulerError> {
+        commands::toggle_cpu_pool_mining(enabled)
+            .await
+            .map_err(SchedulerError::InternalError)?;
+        EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await).await;
         Ok(())
     }
 
+    async fn set_gpu_pool_mining(enabled: bool) -> Result<(), SchedulerError> {
+        commands::toggle_gpu_pool_mining(enabled)
+            .await
+            .map_err(SchedulerError::InternalError)?;
+        EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await).await;
+        Ok(())
+    }
+
+    async fn switch_node_type(node_type: NodeType) -> Result<(), SchedulerError> {
+        let app_handle = EventsEmitter::get_app_handle_public().await;
+        commands::set_node_type(
+            node_type,
+            app_handle.state::<UniverseAppState>(),
+            app_handle.clone(),
+        )
+        .await
+        .map_err(Self::map_invoke_error)?;
+        EventsEmitter::emit_core_config_loaded(&ConfigCore::content().await).await;
+        Ok(())
+    }
+
+    fn map_invoke_error(error: InvokeError) -> SchedulerError {
+        SchedulerError::InternalError(error.0.to_string())
+    }
+
     /// Internal handler for cleaning up completed one-time events.
     ///
     /// Removes "In" timing events after they execute, as they are not recurring.
@@ -1159,7 +2625,7 @@ impl EventScheduler {
             && let SchedulerEventTiming::In(_) = event.timing
         {
             info!(target: LOG_TARGET_APP_LOGIC, "Cleaning up schedule for event ID {:?}", event_id);
-            if let Err(e) = Self::handle_remove_event(events, event_id.clone()) {
+            if let Err(e) = Self::handle_remove_event(events, event_id.clone()).await {
                 error!(target: LOG_TARGET_APP_LOGIC, "Failed to clean up scheduled event {:?}: {}", event_id, e);
             }
         }
@@ -1175,6 +2641,7 @@ impl EventScheduler {
     /// * `event_id` - Unique identifier for the event
     /// * `_event_type` - Type of event (not used in scheduling logic, only for identification)
     /// * `timing` - When the event should be triggered
+    /// * `scheduled_at` - When the event was scheduled; "In" delays count from here
     ///
     /// ### Returns
     /// * `Ok(JoinHandle)` - Handle to the spawned scheduling task
@@ -1183,12 +2650,15 @@ impl EventScheduler {
         event_id: String,
         _event_type: SchedulerEventType, // Not used in scheduling logic, only for identification
         timing: SchedulerEventTiming,
+        scheduled_at: DateTime<Local>,
     ) -> Result<tokio::task::JoinHandle<()>, SchedulerError> {
         let handle = match timing {
             SchedulerEventTiming::In(in_variant_payload) => tokio::spawn(async move {
                 let duration = in_variant_payload.to_duration();
                 if let Ok(duration) = duration {
-                    sleep(duration.to_std().unwrap_or_default()).await;
+                    // Count from when the event was scheduled so rescheduled timers don't restart the delay
+                    let remaining = scheduled_at + duration - Self::now();
+                    sleep(remaining.to_std().unwrap_or_default()).await;
 
                     let _unused = INSTANCE
                         .message_sender
@@ -1213,11 +2683,11 @@ impl EventScheduler {
                 let copied_cron_schedule = cron_schedule.clone();
                 let copied_event_id = event_id.clone();
 
-                tokio::spawn(async move {
+                let start_loop = async move {
                     let cron_schedule = copied_cron_schedule.clone();
                     let event_id = copied_event_id.clone();
                     loop {
-                        let local_now = Local::now();
+                        let local_now = Self::now();
 
                         if let Some(next_start_wait_time) =
                             cron_schedule.find_next_start_wait_time(local_now)
@@ -1235,11 +2705,11 @@ impl EventScheduler {
                                     event_id: event_id.clone(),
                                 });
                     }
-                });
-                tokio::spawn(async move {
+                };
+                let end_loop = async move {
                     let cron_schedule = cron_schedule.clone();
                     loop {
-                        let local_now = Local::now();
+                        let local_now = Self::now();
                         // Now wait until end time, eg. currently is 10AM and the range is 9AM - 11AM, then we wait until 11AM
                         if let Some(next_end_wait_time) =
                             cron_schedule.find_next_end_wait_time(local_now)
@@ -1250,8 +2720,14 @@ impl EventScheduler {
                                     event_id: event_id.clone(),
                                 },
                             );
+                        } else {
+                            break;
                         }
                     }
+                };
+                // Both loops live in one task so aborting the handle stops them together
+                tokio::spawn(async move {
+                    tokio::join!(start_loop, end_loop);
                 })
             }
         };
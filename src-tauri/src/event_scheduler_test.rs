@@ -20,12 +20,19 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use chrono::Local;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Duration, Local, NaiveDate, TimeZone};
 use test_case::test_case;
 
 use crate::event_scheduler::{
-    BetweenTimeVariantPayload, CronSchedule, InVariantPayload, SchedulerError, TimePeriod, TimeUnit,
+    BetweenTimeVariantPayload, CatchUpAction, CatchUpReason, ClockJumpDetector, CronSchedule,
+    DayOfWeek, ExecutionPhase, ExecutionRecord, InVariantPayload, ScheduleDateRange,
+    ScheduledEventInfo, SchedulerClock, SchedulerError, SchedulerEventState, SchedulerEventTiming,
+    SchedulerEventType, TimePeriod, TimeUnit, find_overlapping_event, plan_catch_up,
+    resolve_active_mine_event,
 };
+use crate::node::node_manager::NodeType;
 
 // =============================================================================
 // CronSchedule::new tests
@@ -368,6 +375,8 @@ fn between_payload_am_pm_conversion() {
             end_hour,
             end_minute: 0,
             end_period: end_period.clone(),
+            weekdays: Vec::new(),
+            date_range: None,
         };
 
         let result = payload.to_cron_schedule();
@@ -408,6 +417,8 @@ fn between_payload_to_cron_schedule_valid() {
         end_hour: 5,
         end_minute: 0,
         end_period: TimePeriod::PM,
+        weekdays: Vec::new(),
+        date_range: None,
     };
 
     let result = payload.to_cron_schedule();
@@ -440,6 +451,8 @@ fn between_payload_invalid_hour_returns_error() {
             end_hour: 6,
             end_minute: 0,
             end_period: TimePeriod::AM,
+            weekdays: Vec::new(),
+            date_range: None,
         },
         BetweenTimeVariantPayload {
             start_hour: 13,
@@ -448,6 +461,8 @@ fn between_payload_invalid_hour_returns_error() {
             end_hour: 6,
             end_minute: 0,
             end_period: TimePeriod::AM,
+            weekdays: Vec::new(),
+            date_range: None,
         },
         BetweenTimeVariantPayload {
             start_hour: 10,
@@ -456,6 +471,8 @@ fn between_payload_invalid_hour_returns_error() {
             end_hour: 0,
             end_minute: 0,
             end_period: TimePeriod::AM,
+            weekdays: Vec::new(),
+            date_range: None,
         },
     ];
 
@@ -478,6 +495,8 @@ fn between_payload_invalid_minute_returns_error() {
         end_hour: 6,
         end_minute: 0,
         end_period: TimePeriod::AM,
+        weekdays: Vec::new(),
+        date_range: None,
     };
 
     let result = payload.to_cron_schedule();
@@ -488,3 +507,732 @@ fn between_payload_invalid_minute_returns_error() {
         result
     );
 }
+
+// =============================================================================
+// SchedulerEventTiming::parse_between_variant_24h tests
+// =============================================================================
+
+#[test_case(0, 12, "AM" ; "midnight")]
+#[test_case(6, 6, "AM" ; "morning")]
+#[test_case(12, 12, "PM" ; "noon")]
+#[test_case(22, 10, "PM" ; "evening")]
+fn between_24h_converts_to_12h_payload(hour: i64, expected_hour: i64, expected_period: &str) {
+    let timing = SchedulerEventTiming::parse_between_variant_24h(hour, 15, 23, 0)
+        .expect("Valid 24-hour window");
+
+    let SchedulerEventTiming::Between(payload) = timing else {
+        panic!("Expected Between timing");
+    };
+    assert_eq!(payload.start_hour, expected_hour);
+    assert_eq!(payload.start_minute, 15);
+    assert_eq!(format!("{:?}", payload.start_period), expected_period);
+    assert_eq!(
+        payload
+            .to_cron_schedule()
+            .unwrap()
+            .start_time
+            .pattern
+            .to_string(),
+        format!("15 {} * * *", hour)
+    );
+}
+
+#[test_case(24, 0 ; "hour too large")]
+#[test_case(-1, 0 ; "negative hour")]
+#[test_case(10, 60 ; "minute too large")]
+fn between_24h_out_of_range_returns_error(hour: i64, minute: i64) {
+    let result = SchedulerEventTiming::parse_between_variant_24h(hour, minute, 6, 0);
+
+    assert!(
+        matches!(result, Err(SchedulerError::InvalidTimingFormat(_))),
+        "Expected InvalidTimingFormat error for {}:{}, but got: {:?}",
+        hour,
+        minute,
+        result
+    );
+}
+
+// =============================================================================
+// SchedulerEventTiming::next_activation tests
+// =============================================================================
+
+#[test]
+fn in_timing_next_activation_is_delay_after_scheduling() {
+    let timing = SchedulerEventTiming::parse_in_variant(30, TimeUnit::Minutes).unwrap();
+    let scheduled_at = Local::now();
+
+    let next = timing.next_activation(scheduled_at, scheduled_at + Duration::minutes(10));
+
+    assert_eq!(next, Some(scheduled_at + Duration::minutes(30)));
+}
+
+#[test]
+fn in_timing_has_no_next_activation_once_elapsed() {
+    let timing = SchedulerEventTiming::parse_in_variant(30, TimeUnit::Minutes).unwrap();
+    let scheduled_at = Local::now();
+
+    let next = timing.next_activation(scheduled_at, scheduled_at + Duration::minutes(31));
+
+    assert!(next.is_none());
+}
+
+#[test]
+fn between_timing_next_activation_is_next_window_start() {
+    let timing = SchedulerEventTiming::parse_between_variant_24h(22, 0, 6, 0).unwrap();
+    let now = Local.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+
+    let next = timing.next_activation(now, now);
+
+    assert_eq!(next, Local.with_ymd_and_hms(2025, 3, 10, 22, 0, 0).single());
+}
+
+// =============================================================================
+// Weekday and date range tests
+// =============================================================================
+
+const WEEKDAYS: [DayOfWeek; 5] = [
+    DayOfWeek::Monday,
+    DayOfWeek::Tuesday,
+    DayOfWeek::Wednesday,
+    DayOfWeek::Thursday,
+    DayOfWeek::Friday,
+];
+
+fn december() -> ScheduleDateRange {
+    ScheduleDateRange {
+        start_month: 12,
+        start_day: 1,
+        end_month: 12,
+        end_day: 31,
+    }
+}
+
+fn between(start_hour: i64, end_hour: i64) -> BetweenTimeVariantPayload {
+    let SchedulerEventTiming::Between(payload) =
+        SchedulerEventTiming::parse_between_variant_24h(start_hour, 0, end_hour, 0).unwrap()
+    else {
+        panic!("Expected Between timing");
+    };
+    payload
+}
+
+#[test]
+fn weekday_window_on_same_day_uses_same_days_for_end() {
+    let mut payload = between(9, 17);
+    payload.weekdays = WEEKDAYS.to_vec();
+
+    let schedule = payload.to_cron_schedule().unwrap();
+
+    assert_eq!(schedule.start_time.pattern.to_string(), "0 9 * * 1,2,3,4,5");
+    assert_eq!(schedule.end_time.pattern.to_string(), "0 17 * * 1,2,3,4,5");
+}
+
+#[test]
+fn weekday_window_crossing_midnight_ends_on_following_days() {
+    let mut payload = between(22, 7);
+    payload.weekdays = WEEKDAYS.to_vec();
+
+    let schedule = payload.to_cron_schedule().unwrap();
+
+    assert_eq!(
+        schedule.start_time.pattern.to_string(),
+        "0 22 * * 1,2,3,4,5"
+    );
+    assert_eq!(schedule.end_time.pattern.to_string(), "0 7 * * 2,3,4,5,6");
+}
+
+#[test]
+fn full_day_weekend_window_ends_next_midnight() {
+    let mut payload = between(0, 0);
+    payload.weekdays = vec![DayOfWeek::Sunday, DayOfWeek::Saturday];
+
+    let schedule = payload.to_cron_schedule().unwrap();
+
+    assert_eq!(schedule.start_time.pattern.to_string(), "0 0 * * 0,6");
+    assert_eq!(schedule.end_time.pattern.to_string(), "0 0 * * 0,1");
+}
+
+#[test]
+fn weekday_window_friday_night_runs_into_saturday() {
+    let mut payload = between(22, 7);
+    payload.weekdays = WEEKDAYS.to_vec();
+    let schedule = payload.to_cron_schedule().unwrap();
+    // 2025-03-07 is a Friday
+    let friday_night = Local.with_ymd_and_hms(2025, 3, 7, 23, 0, 0).unwrap();
+    let saturday_noon = Local.with_ymd_and_hms(2025, 3, 8, 12, 0, 0).unwrap();
+
+    assert_eq!(
+        schedule.find_next_end_time(friday_night),
+        Local.with_ymd_and_hms(2025, 3, 8, 7, 0, 0).single(),
+        "Friday's window should end on Saturday morning"
+    );
+    assert_eq!(
+        schedule.find_next_start_time(saturday_noon),
+        Local.with_ymd_and_hms(2025, 3, 10, 22, 0, 0).single(),
+        "No window should start over the weekend"
+    );
+}
+
+#[test_case(12, 15, true ; "inside single month")]
+#[test_case(11, 30, false ; "day before")]
+#[test_case(1, 1, false ; "after year end")]
+fn date_range_contains(month: u32, day: u32, expected: bool) {
+    let date = NaiveDate::from_ymd_opt(2025, month, day).unwrap();
+    assert_eq!(december().contains(date), expected);
+}
+
+#[test]
+fn date_range_wrapping_new_year_contains_both_ends() {
+    let range = ScheduleDateRange {
+        start_month: 12,
+        start_day: 15,
+        end_month: 1,
+        end_day: 15,
+    };
+
+    assert!(range.contains(NaiveDate::from_ymd_opt(2025, 12, 20).unwrap()));
+    assert!(range.contains(NaiveDate::from_ymd_opt(2026, 1, 10).unwrap()));
+    assert!(!range.contains(NaiveDate::from_ymd_opt(2026, 1, 16).unwrap()));
+    assert!(!range.contains(NaiveDate::from_ymd_opt(2025, 12, 14).unwrap()));
+}
+
+#[test]
+fn date_range_with_invalid_day_returns_error() {
+    let range = ScheduleDateRange {
+        start_month: 2,
+        start_day: 30,
+        end_month: 3,
+        end_day: 1,
+    };
+
+    let result = SchedulerEventTiming::parse_between_variant_24h(22, 0, 6, 0)
+        .unwrap()
+        .with_calendar(Vec::new(), Some(range));
+
+    assert!(matches!(
+        result,
+        Err(SchedulerError::InvalidTimingFormat(_))
+    ));
+}
+
+#[test]
+fn date_range_window_skips_to_range_start() {
+    let mut payload = between(22, 6);
+    payload.date_range = Some(december());
+    let schedule = payload.to_cron_schedule().unwrap();
+    let march = Local.with_ymd_and_hms(2025, 3, 10, 12, 0, 0).unwrap();
+
+    assert_eq!(
+        schedule.find_next_start_time(march),
+        Local.with_ymd_and_hms(2025, 12, 1, 22, 0, 0).single()
+    );
+    assert_eq!(
+        schedule.find_next_end_time(march),
+        Local.with_ymd_and_hms(2025, 12, 2, 6, 0, 0).single(),
+        "No window should end before the first one in the range has started"
+    );
+}
+
+#[test]
+fn date_range_last_window_runs_past_range_end() {
+    let mut payload = between(22, 6);
+    payload.date_range = Some(december());
+    let schedule = payload.to_cron_schedule().unwrap();
+    let new_years_eve = Local.with_ymd_and_hms(2025, 12, 31, 23, 0, 0).unwrap();
+
+    assert_eq!(
+        schedule.find_next_end_time(new_years_eve),
+        Local.with_ymd_and_hms(2026, 1, 1, 6, 0, 0).single()
+    );
+    assert_eq!(
+        schedule.find_next_start_time(new_years_eve),
+        Local.with_ymd_and_hms(2026, 12, 1, 22, 0, 0).single()
+    );
+}
+
+#[test]
+fn with_calendar_rejects_in_timing() {
+    let result = SchedulerEventTiming::parse_in_variant(5, TimeUnit::Minutes)
+        .unwrap()
+        .with_calendar(WEEKDAYS.to_vec(), None);
+
+    assert!(matches!(
+        result,
+        Err(SchedulerError::InvalidTimingFormat(_))
+    ));
+}
+
+#[test]
+fn persisted_between_payload_without_calendar_fields_migrates() {
+    let json = r#"{"Between":{"start_hour":10,"start_minute":0,"start_period":"PM","end_hour":6,"end_minute":0,"end_period":"AM"}}"#;
+
+    let timing: SchedulerEventTiming = serde_json::from_str(json).unwrap();
+
+    let SchedulerEventTiming::Between(payload) = timing else {
+        panic!("Expected Between timing");
+    };
+    assert!(payload.weekdays.is_empty());
+    assert!(payload.date_range.is_none());
+    let schedule = payload.to_cron_schedule().unwrap();
+    assert_eq!(schedule.start_time.pattern.to_string(), "0 22 * * *");
+    assert_eq!(schedule.end_time.pattern.to_string(), "0 6 * * *");
+}
+
+// =============================================================================
+// SchedulerEventType serialization tests
+// =============================================================================
+
+#[test]
+fn action_event_types_round_trip_through_config_format() {
+    let cases = [
+        (
+            SchedulerEventType::GpuMining { enabled: true },
+            r#"{"GpuMining":{"enabled":true}}"#,
+        ),
+        (
+            SchedulerEventType::SwitchCpuPool {
+                pool_name: "LuckyPoolRANDOMX".to_string(),
+            },
+            r#"{"SwitchCpuPool":{"pool_name":"LuckyPoolRANDOMX"}}"#,
+        ),
+        (
+            SchedulerEventType::SwitchNodeType {
+                node_type: NodeType::Remote,
+            },
+            r#"{"SwitchNodeType":{"node_type":"Remote"}}"#,
+        ),
+        (SchedulerEventType::ResumeMining, r#""ResumeMining""#),
+    ];
+
+    for (event_type, json) in cases {
+        assert_eq!(serde_json::to_string(&event_type).unwrap(), json);
+        let parsed: SchedulerEventType = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed, event_type);
+    }
+}
+
+// =============================================================================
+// Overlap detection and priority resolution tests
+// =============================================================================
+
+fn mine_window(
+    id: &str,
+    mode: &str,
+    start_hour: i64,
+    end_hour: i64,
+    priority: u32,
+) -> ScheduledEventInfo {
+    ScheduledEventInfo {
+        id: id.to_string(),
+        event_type: SchedulerEventType::Mine {
+            mining_mode: mode.to_string(),
+        },
+        timing: SchedulerEventTiming::parse_between_variant_24h(start_hour, 0, end_hour, 0)
+            .unwrap(),
+        state: SchedulerEventState::Active,
+        next_activation: None,
+        priority,
+        catch_ups: Vec::new(),
+        journal: Vec::new(),
+    }
+}
+
+fn march_10_at(hour: u32) -> chrono::DateTime<Local> {
+    Local.with_ymd_and_hms(2025, 3, 10, hour, 0, 0).unwrap()
+}
+
+#[test]
+fn is_active_at_includes_start_and_excludes_end() {
+    let schedule = between(22, 6).to_cron_schedule().unwrap();
+
+    assert!(schedule.is_active_at(march_10_at(22)));
+    assert!(schedule.is_active_at(march_10_at(23)));
+    assert!(!schedule.is_active_at(march_10_at(6)));
+    assert!(!schedule.is_active_at(march_10_at(12)));
+}
+
+#[test]
+fn overlapping_mine_windows_at_same_priority_are_rejected() {
+    let existing = vec![mine_window("night", "Eco", 22, 6, 0)];
+    let candidate = mine_window("late", "Ludicrous", 2, 8, 0);
+
+    let overlapping = find_overlapping_event(&existing, &candidate, march_10_at(12));
+
+    assert_eq!(overlapping.map(|event| event.id.as_str()), Some("night"));
+}
+
+#[test]
+fn adjacent_and_disjoint_windows_do_not_overlap() {
+    let existing = vec![
+        mine_window("night", "Eco", 22, 6, 0),
+        mine_window("evening", "Turbo", 18, 22, 0),
+    ];
+    let candidate = mine_window("day", "Ludicrous", 9, 17, 0);
+
+    assert!(find_overlapping_event(&existing, &candidate, march_10_at(12)).is_none());
+}
+
+#[test]
+fn overlapping_mine_windows_with_different_priorities_are_allowed() {
+    let existing = vec![mine_window("night", "Eco", 22, 6, 0)];
+    let candidate = mine_window("late", "Ludicrous", 2, 8, 1);
+
+    assert!(find_overlapping_event(&existing, &candidate, march_10_at(12)).is_none());
+}
+
+#[test]
+fn windows_on_different_weekdays_do_not_overlap() {
+    let mut weekday = mine_window("weekday", "Eco", 9, 17, 0);
+    weekday.timing = weekday
+        .timing
+        .with_calendar(WEEKDAYS.to_vec(), None)
+        .unwrap();
+    let mut weekend = mine_window("weekend", "Ludicrous", 8, 20, 0);
+    weekend.timing = weekend
+        .timing
+        .with_calendar(vec![DayOfWeek::Saturday, DayOfWeek::Sunday], None)
+        .unwrap();
+
+    assert!(find_overlapping_event(&[weekday], &weekend, march_10_at(12)).is_none());
+}
+
+#[test]
+fn overlapping_action_windows_are_rejected_regardless_of_priority() {
+    let gpu_window = |id: &str, start_hour: i64, end_hour: i64, priority: u32| ScheduledEventInfo {
+        event_type: SchedulerEventType::GpuMining { enabled: true },
+        ..mine_window(id, "", start_hour, end_hour, priority)
+    };
+    let existing = vec![gpu_window("gpu_night", 22, 6, 0)];
+
+    let overlapping =
+        find_overlapping_event(&existing, &gpu_window("gpu_late", 2, 8, 5), march_10_at(12));
+    assert_eq!(
+        overlapping.map(|event| event.id.as_str()),
+        Some("gpu_night")
+    );
+
+    let cpu_window = ScheduledEventInfo {
+        event_type: SchedulerEventType::CpuMining { enabled: true },
+        ..mine_window("cpu_late", "", 2, 8, 0)
+    };
+    assert!(find_overlapping_event(&existing, &cpu_window, march_10_at(12)).is_none());
+}
+
+#[test]
+fn replacing_an_event_does_not_overlap_itself() {
+    let existing = vec![mine_window("night", "Eco", 22, 6, 0)];
+    let candidate = mine_window("night", "Ludicrous", 23, 7, 0);
+
+    assert!(find_overlapping_event(&existing, &candidate, march_10_at(12)).is_none());
+}
+
+#[test]
+fn resolver_picks_highest_priority_open_window() {
+    let events = vec![
+        mine_window("night", "Eco", 22, 6, 0),
+        mine_window("late", "Ludicrous", 2, 4, 1),
+    ];
+
+    let at = |hour| {
+        resolve_active_mine_event(
+            &events,
+            Local.with_ymd_and_hms(2025, 3, 11, hour, 0, 0).unwrap(),
+        )
+        .map(|event| event.id.as_str())
+    };
+
+    assert_eq!(at(1), Some("night"));
+    assert_eq!(at(3), Some("late"));
+    assert_eq!(
+        at(4),
+        Some("night"),
+        "Falls back when the higher window closes"
+    );
+    assert_eq!(at(6), None, "Nothing is open once both windows have closed");
+}
+
+#[test]
+fn resolver_ignores_paused_events() {
+    let mut late = mine_window("late", "Ludicrous", 2, 4, 1);
+    late.state = SchedulerEventState::Paused;
+    let events = vec![mine_window("night", "Eco", 22, 6, 0), late];
+
+    let effective = resolve_active_mine_event(
+        &events,
+        Local.with_ymd_and_hms(2025, 3, 11, 3, 0, 0).unwrap(),
+    );
+
+    assert_eq!(effective.map(|event| event.id.as_str()), Some("night"));
+}
+
+// =============================================================================
+// Catch-up tests
+// =============================================================================
+
+struct ManualClock(Mutex<DateTime<Local>>);
+
+impl ManualClock {
+    fn at(now: DateTime<Local>) -> Arc<Self> {
+        Arc::new(Self(Mutex::new(now)))
+    }
+
+    fn set(&self, now: DateTime<Local>) {
+        *self.0.lock().unwrap() = now;
+    }
+}
+
+impl SchedulerClock for ManualClock {
+    fn now(&self) -> DateTime<Local> {
+        *self.0.lock().unwrap()
+    }
+}
+
+fn entered(mut event: ScheduledEventInfo) -> ScheduledEventInfo {
+    event.state = SchedulerEventState::Triggered;
+    event
+}
+
+#[test]
+fn catch_up_enters_window_opened_while_away() {
+    let events = vec![mine_window("night", "Eco", 22, 6, 0)];
+
+    let plan = plan_catch_up(&events, ManualClock::at(march_10_at(23)).as_ref());
+
+    assert_eq!(plan, vec![("night".to_string(), CatchUpAction::Enter)]);
+}
+
+#[test]
+fn catch_up_exits_window_that_closed_while_away() {
+    let events = vec![entered(mine_window("night", "Eco", 22, 6, 0))];
+
+    let plan = plan_catch_up(&events, ManualClock::at(march_10_at(12)).as_ref());
+
+    assert_eq!(plan, vec![("night".to_string(), CatchUpAction::Exit)]);
+}
+
+#[test]
+fn catch_up_leaves_windows_in_step_with_the_clock() {
+    let events = vec![
+        entered(mine_window("night", "Eco", 22, 6, 0)),
+        mine_window("day", "Ludicrous", 9, 17, 0),
+    ];
+
+    let plan = plan_catch_up(&events, ManualClock::at(march_10_at(23)).as_ref());
+
+    assert!(plan.is_empty());
+}
+
+#[test]
+fn catch_up_ignores_paused_and_one_time_events() {
+    let mut paused = mine_window("night", "Eco", 22, 6, 0);
+    paused.state = SchedulerEventState::Paused;
+    let one_time = ScheduledEventInfo {
+        timing: SchedulerEventTiming::In(InVariantPayload {
+            time_value: 1,
+            time_unit: TimeUnit::Minutes,
+        }),
+        ..mine_window("soon", "Eco", 0, 1, 0)
+    };
+
+    let plan = plan_catch_up(
+        &[paused, one_time],
+        ManualClock::at(march_10_at(23)).as_ref(),
+    );
+
+    assert!(plan.is_empty());
+}
+
+#[test_case(SchedulerEventState::Active, SchedulerEventState::Active)]
+#[test_case(SchedulerEventState::Paused, SchedulerEventState::Paused)]
+#[test_case(SchedulerEventState::Triggered, SchedulerEventState::Triggered)]
+#[test_case(SchedulerEventState::Completed, SchedulerEventState::Active)]
+fn saved_state_is_restored_after_restart(
+    saved: SchedulerEventState,
+    expected: SchedulerEventState,
+) {
+    let mut event = mine_window("night", "Eco", 22, 6, 0);
+    event.state = saved;
+
+    let json = serde_json::to_value(&event).unwrap();
+    let parsed: ScheduledEventInfo = serde_json::from_value(json).unwrap();
+
+    assert_eq!(parsed.state.restored(), expected);
+}
+
+#[test]
+fn catch_up_exits_before_entering() {
+    let events = vec![
+        mine_window("evening", "Ludicrous", 18, 23, 0),
+        entered(mine_window("day", "Eco", 9, 17, 0)),
+    ];
+
+    let plan = plan_catch_up(&events, ManualClock::at(march_10_at(20)).as_ref());
+
+    assert_eq!(
+        plan,
+        vec![
+            ("day".to_string(), CatchUpAction::Exit),
+            ("evening".to_string(), CatchUpAction::Enter),
+        ]
+    );
+}
+
+#[test]
+fn catch_up_follows_the_clock_across_a_jump() {
+    let clock = ManualClock::at(march_10_at(12));
+    let events = vec![mine_window("night", "Eco", 22, 6, 0)];
+    assert!(plan_catch_up(&events, clock.as_ref()).is_empty());
+
+    clock.set(march_10_at(23));
+
+    assert_eq!(
+        plan_catch_up(&events, clock.as_ref()),
+        vec![("night".to_string(), CatchUpAction::Enter)]
+    );
+}
+
+#[test]
+fn clock_jump_detector_ignores_normal_progress() {
+    let clock = ManualClock::at(march_10_at(12));
+    let start = std::time::Instant::now();
+    let mut detector = ClockJumpDetector::new(clock.clone(), start);
+
+    clock.set(march_10_at(12) + Duration::seconds(30));
+
+    assert_eq!(
+        detector.check(start + std::time::Duration::from_secs(30)),
+        None
+    );
+}
+
+#[test_case(Duration::hours(1) ; "forward jump")]
+#[test_case(Duration::hours(-1) ; "backward jump")]
+fn clock_jump_detector_reports_wall_clock_jumps(jump: Duration) {
+    let clock = ManualClock::at(march_10_at(12));
+    let start = std::time::Instant::now();
+    let mut detector = ClockJumpDetector::new(clock.clone(), start);
+
+    clock.set(march_10_at(12) + Duration::seconds(30) + jump);
+
+    assert_eq!(
+        detector.check(start + std::time::Duration::from_secs(30)),
+        Some(jump)
+    );
+}
+
+#[test]
+fn clock_jump_detector_measures_from_the_last_check() {
+    let clock = ManualClock::at(march_10_at(12));
+    let start = std::time::Instant::now();
+    let mut detector = ClockJumpDetector::new(clock.clone(), start);
+
+    clock.set(march_10_at(14));
+    assert!(
+        detector
+            .check(start + std::time::Duration::from_secs(30))
+            .is_some()
+    );
+
+    clock.set(march_10_at(14) + Duration::seconds(30));
+    assert_eq!(
+        detector.check(start + std::time::Duration::from_secs(60)),
+        None
+    );
+}
+
+// =============================================================================
+// Execution journal and upcoming activation tests
+// =============================================================================
+
+#[test]
+fn next_start_times_lists_consecutive_window_starts() {
+    let schedule = between(22, 6).to_cron_schedule().unwrap();
+
+    let starts = schedule.next_start_times(march_10_at(12), 3);
+
+    assert_eq!(
+        starts,
+        vec![
+            march_10_at(22),
+            march_10_at(22) + Duration::days(1),
+            march_10_at(22) + Duration::days(2),
+        ]
+    );
+}
+
+#[test]
+fn next_end_times_include_the_end_of_the_open_window() {
+    let schedule = between(22, 6).to_cron_schedule().unwrap();
+
+    let ends = schedule.next_end_times(march_10_at(23), 2);
+
+    assert_eq!(
+        ends,
+        vec![
+            Local.with_ymd_and_hms(2025, 3, 11, 6, 0, 0).unwrap(),
+            Local.with_ymd_and_hms(2025, 3, 12, 6, 0, 0).unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn next_activations_skip_days_outside_the_calendar() {
+    let payload = BetweenTimeVariantPayload {
+        weekdays: vec![DayOfWeek::Saturday, DayOfWeek::Sunday],
+        ..between(9, 17)
+    };
+    let schedule = payload.to_cron_schedule().unwrap();
+    // Monday 10 March 2025
+    let starts = schedule.next_start_times(march_10_at(12), 3);
+    let ends = schedule.next_end_times(march_10_at(12), 3);
+
+    let day = |d| Local.with_ymd_and_hms(2025, 3, d, 9, 0, 0).unwrap();
+    assert_eq!(starts, vec![day(15), day(16), day(22)]);
+    assert_eq!(
+        ends,
+        starts
+            .iter()
+            .map(|start| *start + Duration::hours(8))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn next_activations_with_zero_count_are_empty() {
+    let schedule = between(22, 6).to_cron_schedule().unwrap();
+
+    assert!(schedule.next_start_times(march_10_at(12), 0).is_empty());
+    assert!(schedule.next_end_times(march_10_at(12), 0).is_empty());
+}
+
+#[test]
+fn journal_round_trips_and_stays_compatible_with_older_configs() {
+    let mut event = mine_window("night", "Eco", 22, 6, 0);
+    event.journal = vec![
+        ExecutionRecord {
+            triggered_at: 1_741_640_400,
+            phase: ExecutionPhase::Enter,
+            action: "Mining in Eco mode".to_string(),
+            catch_up: Some(CatchUpReason::Startup),
+            error: None,
+        },
+        ExecutionRecord {
+            triggered_at: 1_741_669_200,
+            phase: ExecutionPhase::Exit,
+            action: "Mine (Eco)".to_string(),
+            catch_up: None,
+            error: Some("Scheduled action failed: GPU miner not found".to_string()),
+        },
+    ];
+
+    let json = serde_json::to_value(&event).unwrap();
+    assert!(json["journal"][0].get("error").is_none());
+    assert!(json["journal"][1].get("catch_up").is_none());
+    let parsed: ScheduledEventInfo = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(parsed.journal, event.journal);
+
+    let mut older = json;
+    older.as_object_mut().unwrap().remove("journal");
+    let parsed: ScheduledEventInfo = serde_json::from_value(older).unwrap();
+    assert!(parsed.journal.is_empty());
+}
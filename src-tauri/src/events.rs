@@ -41,8 +41,11 @@ pub enum EventType {
     GpuDevicesUpdate,
     CpuPoolsStatsUpdate,
     GpuPoolsStatsUpdate,
+    PoolSwitched,
+    ThermalThrottle,
     CpuMiningUpdate,
     GpuMiningUpdate,
+    GpuDevicesMiningUpdate,
     NewBlockHeight,
     CloseSplashscreen,
     DetectedDevices,
@@ -84,6 +87,8 @@ pub enum EventType {
     UpdateAppModuleStatus,
     UpdateSelectedMiner,
     AvailableMiners,
+    UpdateSelectedCpuMiner,
+    AvailableCpuMiners,
     WalletStatusUpdate,
     UpdateCpuMinerControlsState,
     UpdateGpuMinerControlsState,
@@ -30,9 +30,13 @@ use crate::events::{
 use crate::internal_wallet::TariAddressType;
 use crate::mining::MinerControlsState;
 use crate::mining::cpu::CpuMinerStatus;
+use crate::mining::cpu::consts::{CpuMiner, CpuMinerType};
 use crate::mining::gpu::consts::{GpuMiner, GpuMinerStatus, GpuMinerType};
+use crate::mining::gpu::device_status::GpuDeviceMiningStatus;
 use crate::mining::gpu::miners::GpuCommonInformation;
 use crate::mining::pools::PoolStatus;
+use crate::mining::pools::failover::PoolSwitchRecord;
+use crate::mining::thermal::ThermalThrottleRecord;
 #[cfg(target_os = "windows")]
 use crate::system_dependencies::UniversalSystemDependency;
 use crate::wallet::wallet_types::{TransactionInfo, WalletBalance};
@@ -233,6 +237,34 @@ impl EventsEmitter {
         }
     }
 
+    pub async fn emit_update_selected_cpu_miner(payload: CpuMinerType) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::UpdateSelectedCpuMiner,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to emit UpdateSelectedCpuMiner event: {e:?}");
+        }
+    }
+
+    pub async fn emit_available_cpu_miners(payload: HashMap<CpuMinerType, CpuMiner>) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::AvailableCpuMiners,
+            payload,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to emit AvailableCpuMiners event: {e:?}");
+        }
+    }
+
     pub async fn emit_close_splashscreen() {
         let _unused = FrontendReadyChannel::current().wait_for_ready().await;
         let event = Event {
@@ -476,6 +508,34 @@ impl EventsEmitter {
         }
     }
 
+    pub async fn emit_pool_switched(record: PoolSwitchRecord) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::PoolSwitched,
+            payload: record,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to emit PoolSwitched event: {e:?}");
+        }
+    }
+
+    pub async fn emit_thermal_throttle(record: ThermalThrottleRecord) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::ThermalThrottle,
+            payload: record,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to emit ThermalThrottle event: {e:?}");
+        }
+    }
+
     pub async fn emit_gpu_pools_status_update(pool_status: HashMap<String, PoolStatus>) {
         let _unused = FrontendReadyChannel::current().wait_for_ready().await;
         let event = Event {
@@ -518,6 +578,20 @@ impl EventsEmitter {
         }
     }
 
+    pub async fn emit_gpu_devices_mining_update(devices: Vec<GpuDeviceMiningStatus>) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::GpuDevicesMiningUpdate,
+            payload: devices,
+        };
+        if let Err(e) = Self::get_app_handle()
+            .await
+            .emit(BACKEND_STATE_UPDATE, event)
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to emit GpuDevicesMiningUpdate event: {e:?}");
+        }
+    }
+
     pub async fn emit_new_block_mined(
         block_height: u64,
         coinbase_transaction: Option<TransactionInfo>,
@@ -120,6 +120,9 @@ mod shutdown_manager;
 mod system_dependencies;
 mod systemtray_manager;
 mod tapplets;
+mod tariff;
+#[cfg(test)]
+mod tariff_test;
 mod tasks_tracker;
 mod telemetry_manager;
 mod telemetry_service;
@@ -162,6 +165,8 @@ const APPLICATION_FOLDER_ID: &str = "com.tari.universe.beta";
 struct UniverseAppState {
     node_status_watch_rx: Arc<watch::Receiver<BaseNodeStatus>>,
     wallet_state_watch_rx: Arc<watch::Receiver<Option<WalletState>>>,
+    cpu_miner_status_watch_rx: Arc<watch::Receiver<CpuMinerStatus>>,
+    gpu_miner_status_watch_rx: Arc<watch::Receiver<GpuMinerStatus>>,
     in_memory_config: Arc<RwLock<AppInMemoryConfig>>,
     mm_proxy_manager: MmProxyManager,
     node_manager: NodeManager,
@@ -180,6 +185,24 @@ struct UniverseAppState {
 
 #[allow(clippy::too_many_lines)]
 fn main() {
+    // Proxy mode for MCP agent hosts: stdout carries the protocol, so bail out before anything
+    // else in the app gets a chance to print or start.
+    if std::env::args().any(|arg| arg == mcp::stdio::STDIO_FLAG) {
+        let exit_code = match tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(anyhow::Error::from)
+            .and_then(|runtime| runtime.block_on(mcp::stdio::run_proxy()))
+        {
+            Ok(()) => 0,
+            Err(e) => {
+                eprintln!("MCP stdio proxy failed: {e}");
+                1
+            }
+        };
+        std::process::exit(exit_code);
+    }
+
     #[cfg(target_os = "linux")]
     {
         if std::path::Path::new("/dev/dri").exists()
@@ -319,6 +342,8 @@ fn main() {
     let app_state = UniverseAppState {
         node_status_watch_rx: Arc::new(base_node_watch_rx),
         wallet_state_watch_rx: Arc::new(wallet_state_watch_rx.clone()),
+        cpu_miner_status_watch_rx: Arc::new(cpu_miner_status_watch_rx.clone()),
+        gpu_miner_status_watch_rx: Arc::new(gpu_status_rx.clone()),
         in_memory_config: app_in_memory_config.clone(),
         mm_proxy_manager: mm_proxy_manager.clone(),
         node_manager,
@@ -452,11 +477,17 @@ fn main() {
             commands::start_gpu_mining,
             commands::stop_cpu_mining,
             commands::stop_gpu_mining,
+            commands::switch_cpu_miner,
+            commands::switch_gpu_miner,
+            commands::benchmark_cpu_threads,
+            commands::benchmark_gpu_miners,
             commands::toggle_cpu_pool_mining,
             commands::toggle_gpu_pool_mining,
             commands::proceed_with_update,
             commands::set_pre_release,
             commands::toggle_device_exclusion,
+            commands::set_gpu_device_intensity,
+            commands::set_gpu_device_limits,
             commands::set_airdrop_tokens,
             commands::get_airdrop_tokens,
             commands::frontend_ready,
@@ -488,6 +519,16 @@ fn main() {
             commands::set_security_warning_dismissed,
             commands::change_cpu_pool,
             commands::change_gpu_pool,
+            commands::save_custom_cpu_pool,
+            commands::save_custom_gpu_pool,
+            commands::remove_cpu_pool,
+            commands::remove_gpu_pool,
+            commands::set_cpu_pool_priority,
+            commands::set_gpu_pool_priority,
+            commands::set_pool_failover_settings,
+            commands::set_thermal_limits,
+            commands::get_pool_switch_history,
+            commands::rank_pools_by_latency,
             commands::update_selected_gpu_pool_config,
             commands::update_selected_cpu_pool_config,
             commands::reset_gpu_pool_config,
@@ -507,6 +548,10 @@ fn main() {
             commands::remove_scheduler_event,
             commands::pause_scheduler_event,
             commands::resume_scheduler_event,
+            commands::get_scheduler_event_activations,
+            commands::set_tariff_settings,
+            commands::get_tariff_cost_estimates,
+            commands::clear_tariff_schedule,
             // MCP commands
             mcp::commands::get_mcp_config,
             mcp::commands::get_mcp_token,
@@ -515,9 +560,15 @@ fn main() {
             mcp::commands::revoke_mcp_token,
             mcp::commands::set_mcp_port,
             mcp::commands::set_mcp_max_transaction_amount,
+            mcp::commands::set_mcp_spend_limits,
+            mcp::commands::set_mcp_destination_allowlist_enabled,
             mcp::commands::set_mcp_tier_enabled,
+            mcp::commands::create_mcp_client,
+            mcp::commands::revoke_mcp_client,
             mcp::commands::get_mcp_audit_log,
             mcp::commands::export_mcp_audit_log,
+            mcp::commands::query_mcp_audit_log,
+            mcp::commands::verify_mcp_audit_log,
             mcp::commands::set_mcp_transactions_enabled,
             mcp::commands::mcp_transaction_dialog_response,
         ])
@@ -22,14 +22,17 @@
 
 use crate::LOG_TARGET_APP_LOGIC;
 use dirs::config_dir;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
 use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tari_common::configuration::Network;
 use tokio::io::AsyncWriteExt;
 use tokio::sync::RwLock;
@@ -38,6 +41,12 @@ use crate::APPLICATION_FOLDER_ID;
 
 const MAX_BUFFER_SIZE: usize = 500;
 const MAX_LOG_LINES: usize = 10_000;
+/// Archives are gzip-compressed, so a few hundred thousand entries stay cheap to keep.
+const MAX_ARCHIVES: usize = 20;
+const DEFAULT_QUERY_LIMIT: usize = 100;
+const MAX_QUERY_LIMIT: usize = 1_000;
+/// A scan that overlaps a rotation is retried this many times before giving up
+const MAX_SCAN_ATTEMPTS: usize = 3;
 
 static INSTANCE: LazyLock<RwLock<AuditLog>> = LazyLock::new(|| RwLock::new(AuditLog::new()));
 
@@ -50,31 +59,140 @@ pub struct AuditEntry {
     pub duration_ms: Option<u64>,
     pub client_info: Option<String>,
     pub details: Option<String>,
+    /// Hash of the previous entry, `None` for the first entry of a chain.
+    #[serde(default)]
+    pub prev_hash: Option<String>,
+    /// SHA-256 over this entry (including `prev_hash`) with `hash` unset. Filled in by
+    /// [`AuditLog::record`]; entries written before hash-chaining have none.
+    #[serde(default)]
+    pub hash: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl AuditEntry {
+    pub fn compute_hash(&self) -> String {
+        let mut unhashed = self.clone();
+        unhashed.hash = None;
+        let bytes = serde_json::to_vec(&unhashed).unwrap_or_default();
+        hex::encode(ring::digest::digest(&ring::digest::SHA256, &bytes))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AuditStatus {
     Started,
     Success,
     Error,
     Denied,
     RateLimited,
+    BudgetExceeded,
+    DestinationNotAllowed,
+}
+
+/// Filter for [`AuditLog::query`]. All set fields must match.
+#[derive(Debug, Clone, Default)]
+pub struct AuditQuery {
+    pub tool_name: Option<String>,
+    pub tier: Option<String>,
+    pub status: Option<AuditStatus>,
+    pub since: Option<SystemTime>,
+    pub until: Option<SystemTime>,
+    pub limit: Option<usize>,
+}
+
+impl AuditQuery {
+    /// Builds a query from the loosely typed filters the Tauri command and MCP tool accept.
+    /// Times are unix seconds.
+    pub fn from_filters(
+        tool_name: Option<String>,
+        tier: Option<String>,
+        status: Option<String>,
+        since_secs: Option<u64>,
+        until_secs: Option<u64>,
+        limit: Option<usize>,
+    ) -> Result<Self, String> {
+        let status = status
+            .map(|s| {
+                serde_json::from_value::<AuditStatus>(serde_json::Value::String(s.clone()))
+                    .map_err(|_| format!("Unknown audit status: {s}"))
+            })
+            .transpose()?;
+        Ok(Self {
+            tool_name,
+            tier: tier.map(|t| t.to_lowercase()),
+            status,
+            since: since_secs.map(|s| UNIX_EPOCH + Duration::from_secs(s)),
+            until: until_secs.map(|s| UNIX_EPOCH + Duration::from_secs(s)),
+            limit,
+        })
+    }
+
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        self.tool_name
+            .as_ref()
+            .is_none_or(|t| *t == entry.tool_name)
+            && self.tier.as_ref().is_none_or(|t| *t == entry.tier)
+            && self.status.as_ref().is_none_or(|s| *s == entry.status)
+            && self.since.is_none_or(|since| entry.timestamp >= since)
+            && self.until.is_none_or(|until| entry.timestamp <= until)
+    }
+
+    fn effective_limit(&self) -> usize {
+        self.limit
+            .unwrap_or(DEFAULT_QUERY_LIMIT)
+            .clamp(1, MAX_QUERY_LIMIT)
+    }
+}
+
+/// Outcome of walking the hash chain across archives and the live log.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditVerification {
+    pub entries_checked: usize,
+    pub first_broken_link: Option<AuditBrokenLink>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AuditBrokenLink {
+    pub file: String,
+    /// 1-based line number within `file`.
+    pub line: usize,
+    pub reason: String,
+}
+
+/// One log file to scan. The live log is only read up to the length it had when the snapshot
+/// was taken, so entries appended while it is being scanned are left out.
+#[derive(Debug, Clone, PartialEq)]
+struct AuditLogFile {
+    path: PathBuf,
+    read_limit: Option<u64>,
+}
+
+/// The log files as they were at one point in time, taken under the lock and scanned without it.
+struct AuditSnapshot {
+    files: Vec<AuditLogFile>,
+    last_hash: Option<String>,
+    rotations: u64,
 }
 
 pub struct AuditLog {
     buffer: VecDeque<AuditEntry>,
     log_path: PathBuf,
     line_count: usize,
+    last_hash: Option<String>,
+    /// Bumped on every rotation, tells a scan that the files it was reading have moved
+    rotations: u64,
 }
 
 impl AuditLog {
     fn new() -> Self {
         let log_path = Self::_get_log_path();
         let line_count = Self::_count_lines(&log_path);
+        let last_hash = Self::_read_last_hash(&log_path);
         Self {
             buffer: VecDeque::with_capacity(MAX_BUFFER_SIZE),
             log_path,
             line_count,
+            last_hash,
+            rotations: 0,
         }
     }
 
@@ -98,10 +216,29 @@ impl AuditLog {
         }
     }
 
-    pub async fn record(entry: AuditEntry) {
-        let cloned = entry.clone();
+    /// Picks the chain up where the previous session left off. After a rotation the live log is
+    /// gone, so the newest archive is consulted.
+    fn _read_last_hash(log_path: &Path) -> Option<String> {
+        Self::_all_log_files(log_path)
+            .iter()
+            .rev()
+            .find_map(|file| {
+                Self::_read_lines(file)
+                    .ok()?
+                    .iter()
+                    .rev()
+                    .filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok())
+                    .find_map(|entry| entry.hash)
+            })
+    }
+
+    pub async fn record(mut entry: AuditEntry) {
         let mut log = Self::current().write().await;
 
+        entry.prev_hash = log.last_hash.clone();
+        entry.hash = Some(entry.compute_hash());
+        let cloned = entry.clone();
+
         // Add to ring buffer
         if log.buffer.len() >= MAX_BUFFER_SIZE {
             log.buffer.pop_front();
@@ -113,76 +250,267 @@ impl AuditLog {
             log._rotate().await;
         }
 
-        let log_path = log.log_path.clone();
-        let current_count = &mut log.line_count;
-
         // Write to file (outside of heavy processing but still within lock for line_count accuracy)
-        if let Ok(serialized) = serde_json::to_string(&cloned) {
-            if let Some(parent) = log_path.parent() {
-                let _unused = tokio::fs::create_dir_all(parent).await;
+        let serialized = match serde_json::to_string(&cloned) {
+            Ok(serialized) => serialized,
+            Err(e) => {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to serialize MCP audit entry: {e:?}");
+                return;
             }
-            match tokio::fs::OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(&log_path)
-                .await
-            {
-                Ok(mut file) => {
-                    let line = format!("{serialized}\n");
-                    if file.write_all(line.as_bytes()).await.is_ok() {
-                        *current_count += 1;
-                    }
-                }
-                Err(e) => {
-                    error!(target: LOG_TARGET_APP_LOGIC, "Failed to open MCP audit log: {e:?}");
-                }
+        };
+        // The chain only moves on once the entry is on disk, otherwise the next entry would
+        // point at a hash that verify can not find
+        match Self::_append_line(&log.log_path, &serialized).await {
+            Ok(()) => {
+                log.last_hash = cloned.hash;
+                log.line_count += 1;
+            }
+            Err(e) => {
+                error!(target: LOG_TARGET_APP_LOGIC, "Failed to write MCP audit log: {e:?}");
             }
         }
     }
 
+    async fn _append_line(log_path: &Path, serialized: &str) -> std::io::Result<()> {
+        if let Some(parent) = log_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(log_path)
+            .await?;
+        file.write_all(format!("{serialized}\n").as_bytes()).await
+    }
+
     async fn _rotate(&mut self) {
         let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
-        let rotated_path = self.log_path.with_extension(format!("{timestamp}.jsonl"));
-        if let Err(e) = tokio::fs::rename(&self.log_path, &rotated_path).await {
-            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to rotate MCP audit log: {e:?}");
-        } else {
-            info!(target: LOG_TARGET_APP_LOGIC, "Rotated MCP audit log to {rotated_path:?}");
+        let archive_path = self
+            .log_path
+            .with_extension(format!("{timestamp}.jsonl.gz"));
+        let source = self.log_path.clone();
+        let target = archive_path.clone();
+        match tokio::task::spawn_blocking(move || Self::_compress_into_archive(&source, &target))
+            .await
+        {
+            Ok(Ok(())) => {
+                info!(target: LOG_TARGET_APP_LOGIC, "Rotated MCP audit log to {archive_path:?}");
+            }
+            Ok(Err(e)) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to rotate MCP audit log: {e:?}");
+            }
+            Err(e) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "MCP audit log rotation task failed: {e:?}");
+            }
         }
         self.line_count = 0;
-        self._cleanup_old_rotated_logs().await;
-    }
-
-    async fn _cleanup_old_rotated_logs(&self) {
-        const MAX_ROTATED_LOGS: usize = 5;
-        if let Some(parent) = self.log_path.parent() {
-            let mut rotated: Vec<PathBuf> = Vec::new();
-            if let Ok(mut entries) = tokio::fs::read_dir(parent).await {
-                while let Ok(Some(entry)) = entries.next_entry().await {
-                    let path = entry.path();
-                    let is_rotated = path
-                        .to_str()
-                        .map(|s| {
-                            s.contains("mcp_audit.")
-                                && s.ends_with(".jsonl")
-                                && path != self.log_path
-                        })
-                        .unwrap_or(false);
-                    if is_rotated {
-                        rotated.push(path);
-                    }
+        self.rotations += 1;
+        self._cleanup_old_archives().await;
+    }
+
+    fn _compress_into_archive(source: &Path, archive: &Path) -> std::io::Result<()> {
+        let mut input = File::open(source)?;
+        let mut encoder = GzEncoder::new(File::create(archive)?, Compression::default());
+        std::io::copy(&mut input, &mut encoder)?;
+        encoder.finish()?.sync_all()?;
+        std::fs::remove_file(source)
+    }
+
+    /// Rotated logs next to `log_path`, oldest first. Includes uncompressed logs rotated by
+    /// older versions.
+    fn _archive_paths(log_path: &Path) -> Vec<PathBuf> {
+        let (Some(parent), Some(stem)) = (
+            log_path.parent(),
+            log_path.file_stem().and_then(|s| s.to_str()),
+        ) else {
+            return Vec::new();
+        };
+        let prefix = format!("{stem}.");
+        let mut archives: Vec<PathBuf> = std::fs::read_dir(parent)
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .map(|entry| entry.path())
+                    .filter(|path| {
+                        path.as_path() != log_path
+                            && path
+                                .file_name()
+                                .and_then(|n| n.to_str())
+                                .is_some_and(|name| {
+                                    name.starts_with(&prefix)
+                                        && (name.ends_with(".jsonl") || name.ends_with(".jsonl.gz"))
+                                })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        archives.sort();
+        archives
+    }
+
+    async fn _cleanup_old_archives(&self) {
+        let archives = Self::_archive_paths(&self.log_path);
+        if archives.len() > MAX_ARCHIVES {
+            for old in &archives[..archives.len() - MAX_ARCHIVES] {
+                if let Err(e) = tokio::fs::remove_file(old).await {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "Failed to remove old MCP audit log {old:?}: {e:?}");
+                } else {
+                    info!(target: LOG_TARGET_APP_LOGIC, "Removed old MCP audit log: {old:?}");
+                }
+            }
+        }
+    }
+
+    fn _read_lines(file: &AuditLogFile) -> std::io::Result<Vec<String>> {
+        let path = &file.path;
+        let file = File::open(path)?.take(file.read_limit.unwrap_or(u64::MAX));
+        let is_compressed = path.extension().is_some_and(|ext| ext == "gz");
+        if is_compressed {
+            BufReader::new(GzDecoder::new(file)).lines().collect()
+        } else {
+            BufReader::new(file).lines().collect()
+        }
+    }
+
+    /// Archives oldest first, then the live log bounded to its current length.
+    fn _all_log_files(log_path: &Path) -> Vec<AuditLogFile> {
+        let mut files: Vec<AuditLogFile> = Self::_archive_paths(log_path)
+            .into_iter()
+            .map(|path| AuditLogFile {
+                path,
+                read_limit: None,
+            })
+            .collect();
+        if let Ok(metadata) = std::fs::metadata(log_path) {
+            files.push(AuditLogFile {
+                path: log_path.to_path_buf(),
+                read_limit: Some(metadata.len()),
+            });
+        }
+        files
+    }
+
+    fn _query_files(files: &[AuditLogFile], query: &AuditQuery) -> Vec<AuditEntry> {
+        let mut matched: VecDeque<AuditEntry> = VecDeque::new();
+        let limit = query.effective_limit();
+        for file in files {
+            let lines = match Self::_read_lines(file) {
+                Ok(lines) => lines,
+                Err(e) => {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "Failed to read MCP audit log {:?}: {e:?}", file.path);
+                    continue;
                 }
+            };
+            for entry in lines
+                .iter()
+                .filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok())
+                .filter(|entry| query.matches(entry))
+            {
+                if matched.len() >= limit {
+                    matched.pop_front();
+                }
+                matched.push_back(entry);
             }
-            rotated.sort();
-            if rotated.len() > MAX_ROTATED_LOGS {
-                for old in &rotated[..rotated.len() - MAX_ROTATED_LOGS] {
-                    if let Err(e) = tokio::fs::remove_file(old).await {
-                        warn!(target: LOG_TARGET_APP_LOGIC, "Failed to remove old MCP audit log {old:?}: {e:?}");
-                    } else {
-                        info!(target: LOG_TARGET_APP_LOGIC, "Removed old MCP audit log: {old:?}");
+        }
+        matched.into_iter().rev().collect()
+    }
+
+    /// `expected_last` is the hash the running instance last wrote, which catches entries
+    /// removed from the end of the log.
+    fn _verify_files(files: &[AuditLogFile], expected_last: Option<&str>) -> AuditVerification {
+        let mut entries_checked = 0;
+        // Stays `None` until the first hashed entry; anything before it predates hash-chaining.
+        let mut previous_hash: Option<String> = None;
+        let mut position = (String::new(), 0);
+        for log_file in files {
+            let file = log_file
+                .path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let lines = match Self::_read_lines(log_file) {
+                Ok(lines) => lines,
+                Err(e) => {
+                    return Self::_broken(
+                        entries_checked,
+                        file,
+                        0,
+                        format!("Unreadable log file: {e}"),
+                    );
+                }
+            };
+            for (index, line) in lines.iter().enumerate() {
+                let line_number = index + 1;
+                position = (file.clone(), line_number);
+                let entry = match serde_json::from_str::<AuditEntry>(line) {
+                    Ok(entry) => entry,
+                    Err(e) => {
+                        return Self::_broken(
+                            entries_checked,
+                            file,
+                            line_number,
+                            format!("Malformed entry: {e}"),
+                        );
                     }
+                };
+                let Some(hash) = &entry.hash else {
+                    if previous_hash.is_some() {
+                        return Self::_broken(
+                            entries_checked,
+                            file,
+                            line_number,
+                            "Entry is missing its hash".to_string(),
+                        );
+                    }
+                    continue;
+                };
+                if *hash != entry.compute_hash() {
+                    return Self::_broken(
+                        entries_checked,
+                        file,
+                        line_number,
+                        "Entry contents do not match its hash".to_string(),
+                    );
+                }
+                if previous_hash.is_some() && entry.prev_hash != previous_hash {
+                    return Self::_broken(
+                        entries_checked,
+                        file,
+                        line_number,
+                        "Entry does not link to the previous entry".to_string(),
+                    );
                 }
+                previous_hash = Some(hash.clone());
+                entries_checked += 1;
             }
         }
+        if let Some(expected_last) = expected_last
+            && previous_hash.as_deref() != Some(expected_last)
+        {
+            let (file, line) = position;
+            return Self::_broken(
+                entries_checked,
+                file,
+                line + 1,
+                "Log ends before the most recently recorded entry".to_string(),
+            );
+        }
+        AuditVerification {
+            entries_checked,
+            first_broken_link: None,
+        }
+    }
+
+    fn _broken(
+        entries_checked: usize,
+        file: String,
+        line: usize,
+        reason: String,
+    ) -> AuditVerification {
+        AuditVerification {
+            entries_checked,
+            first_broken_link: Some(AuditBrokenLink { file, line, reason }),
+        }
     }
 
     pub async fn get_recent(count: usize) -> Vec<AuditEntry> {
@@ -190,6 +518,47 @@ impl AuditLog {
         log.buffer.iter().rev().take(count).cloned().collect()
     }
 
+    async fn _snapshot() -> AuditSnapshot {
+        let log = Self::current().read().await;
+        AuditSnapshot {
+            files: Self::_all_log_files(&log.log_path),
+            last_hash: log.last_hash.clone(),
+            rotations: log.rotations,
+        }
+    }
+
+    /// Runs `scan` over a snapshot without holding the lock, so `record` never waits on a query.
+    /// A rotation during the scan moves the live log into an archive, the scan is then retried.
+    async fn _scan<T, F>(scan: F) -> Result<T, anyhow::Error>
+    where
+        T: Send + 'static,
+        F: Fn(AuditSnapshot) -> T + Clone + Send + 'static,
+    {
+        for _ in 0..MAX_SCAN_ATTEMPTS {
+            let snapshot = Self::_snapshot().await;
+            let rotations = snapshot.rotations;
+            let scan = scan.clone();
+            let result = tokio::task::spawn_blocking(move || scan(snapshot)).await?;
+            if Self::current().read().await.rotations == rotations {
+                return Ok(result);
+            }
+        }
+        Err(anyhow::anyhow!(
+            "MCP audit log kept rotating while it was being read"
+        ))
+    }
+
+    /// Matching entries from the archives and the live log, newest first.
+    pub async fn query(query: AuditQuery) -> Result<Vec<AuditEntry>, anyhow::Error> {
+        Self::_scan(move |snapshot| Self::_query_files(&snapshot.files, &query)).await
+    }
+
+    /// Walks the hash chain and reports the first entry that was altered, removed or reordered.
+    pub async fn verify() -> Result<AuditVerification, anyhow::Error> {
+        Self::_scan(|snapshot| Self::_verify_files(&snapshot.files, snapshot.last_hash.as_deref()))
+            .await
+    }
+
     pub async fn export() -> Result<String, anyhow::Error> {
         let log = Self::current().read().await;
         let path = &log.log_path;
@@ -200,3 +569,235 @@ impl AuditLog {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn entry(tool_name: &str, tier: &str, status: AuditStatus, secs: u64) -> AuditEntry {
+        AuditEntry {
+            timestamp: UNIX_EPOCH + Duration::from_secs(secs),
+            tool_name: tool_name.to_string(),
+            tier: tier.to_string(),
+            status,
+            duration_ms: None,
+            client_info: None,
+            details: None,
+            prev_hash: None,
+            hash: None,
+        }
+    }
+
+    /// Chains `entries` the way `AuditLog::record` does, continuing from `prev_hash`.
+    fn chain(entries: Vec<AuditEntry>, mut prev_hash: Option<String>) -> Vec<AuditEntry> {
+        entries
+            .into_iter()
+            .map(|mut e| {
+                e.prev_hash = prev_hash.clone();
+                e.hash = Some(e.compute_hash());
+                prev_hash = e.hash.clone();
+                e
+            })
+            .collect()
+    }
+
+    fn sample_chain() -> Vec<AuditEntry> {
+        chain(
+            vec![
+                entry("get_wallet_balance", "read", AuditStatus::Success, 100),
+                entry("start_mining", "control", AuditStatus::Success, 200),
+                entry("send_transaction", "transaction", AuditStatus::Denied, 300),
+                entry("get_chain_status", "read", AuditStatus::Error, 400),
+            ],
+            None,
+        )
+    }
+
+    fn write_log(path: &Path, entries: &[AuditEntry]) {
+        let mut file = File::create(path).unwrap();
+        for e in entries {
+            writeln!(file, "{}", serde_json::to_string(e).unwrap()).unwrap();
+        }
+    }
+
+    #[test]
+    fn intact_chain_verifies() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("mcp_audit.jsonl");
+        let entries = sample_chain();
+        write_log(&log_path, &entries);
+
+        let result = AuditLog::_verify_files(
+            &AuditLog::_all_log_files(&log_path),
+            entries.last().unwrap().hash.as_deref(),
+        );
+
+        assert_eq!(result.entries_checked, 4);
+        assert!(result.first_broken_link.is_none());
+    }
+
+    #[test]
+    fn edited_entry_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("mcp_audit.jsonl");
+        let mut entries = sample_chain();
+        entries[2].status = AuditStatus::Success;
+        write_log(&log_path, &entries);
+
+        let result = AuditLog::_verify_files(&AuditLog::_all_log_files(&log_path), None);
+
+        let broken = result.first_broken_link.unwrap();
+        assert_eq!(broken.line, 3);
+        assert!(broken.reason.contains("do not match"));
+        assert_eq!(result.entries_checked, 2);
+    }
+
+    #[test]
+    fn removed_entry_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("mcp_audit.jsonl");
+        let mut entries = sample_chain();
+        entries.remove(1);
+        write_log(&log_path, &entries);
+
+        let result = AuditLog::_verify_files(&AuditLog::_all_log_files(&log_path), None);
+
+        let broken = result.first_broken_link.unwrap();
+        assert_eq!(broken.line, 2);
+        assert!(broken.reason.contains("does not link"));
+    }
+
+    #[test]
+    fn truncated_tail_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("mcp_audit.jsonl");
+        let entries = sample_chain();
+        write_log(&log_path, &entries[..3]);
+
+        let result = AuditLog::_verify_files(
+            &AuditLog::_all_log_files(&log_path),
+            entries.last().unwrap().hash.as_deref(),
+        );
+
+        let broken = result.first_broken_link.unwrap();
+        assert_eq!(broken.line, 4);
+        assert_eq!(result.entries_checked, 3);
+    }
+
+    #[test]
+    fn legacy_entries_before_chain_are_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("mcp_audit.jsonl");
+        let mut entries = vec![entry("legacy", "read", AuditStatus::Success, 1)];
+        entries.extend(sample_chain());
+        write_log(&log_path, &entries);
+
+        let result = AuditLog::_verify_files(&AuditLog::_all_log_files(&log_path), None);
+
+        assert!(result.first_broken_link.is_none());
+        assert_eq!(result.entries_checked, 4);
+    }
+
+    #[test]
+    fn chain_continues_across_compressed_archive() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("mcp_audit.jsonl");
+        let entries = sample_chain();
+        write_log(&log_path, &entries[..2]);
+        let archive = log_path.with_extension("20260101_000000.jsonl.gz");
+        AuditLog::_compress_into_archive(&log_path, &archive).unwrap();
+        write_log(&log_path, &entries[2..]);
+
+        let files = AuditLog::_all_log_files(&log_path);
+        let paths: Vec<PathBuf> = files.iter().map(|file| file.path.clone()).collect();
+        assert_eq!(paths, vec![archive, log_path.clone()]);
+        assert_eq!(
+            AuditLog::_read_last_hash(&log_path),
+            entries.last().unwrap().hash
+        );
+
+        let result = AuditLog::_verify_files(&files, entries.last().unwrap().hash.as_deref());
+        assert!(result.first_broken_link.is_none());
+        assert_eq!(result.entries_checked, 4);
+    }
+
+    #[test]
+    fn entries_appended_after_the_snapshot_are_left_out() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("mcp_audit.jsonl");
+        let entries = sample_chain();
+        write_log(&log_path, &entries[..3]);
+        let files = AuditLog::_all_log_files(&log_path);
+
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&log_path)
+            .unwrap();
+        write!(file, "{}", serde_json::to_string(&entries[3]).unwrap()).unwrap();
+
+        let result = AuditLog::_verify_files(&files, entries[2].hash.as_deref());
+        assert!(result.first_broken_link.is_none());
+        assert_eq!(result.entries_checked, 3);
+    }
+
+    #[test]
+    fn last_hash_falls_back_to_newest_archive() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("mcp_audit.jsonl");
+        let entries = sample_chain();
+        write_log(&log_path, &entries);
+        let archive = log_path.with_extension("20260101_000000.jsonl.gz");
+        AuditLog::_compress_into_archive(&log_path, &archive).unwrap();
+
+        assert!(!log_path.exists());
+        assert_eq!(
+            AuditLog::_read_last_hash(&log_path),
+            entries.last().unwrap().hash
+        );
+    }
+
+    #[test]
+    fn query_filters_and_returns_newest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let log_path = dir.path().join("mcp_audit.jsonl");
+        write_log(&log_path, &sample_chain());
+        let files = AuditLog::_all_log_files(&log_path);
+
+        let reads = AuditLog::_query_files(
+            &files,
+            &AuditQuery::from_filters(None, Some("READ".to_string()), None, None, None, None)
+                .unwrap(),
+        );
+        let tools: Vec<&str> = reads.iter().map(|e| e.tool_name.as_str()).collect();
+        assert_eq!(tools, vec!["get_chain_status", "get_wallet_balance"]);
+
+        let in_range = AuditLog::_query_files(
+            &files,
+            &AuditQuery::from_filters(None, None, None, Some(200), Some(300), None).unwrap(),
+        );
+        assert_eq!(in_range.len(), 2);
+
+        let denied = AuditLog::_query_files(
+            &files,
+            &AuditQuery::from_filters(None, None, Some("Denied".to_string()), None, None, None)
+                .unwrap(),
+        );
+        assert_eq!(denied.len(), 1);
+        assert_eq!(denied[0].tool_name, "send_transaction");
+
+        let limited = AuditLog::_query_files(
+            &files,
+            &AuditQuery::from_filters(None, None, None, None, None, Some(1)).unwrap(),
+        );
+        assert_eq!(limited.len(), 1);
+        assert_eq!(limited[0].tool_name, "get_chain_status");
+    }
+
+    #[test]
+    fn query_rejects_unknown_status() {
+        let result =
+            AuditQuery::from_filters(None, None, Some("Bogus".to_string()), None, None, None);
+        assert!(result.is_err());
+    }
+}
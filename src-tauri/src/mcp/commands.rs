@@ -25,7 +25,7 @@ use std::time::SystemTime;
 use crate::configs::config_mcp::{ConfigMcp, ConfigMcpContent, SECONDS_PER_DAY};
 use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
-use crate::mcp::audit::AuditLog;
+use crate::mcp::audit::{AuditLog, AuditQuery, AuditVerification};
 use crate::mcp::server::McpServerManager;
 
 #[tauri::command]
@@ -134,6 +134,33 @@ pub async fn set_mcp_max_transaction_amount(amount: Option<u64>) -> Result<(), S
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_mcp_spend_limits(
+    daily_limit: Option<u64>,
+    weekly_limit: Option<u64>,
+) -> Result<(), String> {
+    if daily_limit == Some(0) || weekly_limit == Some(0) {
+        return Err("Spend limits must be greater than zero".to_string());
+    }
+    ConfigMcp::update_field(ConfigMcpContent::set_daily_spend_limit, daily_limit)
+        .await
+        .map_err(|e| e.to_string())?;
+    ConfigMcp::update_field(ConfigMcpContent::set_weekly_spend_limit, weekly_limit)
+        .await
+        .map_err(|e| e.to_string())?;
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_mcp_destination_allowlist_enabled(enabled: bool) -> Result<(), String> {
+    ConfigMcp::update_field(ConfigMcpContent::set_destination_allowlist_enabled, enabled)
+        .await
+        .map_err(|e| e.to_string())?;
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn set_mcp_tier_enabled(tier: String, enabled: bool) -> Result<(), String> {
     match tier.to_lowercase().as_str() {
@@ -152,6 +179,50 @@ pub async fn set_mcp_tier_enabled(tier: String, enabled: bool) -> Result<(), Str
     Ok(())
 }
 
+#[tauri::command]
+pub async fn create_mcp_client(
+    name: String,
+    tiers: Vec<String>,
+    expiry_days: Option<u32>,
+    rate_limit_per_minute: Option<u32>,
+    pin: Option<String>,
+) -> Result<String, String> {
+    if tiers.iter().any(|t| t.eq_ignore_ascii_case("transaction")) {
+        if !crate::pin::PinManager::pin_locked().await {
+            return Err(
+                "Cannot grant the transaction tier without a PIN configured. Please set up a PIN first."
+                    .to_string(),
+            );
+        }
+        let pin_str = pin.ok_or("PIN is required to grant the transaction tier")?;
+        let pin_password = tari_utilities::SafePassword::from(pin_str);
+        crate::pin::PinManager::validate_pin(pin_password)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+    let token = {
+        let mut config = ConfigMcp::current().write().await;
+        let content = config._get_content_mut();
+        let token = content.add_client(&name, tiers, expiry_days, rate_limit_per_minute)?;
+        ConfigMcp::_save_config(content.clone()).map_err(|e| e.to_string())?;
+        token
+    };
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+    Ok(token)
+}
+
+#[tauri::command]
+pub async fn revoke_mcp_client(name: String) -> Result<(), String> {
+    {
+        let mut config = ConfigMcp::current().write().await;
+        let content = config._get_content_mut();
+        content.revoke_client(&name)?;
+        ConfigMcp::_save_config(content.clone()).map_err(|e| e.to_string())?;
+    }
+    EventsEmitter::emit_mcp_config_loaded(&ConfigMcp::content().await).await;
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn get_mcp_audit_log(count: usize) -> Result<Vec<serde_json::Value>, String> {
     let entries = AuditLog::get_recent(count).await;
@@ -161,6 +232,28 @@ pub async fn get_mcp_audit_log(count: usize) -> Result<Vec<serde_json::Value>, S
         .collect()
 }
 
+#[tauri::command]
+pub async fn query_mcp_audit_log(
+    tool_name: Option<String>,
+    tier: Option<String>,
+    status: Option<String>,
+    since_secs: Option<u64>,
+    until_secs: Option<u64>,
+    limit: Option<usize>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let query = AuditQuery::from_filters(tool_name, tier, status, since_secs, until_secs, limit)?;
+    let entries = AuditLog::query(query).await.map_err(|e| e.to_string())?;
+    entries
+        .into_iter()
+        .map(|e| serde_json::to_value(e).map_err(|e| e.to_string()))
+        .collect()
+}
+
+#[tauri::command]
+pub async fn verify_mcp_audit_log() -> Result<AuditVerification, String> {
+    AuditLog::verify().await.map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub async fn export_mcp_audit_log() -> Result<String, String> {
     AuditLog::export().await.map_err(|e| e.to_string())
@@ -23,5 +23,7 @@
 pub mod audit;
 pub mod commands;
 pub mod rate_limiter;
+pub mod resources;
 pub mod server;
+pub mod stdio;
 pub mod tools;
@@ -20,8 +20,8 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::collections::VecDeque;
-use std::time::Instant;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
 
 use crate::configs::config_mcp::ConfigMcp;
 use crate::configs::trait_config::ConfigImpl;
@@ -64,10 +64,51 @@ impl TransactionRateLimiter {
     }
 }
 
+/// Sliding one-minute request window, tracked separately for each named MCP client.
+pub struct ClientRateLimiter {
+    requests: HashMap<String, VecDeque<Instant>>,
+}
+
+impl ClientRateLimiter {
+    pub fn new() -> Self {
+        Self {
+            requests: HashMap::new(),
+        }
+    }
+
+    /// Returns `true` and records the request if `client` is within `limit_per_minute`.
+    pub fn check_request_allowed(&mut self, client: &str, limit_per_minute: u32) -> bool {
+        self.check_request_allowed_at(client, limit_per_minute, Instant::now())
+    }
+
+    fn check_request_allowed_at(
+        &mut self,
+        client: &str,
+        limit_per_minute: u32,
+        now: Instant,
+    ) -> bool {
+        let window = Duration::from_secs(60);
+        let timestamps = self.requests.entry(client.to_string()).or_default();
+
+        while timestamps
+            .front()
+            .is_some_and(|t| now.duration_since(*t) > window)
+        {
+            timestamps.pop_front();
+        }
+
+        if timestamps.len() >= limit_per_minute as usize {
+            return false;
+        }
+
+        timestamps.push_back(now);
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::time::{Duration, Instant};
 
     #[test]
     fn new_limiter_has_no_timestamps() {
@@ -183,4 +224,31 @@ mod tests {
 
         assert!(limiter.timestamps.len() < limit, "Should be below limit");
     }
+
+    #[test]
+    fn client_limiter_blocks_after_limit() {
+        let mut limiter = ClientRateLimiter::new();
+        let now = Instant::now();
+        assert!(limiter.check_request_allowed_at("dashboard", 2, now));
+        assert!(limiter.check_request_allowed_at("dashboard", 2, now));
+        assert!(!limiter.check_request_allowed_at("dashboard", 2, now));
+    }
+
+    #[test]
+    fn client_limiter_tracks_clients_separately() {
+        let mut limiter = ClientRateLimiter::new();
+        let now = Instant::now();
+        assert!(limiter.check_request_allowed_at("dashboard", 1, now));
+        assert!(!limiter.check_request_allowed_at("dashboard", 1, now));
+        assert!(limiter.check_request_allowed_at("automation", 1, now));
+    }
+
+    #[test]
+    fn client_limiter_frees_slots_after_window() {
+        let mut limiter = ClientRateLimiter::new();
+        let start = Instant::now();
+        assert!(limiter.check_request_allowed_at("dashboard", 1, start));
+        assert!(!limiter.check_request_allowed_at("dashboard", 1, start + Duration::from_secs(30)));
+        assert!(limiter.check_request_allowed_at("dashboard", 1, start + Duration::from_secs(61)));
+    }
 }
@@ -0,0 +1,272 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::{debug, info};
+use rmcp::model::{
+    AnnotateAble, RawResource, Resource, ResourceContents, ResourceUpdatedNotificationParam,
+};
+use rmcp::{Peer, RoleServer};
+use tokio::sync::{Mutex, watch};
+use tokio::task::JoinHandle;
+
+use crate::LOG_TARGET_APP_LOGIC;
+use crate::mcp::tools::{chain, wallet};
+use crate::mining::cpu::CpuMinerStatus;
+use crate::mining::gpu::consts::GpuMinerStatus;
+use crate::node::node_adapter::BaseNodeStatus;
+use crate::wallet::wallet_types::WalletState;
+
+const RESOURCE_MIME_TYPE: &str = "application/json";
+/// Minimum delay between two `resources/updated` notifications for the same resource.
+/// Watch channels only keep the latest value, so bursts of updates collapse into one notification.
+const NOTIFY_MIN_INTERVAL: Duration = Duration::from_secs(1);
+/// How often an idle subscription checks whether the client transport is still open.
+const TRANSPORT_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum McpResource {
+    NodeStatus,
+    CpuMining,
+    GpuMining,
+    WalletBalance,
+}
+
+impl McpResource {
+    pub const ALL: [McpResource; 4] = [
+        McpResource::NodeStatus,
+        McpResource::CpuMining,
+        McpResource::GpuMining,
+        McpResource::WalletBalance,
+    ];
+
+    pub fn uri(&self) -> &'static str {
+        match self {
+            McpResource::NodeStatus => "tari://node/status",
+            McpResource::CpuMining => "tari://mining/cpu",
+            McpResource::GpuMining => "tari://mining/gpu",
+            McpResource::WalletBalance => "tari://wallet/balance",
+        }
+    }
+
+    pub fn from_uri(uri: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|resource| resource.uri() == uri)
+    }
+
+    /// Resources follow the same tiers as the tools exposing the same data.
+    pub fn tier(&self) -> &'static str {
+        match self {
+            McpResource::NodeStatus | McpResource::WalletBalance => "read",
+            McpResource::CpuMining | McpResource::GpuMining => "control",
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            McpResource::NodeStatus => "node_status",
+            McpResource::CpuMining => "cpu_mining_status",
+            McpResource::GpuMining => "gpu_mining_status",
+            McpResource::WalletBalance => "wallet_balance",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            McpResource::NodeStatus => {
+                "Base node status: block height, block time, block reward, sync status, peer count"
+            }
+            McpResource::CpuMining => "CPU miner status: mining state, hashrate, pool connection",
+            McpResource::GpuMining => "GPU miner status: mining state, hashrate, algorithm",
+            McpResource::WalletBalance => {
+                "Wallet balance including available, pending, and timelocked amounts"
+            }
+        }
+    }
+
+    pub fn to_resource(&self) -> Resource {
+        let mut raw = RawResource::new(self.uri(), self.name());
+        raw.description = Some(self.description().to_string());
+        raw.mime_type = Some(RESOURCE_MIME_TYPE.to_string());
+        raw.no_annotation()
+    }
+
+    pub fn contents(&self, text: String) -> ResourceContents {
+        ResourceContents::TextResourceContents {
+            uri: self.uri().to_string(),
+            mime_type: Some(RESOURCE_MIME_TYPE.to_string()),
+            text,
+            meta: None,
+        }
+    }
+}
+
+pub fn node_status_contents(status: &BaseNodeStatus) -> Result<String, String> {
+    chain::get_chain_status(status)
+}
+
+pub fn cpu_mining_contents(status: &CpuMinerStatus) -> Result<String, String> {
+    serde_json::to_string(status).map_err(|e| e.to_string())
+}
+
+pub fn gpu_mining_contents(status: &GpuMinerStatus) -> Result<String, String> {
+    serde_json::to_string(status).map_err(|e| e.to_string())
+}
+
+pub fn wallet_balance_contents(state: Option<&WalletState>) -> Result<String, String> {
+    let balance = state
+        .and_then(|s| s.balance.as_ref())
+        .ok_or_else(|| "Wallet balance is not available yet".to_string())?;
+    serde_json::to_string(&wallet::wallet_balance_json(balance)).map_err(|e| e.to_string())
+}
+
+/// Tracks the `resources/updated` forwarders of a single MCP session.
+#[derive(Clone, Default)]
+pub struct ResourceSubscriptions {
+    watchers: Arc<Mutex<HashMap<McpResource, JoinHandle<()>>>>,
+}
+
+impl ResourceSubscriptions {
+    pub async fn subscribe<T>(
+        &self,
+        resource: McpResource,
+        receiver: watch::Receiver<T>,
+        peer: Peer<RoleServer>,
+    ) where
+        T: Send + Sync + 'static,
+    {
+        let mut watchers = self.watchers.lock().await;
+        if watchers
+            .get(&resource)
+            .is_some_and(|handle| !handle.is_finished())
+        {
+            return;
+        }
+        let handle = tokio::spawn(forward_updates(resource, receiver, peer));
+        watchers.insert(resource, handle);
+    }
+
+    pub async fn unsubscribe(&self, resource: McpResource) -> bool {
+        match self.watchers.lock().await.remove(&resource) {
+            Some(handle) => {
+                handle.abort();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+async fn forward_updates<T>(
+    resource: McpResource,
+    mut receiver: watch::Receiver<T>,
+    peer: Peer<RoleServer>,
+) where
+    T: Send + Sync + 'static,
+{
+    receiver.mark_unchanged();
+    loop {
+        match tokio::time::timeout(TRANSPORT_CHECK_INTERVAL, receiver.changed()).await {
+            Ok(Ok(())) => {
+                let param = ResourceUpdatedNotificationParam {
+                    uri: resource.uri().to_string(),
+                };
+                if let Err(e) = peer.notify_resource_updated(param).await {
+                    debug!(target: LOG_TARGET_APP_LOGIC, "MCP: stopping {} updates, client unreachable: {e}", resource.uri());
+                    break;
+                }
+                tokio::time::sleep(NOTIFY_MIN_INTERVAL).await;
+            }
+            Ok(Err(_)) => {
+                info!(target: LOG_TARGET_APP_LOGIC, "MCP: {} source channel closed", resource.uri());
+                break;
+            }
+            Err(_) => {
+                if peer.is_transport_closed() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tari_transaction_components::tari_amount::MicroMinotari;
+
+    use crate::wallet::wallet_types::WalletBalance;
+
+    #[test]
+    fn uri_round_trips_for_all_resources() {
+        for resource in McpResource::ALL {
+            assert_eq!(McpResource::from_uri(resource.uri()), Some(resource));
+        }
+    }
+
+    #[test]
+    fn unknown_uri_is_rejected() {
+        assert_eq!(McpResource::from_uri("tari://mining/unknown"), None);
+        assert_eq!(McpResource::from_uri(""), None);
+    }
+
+    #[test]
+    fn mining_resources_require_control_tier() {
+        assert_eq!(McpResource::CpuMining.tier(), "control");
+        assert_eq!(McpResource::GpuMining.tier(), "control");
+        assert_eq!(McpResource::NodeStatus.tier(), "read");
+        assert_eq!(McpResource::WalletBalance.tier(), "read");
+    }
+
+    #[test]
+    fn wallet_balance_contents_without_balance_is_error() {
+        assert!(wallet_balance_contents(None).is_err());
+        assert!(wallet_balance_contents(Some(&WalletState::default())).is_err());
+    }
+
+    #[test]
+    fn wallet_balance_contents_reports_amounts() {
+        let state = WalletState {
+            balance: Some(WalletBalance {
+                available_balance: MicroMinotari(1_000),
+                timelocked_balance: MicroMinotari(2_000),
+                pending_incoming_balance: MicroMinotari(3_000),
+                pending_outgoing_balance: MicroMinotari(4_000),
+            }),
+            ..Default::default()
+        };
+        let json_str = wallet_balance_contents(Some(&state)).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+        assert_eq!(parsed["available_balance"], 1_000);
+        assert_eq!(parsed["pending_outgoing_balance"], 4_000);
+    }
+
+    #[test]
+    fn cpu_mining_contents_serializes_default_status() {
+        let json_str = cpu_mining_contents(&CpuMinerStatus::default()).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+        assert_eq!(parsed["is_mining"], false);
+        assert_eq!(parsed["connection"]["is_connected"], false);
+    }
+}
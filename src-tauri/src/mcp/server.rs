@@ -28,39 +28,57 @@ use log::{error, info, warn};
 use rmcp::transport::StreamableHttpServerConfig;
 use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
 use rmcp::transport::streamable_http_server::tower::StreamableHttpService;
-use sha2::{Digest, Sha256};
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 use tokio::task::JoinHandle;
 
 use crate::LOG_TARGET_APP_LOGIC;
 use crate::configs::config_mcp::{ConfigMcp, ConfigMcpContent, SECONDS_PER_DAY};
 use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
+use crate::mcp::rate_limiter::ClientRateLimiter;
+use crate::mcp::stdio;
 use crate::mcp::tools::TariMcpHandler;
+use crate::mining::cpu::CpuMinerStatus;
+use crate::mining::gpu::consts::GpuMinerStatus;
 use crate::node::node_adapter::BaseNodeStatus;
+use crate::node::node_manager::NodeManager;
 use crate::wallet::wallet_manager::WalletManager;
+use crate::wallet::wallet_types::WalletState;
 
 static INSTANCE: LazyLock<RwLock<McpServerManager>> =
     LazyLock::new(|| RwLock::new(McpServerManager::new()));
 
+static CLIENT_RATE_LIMITER: LazyLock<Mutex<ClientRateLimiter>> =
+    LazyLock::new(|| Mutex::new(ClientRateLimiter::new()));
+
 const SHUTDOWN_TIMEOUT_SECS: u64 = 5;
 
 pub struct McpServerManager {
     server_handle: Option<JoinHandle<()>>,
+    stdio_handle: Option<JoinHandle<()>>,
     shutdown_tx: Option<tokio::sync::watch::Sender<bool>>,
     bound_port: Option<u16>,
     node_status_rx: Option<Arc<tokio::sync::watch::Receiver<BaseNodeStatus>>>,
+    cpu_status_rx: Option<Arc<tokio::sync::watch::Receiver<CpuMinerStatus>>>,
+    gpu_status_rx: Option<Arc<tokio::sync::watch::Receiver<GpuMinerStatus>>>,
+    wallet_state_rx: Option<Arc<tokio::sync::watch::Receiver<Option<WalletState>>>>,
     wallet_manager: Option<WalletManager>,
+    node_manager: Option<NodeManager>,
 }
 
 impl McpServerManager {
     fn new() -> Self {
         Self {
             server_handle: None,
+            stdio_handle: None,
             shutdown_tx: None,
             bound_port: None,
             node_status_rx: None,
+            cpu_status_rx: None,
+            gpu_status_rx: None,
+            wallet_state_rx: None,
             wallet_manager: None,
+            node_manager: None,
         }
     }
 
@@ -70,11 +88,19 @@ impl McpServerManager {
 
     pub async fn initialize(
         node_status_rx: Arc<tokio::sync::watch::Receiver<BaseNodeStatus>>,
+        cpu_status_rx: Arc<tokio::sync::watch::Receiver<CpuMinerStatus>>,
+        gpu_status_rx: Arc<tokio::sync::watch::Receiver<GpuMinerStatus>>,
+        wallet_state_rx: Arc<tokio::sync::watch::Receiver<Option<WalletState>>>,
         wallet_manager: WalletManager,
+        node_manager: NodeManager,
     ) {
         let mut manager = Self::current().write().await;
         manager.node_status_rx = Some(node_status_rx);
+        manager.cpu_status_rx = Some(cpu_status_rx);
+        manager.gpu_status_rx = Some(gpu_status_rx);
+        manager.wallet_state_rx = Some(wallet_state_rx);
         manager.wallet_manager = Some(wallet_manager);
+        manager.node_manager = Some(node_manager);
     }
 
     pub fn port(&self) -> Option<u16> {
@@ -103,21 +129,26 @@ impl McpServerManager {
             anyhow::bail!("MCP server is not enabled");
         }
 
-        let token = config
-            .bearer_token()
-            .clone()
-            .ok_or_else(|| anyhow::anyhow!("MCP server has no bearer token configured"))?;
+        if config.bearer_token().is_none() && !config.has_active_clients() {
+            anyhow::bail!("MCP server has no bearer token or client credentials configured");
+        }
 
         let configured_port = *config.port();
 
-        // Get the node status receiver for chain tools
-        let node_status_rx = {
+        // Get the status receivers for chain tools and resources
+        let (node_status_rx, cpu_status_rx, gpu_status_rx, wallet_state_rx) = {
             let manager = Self::current().read().await;
-            manager.node_status_rx.clone().ok_or_else(|| {
-                anyhow::anyhow!(
+            match (
+                manager.node_status_rx.clone(),
+                manager.cpu_status_rx.clone(),
+                manager.gpu_status_rx.clone(),
+                manager.wallet_state_rx.clone(),
+            ) {
+                (Some(node), Some(cpu), Some(gpu), Some(wallet)) => (node, cpu, gpu, wallet),
+                _ => anyhow::bail!(
                     "MCP server not initialized — call McpServerManager::initialize() first"
-                )
-            })?
+                ),
+            }
         };
 
         let addr = std::net::SocketAddr::from(([127, 0, 0, 1], configured_port));
@@ -133,38 +164,54 @@ impl McpServerManager {
         let bound_port = listener.local_addr()?.port();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP server listening on 127.0.0.1:{bound_port}");
 
-        let wallet_manager = {
+        let (wallet_manager, node_manager) = {
             let manager = Self::current().read().await;
-            manager.wallet_manager.clone().ok_or_else(|| {
+            let wallet_manager = manager.wallet_manager.clone().ok_or_else(|| {
                 anyhow::anyhow!("MCP server not initialized — WalletManager not available")
-            })?
+            })?;
+            let node_manager = manager.node_manager.clone().ok_or_else(|| {
+                anyhow::anyhow!("MCP server not initialized — NodeManager not available")
+            })?;
+            (wallet_manager, node_manager)
+        };
+
+        let make_handler = move || {
+            TariMcpHandler::new(
+                node_status_rx.clone(),
+                cpu_status_rx.clone(),
+                gpu_status_rx.clone(),
+                wallet_state_rx.clone(),
+                wallet_manager.clone(),
+                node_manager.clone(),
+            )
         };
 
         // Build the rmcp StreamableHttpService
+        let http_make_handler = make_handler.clone();
         let mcp_service: StreamableHttpService<TariMcpHandler, LocalSessionManager> =
             StreamableHttpService::new(
-                move || {
-                    Ok(TariMcpHandler::new(
-                        node_status_rx.clone(),
-                        wallet_manager.clone(),
-                    ))
-                },
+                move || Ok(http_make_handler()),
                 LocalSessionManager::default().into(),
                 StreamableHttpServerConfig::default(),
             );
 
         // Build axum 0.8 router with bearer auth middleware
-        let protected_router =
-            axum::Router::new()
-                .nest_service("/mcp", mcp_service)
-                .layer(axum::middleware::from_fn(move |req, next| {
-                    let token = token.clone();
-                    auth_middleware(token, req, next)
-                }));
+        let protected_router = axum::Router::new()
+            .nest_service("/mcp", mcp_service)
+            .layer(axum::middleware::from_fn(auth_middleware));
 
         // Shutdown channel
         let (shutdown_tx, mut shutdown_rx) = tokio::sync::watch::channel(false);
 
+        // The stdio bridge is a convenience for local agent hosts, HTTP keeps working without it
+        let stdio_handle = match stdio::start_bridge(make_handler, shutdown_rx.clone()).await {
+            Ok(handle) => Some(handle),
+            Err(e) => {
+                error!(target: LOG_TARGET_APP_LOGIC, "MCP stdio bridge failed to start: {e:?}");
+                None
+            }
+        };
+
         let handle = tokio::spawn(async move {
             if let Err(e) = axum::serve(listener, protected_router)
                 .with_graceful_shutdown(async move {
@@ -181,6 +228,7 @@ impl McpServerManager {
         {
             let mut manager = Self::current().write().await;
             manager.server_handle = Some(handle);
+            manager.stdio_handle = stdio_handle;
             manager.shutdown_tx = Some(shutdown_tx);
             manager.bound_port = Some(bound_port);
         }
@@ -191,12 +239,13 @@ impl McpServerManager {
     }
 
     pub async fn stop() {
-        let (handle, shutdown_tx) = {
+        let (handle, stdio_handle, shutdown_tx) = {
             let mut manager = Self::current().write().await;
             let handle = manager.server_handle.take();
+            let stdio_handle = manager.stdio_handle.take();
             let tx = manager.shutdown_tx.take();
             manager.bound_port = None;
-            (handle, tx)
+            (handle, stdio_handle, tx)
         };
 
         if let Some(tx) = shutdown_tx {
@@ -221,6 +270,17 @@ impl McpServerManager {
             }
         }
 
+        if let Some(stdio_handle) = stdio_handle
+            && tokio::time::timeout(
+                std::time::Duration::from_secs(SHUTDOWN_TIMEOUT_SECS),
+                stdio_handle,
+            )
+            .await
+            .is_err()
+        {
+            warn!(target: LOG_TARGET_APP_LOGIC, "MCP stdio bridge shutdown timed out after {SHUTDOWN_TIMEOUT_SECS}s");
+        }
+
         EventsEmitter::emit_mcp_server_status_update(false, None).await;
 
         crate::mcp::tools::transaction::clear_inflight().await;
@@ -233,43 +293,48 @@ impl McpServerManager {
 }
 
 async fn auth_middleware(
-    expected_token: String,
-    req: axum::http::Request<axum::body::Body>,
+    mut req: axum::http::Request<axum::body::Body>,
     next: axum::middleware::Next,
 ) -> Result<axum::response::Response, axum::http::StatusCode> {
-    let auth_header = req
+    let provided = req
         .headers()
         .get("Authorization")
-        .and_then(|v: &axum::http::HeaderValue| v.to_str().ok());
-
-    match auth_header {
-        Some(header) if header.starts_with("Bearer ") => {
-            let provided = &header[7..];
-            // Constant-time comparison via hashing to avoid timing side-channels
-            let provided_hash = Sha256::digest(provided.as_bytes());
-            let expected_hash = Sha256::digest(expected_token.as_bytes());
-            if provided_hash == expected_hash {
-                let config = ConfigMcp::content().await;
-                if config.is_token_expired() {
-                    return Err(axum::http::StatusCode::UNAUTHORIZED);
-                }
-                // Sliding-window refresh: bump expiry on each successful request
-                // so the token only expires after `token_expiry_days` of inactivity.
-                let _unused = ConfigMcp::update_field(
-                    ConfigMcpContent::set_token_expires_at,
-                    Some(
-                        SystemTime::now()
-                            + std::time::Duration::from_secs(
-                                u64::from(*config.token_expiry_days()) * SECONDS_PER_DAY,
-                            ),
+        .and_then(|v: &axum::http::HeaderValue| v.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .ok_or(axum::http::StatusCode::UNAUTHORIZED)?;
+
+    // Tokens are compared by hash to avoid timing side-channels
+    let config = ConfigMcp::content().await;
+    let identity = config
+        .authenticate(provided)
+        .ok_or(axum::http::StatusCode::UNAUTHORIZED)?;
+
+    if identity.is_default() {
+        // Sliding-window refresh: bump expiry on each successful request
+        // so the token only expires after `token_expiry_days` of inactivity.
+        let _unused = ConfigMcp::update_field(
+            ConfigMcpContent::set_token_expires_at,
+            Some(
+                SystemTime::now()
+                    + std::time::Duration::from_secs(
+                        u64::from(*config.token_expiry_days()) * SECONDS_PER_DAY,
                     ),
-                )
-                .await;
-                Ok(next.run(req).await)
-            } else {
-                Err(axum::http::StatusCode::UNAUTHORIZED)
-            }
-        }
-        _ => Err(axum::http::StatusCode::UNAUTHORIZED),
+            ),
+        )
+        .await;
     }
+
+    if let Some(limit) = identity.rate_limit_per_minute
+        && !CLIENT_RATE_LIMITER
+            .lock()
+            .await
+            .check_request_allowed(&identity.name, limit)
+    {
+        warn!(target: LOG_TARGET_APP_LOGIC, "MCP client '{}' exceeded its rate limit of {limit} requests/min", identity.name);
+        return Err(axum::http::StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    // Picked up by the tool handler through the request parts rmcp forwards
+    req.extensions_mut().insert(identity);
+    Ok(next.run(req).await)
 }
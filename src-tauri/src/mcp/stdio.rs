@@ -0,0 +1,354 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Stdio transport for the MCP server.
+//!
+//! Agent hosts spawn `tari-universe --mcp-stdio` and talk MCP over its stdin/stdout. That process
+//! never starts the app: it connects to a loopback bridge opened by the running instance and
+//! pipes bytes both ways. The bridge serves the same `TariMcpHandler` as the HTTP transport, so
+//! tier checks and the audit log apply unchanged.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use dirs::config_dir;
+use log::{error, info, warn};
+use rmcp::ServiceExt;
+use serde::{Deserialize, Serialize};
+use tari_common::configuration::Network;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+use crate::APPLICATION_FOLDER_ID;
+use crate::LOG_TARGET_APP_LOGIC;
+use crate::configs::config_mcp::{ConfigMcpContent, McpClientIdentity};
+use crate::mcp::tools::TariMcpHandler;
+use crate::utils::file_utils::write_private_file;
+
+/// CLI flag that switches the binary into stdio proxy mode.
+pub const STDIO_FLAG: &str = "--mcp-stdio";
+/// Client name recorded in the audit log for stdio sessions.
+pub const STDIO_CLIENT_NAME: &str = "stdio";
+
+const ENDPOINT_FILE_NAME: &str = "mcp_stdio_endpoint.json";
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+const MAX_HANDSHAKE_LEN: usize = 256;
+
+/// Where the running instance can be reached. Written on server start, removed on stop.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StdioEndpoint {
+    pub port: u16,
+    pub secret: String,
+}
+
+impl StdioEndpoint {
+    pub fn path() -> PathBuf {
+        let config_dir = config_dir().unwrap_or_else(std::env::temp_dir);
+        config_dir
+            .join(APPLICATION_FOLDER_ID)
+            .join("app_configs")
+            .join(Network::get_current_or_user_setting_or_default().as_key_str())
+            .join(ENDPOINT_FILE_NAME)
+    }
+
+    pub fn write_to(&self, path: &Path) -> Result<(), anyhow::Error> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // The secret grants the same access as a bearer token, keep it private to the user
+        write_private_file(path, &serde_json::to_vec(self)?)
+    }
+
+    pub fn read_from(path: &Path) -> Result<Self, anyhow::Error> {
+        let contents = std::fs::read(path)?;
+        Ok(serde_json::from_slice(&contents)?)
+    }
+}
+
+fn stdio_identity() -> McpClientIdentity {
+    McpClientIdentity {
+        name: STDIO_CLIENT_NAME.to_string(),
+        tiers: None,
+        rate_limit_per_minute: None,
+    }
+}
+
+/// Reads the newline-terminated secret byte by byte, so nothing past the handshake is consumed
+/// before the stream is handed to rmcp.
+async fn read_handshake<R: AsyncRead + Unpin>(reader: &mut R) -> Result<String, anyhow::Error> {
+    let mut line = Vec::new();
+    loop {
+        let byte = reader.read_u8().await?;
+        if byte == b'\n' {
+            break;
+        }
+        if line.len() >= MAX_HANDSHAKE_LEN {
+            return Err(anyhow!("Handshake exceeds {MAX_HANDSHAKE_LEN} bytes"));
+        }
+        line.push(byte);
+    }
+    let line = String::from_utf8(line)?;
+    Ok(line.trim_end_matches('\r').to_string())
+}
+
+/// Secrets are compared by hash to avoid timing side-channels.
+fn verify_secret(expected: &str, provided: &str) -> bool {
+    ConfigMcpContent::hash_token(expected) == ConfigMcpContent::hash_token(provided)
+}
+
+/// Binds the loopback bridge, publishes the endpoint file and serves connections until
+/// `shutdown_rx` flips to `true`.
+pub async fn start_bridge<F>(
+    make_handler: F,
+    shutdown_rx: watch::Receiver<bool>,
+) -> Result<JoinHandle<()>, anyhow::Error>
+where
+    F: Fn() -> TariMcpHandler + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+    let endpoint = StdioEndpoint {
+        port: listener.local_addr()?.port(),
+        secret: ConfigMcpContent::generate_token(),
+    };
+    let endpoint_path = StdioEndpoint::path();
+    endpoint.write_to(&endpoint_path)?;
+    info!(target: LOG_TARGET_APP_LOGIC, "MCP stdio bridge listening on 127.0.0.1:{}", endpoint.port);
+
+    let make_handler = Arc::new(make_handler);
+    let secret = Arc::new(endpoint.secret);
+    let mut accept_shutdown_rx = shutdown_rx.clone();
+
+    let handle = tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                accepted = listener.accept() => {
+                    let stream = match accepted {
+                        Ok((stream, _)) => stream,
+                        Err(e) => {
+                            warn!(target: LOG_TARGET_APP_LOGIC, "MCP stdio bridge failed to accept connection: {e}");
+                            continue;
+                        }
+                    };
+                    tokio::spawn(serve_connection(
+                        stream,
+                        secret.clone(),
+                        make_handler(),
+                        shutdown_rx.clone(),
+                    ));
+                }
+                _ = accept_shutdown_rx.wait_for(|v| *v) => break,
+            }
+        }
+
+        if let Err(e) = std::fs::remove_file(&endpoint_path)
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Failed to remove MCP stdio endpoint file: {e}");
+        }
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP stdio bridge stopped");
+    });
+
+    Ok(handle)
+}
+
+async fn serve_connection(
+    mut stream: TcpStream,
+    secret: Arc<String>,
+    handler: TariMcpHandler,
+    mut shutdown_rx: watch::Receiver<bool>,
+) {
+    match tokio::time::timeout(HANDSHAKE_TIMEOUT, read_handshake(&mut stream)).await {
+        Ok(Ok(provided)) if verify_secret(&secret, &provided) => {}
+        Ok(Ok(_)) => {
+            warn!(target: LOG_TARGET_APP_LOGIC, "MCP stdio bridge rejected connection with invalid secret");
+            return;
+        }
+        Ok(Err(e)) => {
+            warn!(target: LOG_TARGET_APP_LOGIC, "MCP stdio bridge handshake failed: {e}");
+            return;
+        }
+        Err(_) => {
+            warn!(target: LOG_TARGET_APP_LOGIC, "MCP stdio bridge handshake timed out");
+            return;
+        }
+    }
+
+    let running = match handler.with_identity(stdio_identity()).serve(stream).await {
+        Ok(running) => running,
+        Err(e) => {
+            error!(target: LOG_TARGET_APP_LOGIC, "MCP stdio session failed to initialize: {e:?}");
+            return;
+        }
+    };
+    info!(target: LOG_TARGET_APP_LOGIC, "MCP stdio session started");
+
+    let cancellation_token = running.cancellation_token();
+    let waiting = running.waiting();
+    tokio::pin!(waiting);
+    tokio::select! {
+        _ = &mut waiting => {}
+        _ = shutdown_rx.wait_for(|v| *v) => {
+            cancellation_token.cancel();
+            let _unused = waiting.await;
+        }
+    }
+    info!(target: LOG_TARGET_APP_LOGIC, "MCP stdio session ended");
+}
+
+/// Entry point for `tari-universe --mcp-stdio`. Nothing may be logged to stdout here, it carries
+/// the MCP stream.
+pub async fn run_proxy() -> Result<(), anyhow::Error> {
+    let endpoint_path = StdioEndpoint::path();
+    let endpoint = StdioEndpoint::read_from(&endpoint_path).map_err(|e| {
+        anyhow!(
+            "Could not read MCP endpoint at {}: {e}. Is Tari Universe running with the MCP server enabled?",
+            endpoint_path.display()
+        )
+    })?;
+
+    let mut stream = TcpStream::connect(("127.0.0.1", endpoint.port))
+        .await
+        .map_err(|e| {
+            anyhow!(
+                "Could not connect to Tari Universe on port {}: {e}",
+                endpoint.port
+            )
+        })?;
+    stream
+        .write_all(format!("{}\n", endpoint.secret).as_bytes())
+        .await?;
+
+    let (mut reader, mut writer) = stream.into_split();
+    let downstream = tokio::io::copy(&mut reader, &mut tokio::io::stdout());
+    let upstream = async move {
+        tokio::io::copy(&mut tokio::io::stdin(), &mut writer).await?;
+        // Let the server see EOF and close the session
+        writer.shutdown().await
+    };
+    tokio::pin!(downstream, upstream);
+
+    tokio::select! {
+        result = &mut downstream => {
+            result?;
+        }
+        result = &mut upstream => {
+            result?;
+            downstream.await?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_round_trips_through_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join(ENDPOINT_FILE_NAME);
+        let endpoint = StdioEndpoint {
+            port: 4242,
+            secret: "tu_secret".to_string(),
+        };
+
+        endpoint.write_to(&path).unwrap();
+
+        assert_eq!(StdioEndpoint::read_from(&path).unwrap(), endpoint);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn endpoint_file_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(ENDPOINT_FILE_NAME);
+        StdioEndpoint {
+            port: 1,
+            secret: "s".to_string(),
+        }
+        .write_to(&path)
+        .unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rewriting_a_readable_endpoint_file_makes_it_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(ENDPOINT_FILE_NAME);
+        std::fs::write(&path, b"{}").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        let endpoint = StdioEndpoint {
+            port: 2,
+            secret: "s".to_string(),
+        };
+        endpoint.write_to(&path).unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(StdioEndpoint::read_from(&path).unwrap().port, 2);
+    }
+
+    #[tokio::test]
+    async fn handshake_stops_at_newline() {
+        let (mut client, mut server) = tokio::io::duplex(64);
+        client
+            .write_all(b"tu_secret\r\n{\"jsonrpc\"")
+            .await
+            .unwrap();
+
+        assert_eq!(read_handshake(&mut server).await.unwrap(), "tu_secret");
+
+        let mut rest = [0u8; 10];
+        server.read_exact(&mut rest).await.unwrap();
+        assert_eq!(&rest, b"{\"jsonrpc\"");
+    }
+
+    #[tokio::test]
+    async fn handshake_rejects_oversized_line() {
+        let (mut client, mut server) = tokio::io::duplex(1024);
+        client
+            .write_all(&[b'a'; MAX_HANDSHAKE_LEN + 1])
+            .await
+            .unwrap();
+
+        assert!(read_handshake(&mut server).await.is_err());
+    }
+
+    #[test]
+    fn verify_secret_requires_exact_match() {
+        assert!(verify_secret("tu_abc", "tu_abc"));
+        assert!(!verify_secret("tu_abc", "tu_abd"));
+        assert!(!verify_secret("tu_abc", ""));
+    }
+}
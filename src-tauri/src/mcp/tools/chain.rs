@@ -20,8 +20,18 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::node::node_adapter::BaseNodeStatus;
+use crate::UniverseAppState;
+use crate::commands;
+use crate::events_emitter::EventsEmitter;
+use crate::node::node_adapter::{BaseNodeStatus, LocalBlockStats};
+use crate::node::node_manager::{NodeManager, NodeType};
+use serde_json::json;
 use tari_common::configuration::Network;
+use tari_utilities::hex::Hex;
+use tauri::Manager;
+
+const DEFAULT_BLOCK_PAGE_SIZE: u64 = 20;
+const MAX_BLOCK_PAGE_SIZE: u64 = 100;
 
 pub fn get_chain_status(status: &BaseNodeStatus) -> Result<String, String> {
     let result = serde_json::json!({
@@ -44,3 +54,135 @@ pub fn get_network_info(status: &BaseNodeStatus) -> Result<String, String> {
     });
     Ok(result.to_string())
 }
+
+pub async fn list_connected_peers(node_manager: &NodeManager) -> Result<String, String> {
+    let node_type = node_manager.get_node_type().await;
+    let peers = node_manager
+        .list_connected_peers()
+        .await
+        .map_err(|e| format!("Failed to list connected peers: {e}"))?;
+
+    let result = json!({
+        "node_type": node_type.to_string(),
+        // Remote nodes don't expose their peers
+        "peers_available": node_type.is_local(),
+        "count": peers.len(),
+        "peers": peers,
+    });
+    Ok(result.to_string())
+}
+
+/// Page of recent blocks, newest first. Pass the returned `next_before_height` as
+/// `before_height` to fetch the following page.
+pub fn block_stats_page(stats: &[LocalBlockStats], page_size: u64) -> serde_json::Value {
+    let next_before_height = stats
+        .last()
+        .filter(|last| stats.len() as u64 == page_size && last.height > 0)
+        .map(|last| last.height);
+    json!({
+        "blocks": stats,
+        "next_before_height": next_before_height,
+    })
+}
+
+pub async fn get_recent_blocks(
+    node_manager: &NodeManager,
+    limit: Option<u64>,
+    before_height: Option<u64>,
+) -> Result<String, String> {
+    let page_size = limit
+        .unwrap_or(DEFAULT_BLOCK_PAGE_SIZE)
+        .clamp(1, MAX_BLOCK_PAGE_SIZE);
+    let service = node_manager
+        .get_current_service()
+        .await
+        .map_err(|e| format!("Node not available: {e}"))?;
+    let stats = service
+        .get_block_stats_page(before_height, page_size)
+        .await
+        .map_err(|e| format!("Failed to fetch block stats: {e}"))?;
+
+    Ok(block_stats_page(&stats, page_size).to_string())
+}
+
+pub async fn get_node_identity(node_manager: &NodeManager) -> Result<String, String> {
+    let node_type = node_manager.get_node_type().await;
+    let identity = node_manager
+        .get_identity()
+        .await
+        .map_err(|e| format!("Failed to get node identity: {e}"))?;
+
+    let result = json!({
+        "node_type": node_type.to_string(),
+        "public_key": identity.public_key.map(|key| key.to_hex()),
+        "public_addresses": identity.public_addresses,
+    });
+    Ok(result.to_string())
+}
+
+/// `recheck` compares recent blocks against the block explorer instead of reporting the result
+/// of the last periodic check.
+pub async fn get_orphan_chain_status(
+    node_manager: &NodeManager,
+    recheck: bool,
+) -> Result<String, String> {
+    let is_on_orphan_chain = if recheck {
+        node_manager
+            .check_if_is_orphan_chain()
+            .await
+            .map_err(|e| format!("Orphan chain check failed: {e}"))?
+    } else {
+        node_manager.is_on_orphan_chain()
+    };
+
+    let result = json!({
+        "is_on_orphan_chain": is_on_orphan_chain,
+        "rechecked": recheck,
+    });
+    Ok(result.to_string())
+}
+
+/// Only the node types a user can pick; `LocalAfterRemote` is an internal transition state.
+pub fn parse_node_type(node_type: &str) -> Result<NodeType, String> {
+    match node_type {
+        "Local" => Ok(NodeType::Local),
+        "Remote" => Ok(NodeType::Remote),
+        "RemoteUntilLocal" => Ok(NodeType::RemoteUntilLocal),
+        _ => Err(format!(
+            "Invalid node type '{node_type}', expected Local, Remote or RemoteUntilLocal"
+        )),
+    }
+}
+
+/// Goes through the Tauri command so phase restarts and node type events match the settings
+/// screen.
+pub async fn set_node_type(
+    node_manager: &NodeManager,
+    node_type: String,
+) -> Result<String, String> {
+    let node_type = parse_node_type(&node_type)?;
+    let app_handle = EventsEmitter::get_app_handle_public().await;
+    commands::set_node_type(
+        node_type,
+        app_handle.state::<UniverseAppState>(),
+        app_handle.clone(),
+    )
+    .await
+    .map_err(|e| format!("Failed to set node type: {}", e.0))?;
+
+    let result = json!({
+        "node_type": node_manager.get_node_type().await.to_string(),
+    });
+    Ok(result.to_string())
+}
+
+pub async fn set_remote_base_node_address(address: String) -> Result<String, String> {
+    let resolved = commands::set_remote_base_node_address(address)
+        .await
+        .map_err(|e| format!("Failed to set remote base node address: {}", e.0))?;
+
+    let result = json!({
+        "remote_base_node_address": resolved,
+    });
+    Ok(result.to_string())
+}
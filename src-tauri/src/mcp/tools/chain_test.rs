@@ -23,7 +23,10 @@
 use crate::node::node_adapter::{BaseNodeStatus, ReadinessStatus};
 use tari_transaction_components::tari_amount::MicroMinotari;
 
-use super::chain::{get_chain_status, get_network_info};
+use crate::node::node_adapter::{LocalBlockStats, block_stats_request};
+use crate::node::node_manager::NodeType;
+
+use super::chain::{block_stats_page, get_chain_status, get_network_info, parse_node_type};
 
 #[test]
 fn get_chain_status_returns_ok_with_default_status() {
@@ -159,3 +162,83 @@ fn get_network_info_network_field_is_string() {
 
     assert!(parsed["network"].is_string());
 }
+
+fn block(height: u64) -> LocalBlockStats {
+    LocalBlockStats {
+        height,
+        total_coinbase_xtm: "1.000000".to_string(),
+        num_coinbases: 1,
+        num_outputs_no_coinbases: 0,
+        num_inputs: 0,
+        pow_algo: "RandomX".to_string(),
+        timestamp: 0,
+    }
+}
+
+#[test]
+fn block_stats_page_points_to_next_page_when_full() {
+    let stats = vec![block(10), block(9), block(8)];
+    let page = block_stats_page(&stats, 3);
+
+    assert_eq!(page["blocks"].as_array().unwrap().len(), 3);
+    assert_eq!(page["blocks"][0]["height"], 10);
+    assert_eq!(page["next_before_height"], 8);
+}
+
+#[test]
+fn block_stats_page_ends_when_short() {
+    let stats = vec![block(2), block(1)];
+    let page = block_stats_page(&stats, 20);
+
+    assert!(page["next_before_height"].is_null());
+}
+
+#[test]
+fn block_stats_page_ends_at_genesis() {
+    let stats = vec![block(1), block(0)];
+    let page = block_stats_page(&stats, 2);
+
+    assert!(page["next_before_height"].is_null());
+}
+
+#[test]
+fn page_ending_at_height_one_leads_to_a_genesis_only_page() {
+    let stats = vec![block(2), block(1)];
+    let page = block_stats_page(&stats, 2);
+    assert_eq!(page["next_before_height"], 1);
+
+    // A from_height of 0 means the tip to the node, genesis has to be asked for in ascending order
+    let request = block_stats_request(Some(1), 2).unwrap();
+    assert_eq!(request.from_height, 0);
+    assert_eq!(request.num_headers, 1);
+    assert_eq!(request.sorting, 1);
+
+    let genesis_page = block_stats_page(&[block(0)], 2);
+    assert!(genesis_page["next_before_height"].is_null());
+}
+
+#[test]
+fn block_stats_request_walks_down_from_before_height() {
+    let request = block_stats_request(Some(10), 3).unwrap();
+    assert_eq!(request.from_height, 9);
+    assert_eq!(request.num_headers, 3);
+    assert_eq!(request.sorting, 0);
+
+    let tip = block_stats_request(None, 3).unwrap();
+    assert_eq!(tip.from_height, 0);
+    assert_eq!(tip.sorting, 0);
+
+    assert!(block_stats_request(Some(0), 3).is_none());
+}
+
+#[test]
+fn parse_node_type_accepts_user_selectable_types() {
+    assert_eq!(parse_node_type("Local").unwrap(), NodeType::Local);
+    assert_eq!(parse_node_type("Remote").unwrap(), NodeType::Remote);
+    assert_eq!(
+        parse_node_type("RemoteUntilLocal").unwrap(),
+        NodeType::RemoteUntilLocal
+    );
+    assert!(parse_node_type("LocalAfterRemote").is_err());
+    assert!(parse_node_type("local").is_err());
+}
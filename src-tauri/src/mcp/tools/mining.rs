@@ -26,6 +26,7 @@ use crate::events_emitter::EventsEmitter;
 use crate::hardware::hardware_status_monitor::HardwareStatusMonitor;
 use crate::mining::cpu::manager::CpuManager;
 use crate::mining::gpu::manager::GpuManager;
+use crate::mining::is_benchmark_running;
 use crate::systemtray_manager::{SystemTrayEvents, SystemTrayManager};
 use serde_json::json;
 
@@ -131,13 +132,12 @@ pub async fn stop_mining(cpu: Option<bool>, gpu: Option<bool>) -> Result<String,
 }
 
 pub async fn set_mining_mode(mode: String) -> Result<String, String> {
-    let cpu_was_running = CpuManager::read().await.is_running();
+    if is_benchmark_running() {
+        return Err("Mining mode can not change while a miner benchmark runs".to_string());
+    }
     let gpu_was_running = GpuManager::read().await.is_running();
 
-    // Stop running miners before changing mode
-    if cpu_was_running {
-        let _unused = CpuManager::write().await.stop_mining().await;
-    }
+    // Stop the gpu miner before changing mode, the cpu miner is updated in place
     if gpu_was_running {
         let _unused = GpuManager::write().await.stop_mining().await;
     }
@@ -159,10 +159,8 @@ pub async fn set_mining_mode(mode: String) -> Result<String, String> {
 
     EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
 
-    // Restart miners that were previously running
-    if cpu_was_running {
-        let _unused = CpuManager::write().await.start_mining().await;
-    }
+    // Apply the mode to the running cpu miner and restart the gpu miner if it was running
+    let _unused = CpuManager::write().await.update_mining_mode().await;
     if gpu_was_running {
         let _unused = GpuManager::write().await.start_mining().await;
     }
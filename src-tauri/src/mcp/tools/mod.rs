@@ -20,11 +20,17 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+pub mod audit_log;
 pub mod chain;
 #[cfg(test)]
 mod chain_test;
 pub mod mining;
+pub mod pools;
+#[cfg(test)]
+mod pools_test;
 pub mod scheduler;
+#[cfg(test)]
+mod scheduler_test;
 pub mod transaction;
 pub mod wallet;
 
@@ -35,23 +41,36 @@ use log::info;
 use rmcp::handler::server::router::tool::ToolRouter;
 use rmcp::handler::server::wrapper::Parameters;
 use rmcp::model::*;
-use rmcp::{ServerHandler, tool, tool_handler, tool_router};
+use rmcp::service::RequestContext;
+use rmcp::{RoleServer, ServerHandler, tool, tool_handler, tool_router};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use tokio::sync::watch;
 
 use crate::LOG_TARGET_APP_LOGIC;
-use crate::configs::config_mcp::ConfigMcp;
+use crate::configs::config_mcp::{ConfigMcp, McpClientIdentity};
 use crate::configs::trait_config::ConfigImpl;
-use crate::mcp::audit::{AuditEntry, AuditLog, AuditStatus};
+use crate::mcp::audit::{AuditEntry, AuditLog, AuditQuery, AuditStatus};
+use crate::mcp::resources::{self, McpResource, ResourceSubscriptions};
+use crate::mining::cpu::CpuMinerStatus;
+use crate::mining::gpu::consts::GpuMinerStatus;
 use crate::node::node_adapter::BaseNodeStatus;
+use crate::node::node_manager::NodeManager;
 use crate::wallet::wallet_manager::WalletManager;
+use crate::wallet::wallet_types::WalletState;
 
 #[derive(Clone)]
 pub struct TariMcpHandler {
     tool_router: ToolRouter<Self>,
     node_status_rx: Arc<watch::Receiver<BaseNodeStatus>>,
+    cpu_status_rx: Arc<watch::Receiver<CpuMinerStatus>>,
+    gpu_status_rx: Arc<watch::Receiver<GpuMinerStatus>>,
+    wallet_state_rx: Arc<watch::Receiver<Option<WalletState>>>,
     wallet_manager: WalletManager,
+    node_manager: NodeManager,
+    subscriptions: ResourceSubscriptions,
+    /// Identity for transports without per-request auth, such as the stdio bridge.
+    transport_identity: Option<McpClientIdentity>,
 }
 
 #[tool_handler]
@@ -59,7 +78,11 @@ impl ServerHandler for TariMcpHandler {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
             protocol_version: ProtocolVersion::V_2025_03_26,
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .build(),
             server_info: Implementation {
                 name: "tari-universe".to_string(),
                 version: env!("CARGO_PKG_VERSION").to_string(),
@@ -72,11 +95,135 @@ impl ServerHandler for TariMcpHandler {
                 icons: None,
             },
             instructions: Some(
-                "Tari Universe MCP server. Available tool categories: mining (start/stop/mode), wallet (address/balance), chain (block height/sync status), and scheduler (scheduled mining events). Use get_mining_status, get_wallet_address, and get_chain_status to get an overview."
+                "Tari Universe MCP server. Available tool categories: mining (start/stop/mode), pools (stats/switch/solo), wallet (address/balance), chain (block height/sync status/blocks/peers), node (identity/orphan chain/node type), scheduler (create, pause, resume and cancel scheduled mining events, and preview their upcoming activations), and audit (query the tamper-evident audit log). Use get_mining_status, get_wallet_address, and get_chain_status to get an overview. Live state is also published as subscribable resources: tari://node/status, tari://mining/cpu, tari://mining/gpu and tari://wallet/balance."
                     .to_string(),
             ),
         }
     }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, ErrorData> {
+        let client = self.client_identity(&context);
+        let mut available = Vec::new();
+        for resource in McpResource::ALL {
+            if Self::is_tier_enabled(client.as_ref(), resource.tier()).await {
+                available.push(resource.to_resource());
+            }
+        }
+        Ok(ListResourcesResult::with_all_items(available))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, ErrorData> {
+        let client = self.client_identity(&context);
+        let resource = Self::resolve_resource(client.as_ref(), &request.uri).await?;
+        let start = Instant::now();
+        self.audit_resource_call(
+            client.as_ref(),
+            "read_resource",
+            resource,
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = match resource {
+            McpResource::NodeStatus => {
+                resources::node_status_contents(&self.node_status_rx.borrow())
+            }
+            McpResource::CpuMining => resources::cpu_mining_contents(&self.cpu_status_rx.borrow()),
+            McpResource::GpuMining => resources::gpu_mining_contents(&self.gpu_status_rx.borrow()),
+            McpResource::WalletBalance => {
+                resources::wallet_balance_contents(self.wallet_state_rx.borrow().as_ref())
+            }
+        };
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_resource_call(
+            client.as_ref(),
+            "read_resource",
+            resource,
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        let text = result.map_err(|e| ErrorData::internal_error(e, None))?;
+        Ok(ReadResourceResult {
+            contents: vec![resource.contents(text)],
+        })
+    }
+
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<(), ErrorData> {
+        let client = self.client_identity(&context);
+        let resource = Self::resolve_resource(client.as_ref(), &request.uri).await?;
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: subscribe called (uri={})", request.uri);
+        let peer = context.peer;
+        match resource {
+            McpResource::NodeStatus => {
+                self.subscriptions
+                    .subscribe(resource, (*self.node_status_rx).clone(), peer)
+                    .await;
+            }
+            McpResource::CpuMining => {
+                self.subscriptions
+                    .subscribe(resource, (*self.cpu_status_rx).clone(), peer)
+                    .await;
+            }
+            McpResource::GpuMining => {
+                self.subscriptions
+                    .subscribe(resource, (*self.gpu_status_rx).clone(), peer)
+                    .await;
+            }
+            McpResource::WalletBalance => {
+                self.subscriptions
+                    .subscribe(resource, (*self.wallet_state_rx).clone(), peer)
+                    .await;
+            }
+        }
+        self.audit_resource_call(
+            client.as_ref(),
+            "subscribe_resource",
+            resource,
+            AuditStatus::Success,
+            None,
+        )
+        .await;
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParams,
+        context: RequestContext<RoleServer>,
+    ) -> Result<(), ErrorData> {
+        let client = self.client_identity(&context);
+        let resource = McpResource::from_uri(&request.uri).ok_or_else(|| {
+            ErrorData::resource_not_found(format!("Unknown resource: {}", request.uri), None)
+        })?;
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: unsubscribe called (uri={})", request.uri);
+        self.subscriptions.unsubscribe(resource).await;
+        self.audit_resource_call(
+            client.as_ref(),
+            "unsubscribe_resource",
+            resource,
+            AuditStatus::Success,
+            None,
+        )
+        .await;
+        Ok(())
+    }
 }
 
 #[derive(Deserialize, JsonSchema)]
@@ -93,6 +240,66 @@ struct SetMiningModeParams {
     mode: String,
 }
 
+#[derive(Deserialize, JsonSchema)]
+struct ChangePoolParams {
+    /// Which miner's pool to change: "cpu" or "gpu"
+    kind: String,
+    /// Pool identifier as returned by list_pools (e.g., LuckyPoolRANDOMX, KryptexPoolC29)
+    pool: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct TogglePoolMiningParams {
+    /// Which miner to switch: "cpu" or "gpu"
+    kind: String,
+    /// true to mine on the selected pool, false for solo mining
+    enabled: bool,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct QueryAuditLogParams {
+    /// Only entries for this tool (e.g., send_transaction)
+    tool_name: Option<String>,
+    /// Only entries of this tier: read, control, or transaction
+    tier: Option<String>,
+    /// Only entries with this status: Started, Success, Error, Denied, RateLimited, BudgetExceeded, or DestinationNotAllowed
+    status: Option<String>,
+    /// Only entries at or after this time, in unix seconds
+    since: Option<u64>,
+    /// Only entries at or before this time, in unix seconds
+    until: Option<u64>,
+    /// Maximum number of entries to return, newest first. Defaults to 100, capped at 1000.
+    limit: Option<usize>,
+    /// Also walk the whole hash chain and report whether it is intact. Reads every archive, off by default.
+    verify_chain: Option<bool>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GetRecentBlocksParams {
+    /// Number of blocks per page (1-100, defaults to 20)
+    limit: Option<u64>,
+    /// Only return blocks below this height. Use next_before_height from the previous page.
+    before_height: Option<u64>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GetOrphanChainStatusParams {
+    /// Run a fresh check against the block explorer instead of returning the last result
+    recheck: Option<bool>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct SetNodeTypeParams {
+    /// Node type: Local, Remote, or RemoteUntilLocal
+    node_type: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct SetRemoteBaseNodeAddressParams {
+    /// gRPC address of the remote base node as scheme://host:port. An empty string restores the default.
+    address: String,
+}
+
 #[derive(Deserialize, JsonSchema)]
 struct GetTransactionHistoryParams {
     /// Maximum number of transactions to return. Defaults to 20.
@@ -125,6 +332,54 @@ struct CancelScheduledEventParams {
     event_id: String,
 }
 
+#[derive(Deserialize, JsonSchema)]
+struct ScheduleEventParams {
+    /// Unique identifier for the scheduled event. Reusing an ID replaces the existing event.
+    event_id: String,
+    /// What to do when the event triggers: "resume_mining", "mine", "cpu_mining", "gpu_mining",
+    /// "switch_cpu_pool", "switch_gpu_pool", "cpu_pool_mining", "gpu_pool_mining" or "switch_node_type".
+    /// For windows, everything except "resume_mining" and "mine" is reverted when the window ends.
+    action: String,
+    /// Mining mode for the "mine" action (e.g., Eco, Turbo, Ludicrous)
+    mining_mode: Option<String>,
+    /// On/off value for the "cpu_mining", "gpu_mining", "cpu_pool_mining" and "gpu_pool_mining" actions
+    enabled: Option<bool>,
+    /// Pool name for the "switch_cpu_pool" and "switch_gpu_pool" actions (see list_pools)
+    pool: Option<String>,
+    /// Node type for the "switch_node_type" action: "Local", "Remote" or "RemoteUntilLocal"
+    node_type: Option<String>,
+    /// Priority for overlapping "mine" windows (defaults to 0). The highest open window wins and
+    /// mining falls back to the next one when it closes. Overlaps at equal priority, or between two
+    /// windows of the same other action, are rejected.
+    priority: Option<u32>,
+    /// One-off delay before triggering (hours: 1-24, minutes/seconds: 1-60). Mutually exclusive with start_time/end_time.
+    delay_value: Option<i64>,
+    /// Unit for delay_value: "hours", "minutes" or "seconds". Defaults to "minutes".
+    delay_unit: Option<String>,
+    /// Daily window start in 24-hour HH:MM format (e.g., "22:00")
+    start_time: Option<String>,
+    /// Daily window end in 24-hour HH:MM format (e.g., "06:30"). A window ending at or before its start runs into the next day.
+    end_time: Option<String>,
+    /// Days a window may start on (e.g., ["Mon", "Tue"]). Defaults to every day.
+    weekdays: Option<Vec<String>>,
+    /// First day of a yearly date range in MM-DD format (e.g., "12-01"). Requires end_date.
+    start_date: Option<String>,
+    /// Last day (inclusive) of a yearly date range in MM-DD format (e.g., "12-31"). May wrap past the new year.
+    end_date: Option<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct GetScheduledEventActivationsParams {
+    /// Number of upcoming starts and ends to return per event. Defaults to 5, capped at 100.
+    count: Option<usize>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+struct ScheduledEventIdParams {
+    /// ID of the scheduled event
+    event_id: String,
+}
+
 #[derive(Deserialize, JsonSchema)]
 struct SendTransactionParams {
     /// Tari address to send to (base58, hex, or emoji format)
@@ -138,17 +393,44 @@ struct SendTransactionParams {
 impl TariMcpHandler {
     pub fn new(
         node_status_rx: Arc<watch::Receiver<BaseNodeStatus>>,
+        cpu_status_rx: Arc<watch::Receiver<CpuMinerStatus>>,
+        gpu_status_rx: Arc<watch::Receiver<GpuMinerStatus>>,
+        wallet_state_rx: Arc<watch::Receiver<Option<WalletState>>>,
         wallet_manager: WalletManager,
+        node_manager: NodeManager,
     ) -> Self {
         Self {
             tool_router: Self::tool_router(),
             node_status_rx,
+            cpu_status_rx,
+            gpu_status_rx,
+            wallet_state_rx,
             wallet_manager,
+            node_manager,
+            subscriptions: ResourceSubscriptions::default(),
+            transport_identity: None,
         }
     }
 
+    pub fn with_identity(mut self, identity: McpClientIdentity) -> Self {
+        self.transport_identity = Some(identity);
+        self
+    }
+
+    /// The client the HTTP auth middleware resolved for this request, falling back to the
+    /// identity of the transport the session runs on.
+    fn client_identity(&self, context: &RequestContext<RoleServer>) -> Option<McpClientIdentity> {
+        context
+            .extensions
+            .get::<axum08::http::request::Parts>()
+            .and_then(|parts| parts.extensions.get::<McpClientIdentity>())
+            .cloned()
+            .or_else(|| self.transport_identity.clone())
+    }
+
     async fn audit_tool_call(
         &self,
+        client: Option<&McpClientIdentity>,
         tool_name: &str,
         tier: &str,
         status: AuditStatus,
@@ -160,13 +442,60 @@ impl TariMcpHandler {
             tier: tier.to_string(),
             status,
             duration_ms,
-            client_info: None,
+            client_info: client.map(|c| c.name.clone()),
             details: None,
+            prev_hash: None,
+            hash: None,
+        };
+        AuditLog::record(entry).await;
+    }
+
+    async fn audit_resource_call(
+        &self,
+        client: Option<&McpClientIdentity>,
+        action: &str,
+        resource: McpResource,
+        status: AuditStatus,
+        duration_ms: Option<u64>,
+    ) {
+        let entry = AuditEntry {
+            timestamp: std::time::SystemTime::now(),
+            tool_name: action.to_string(),
+            tier: resource.tier().to_string(),
+            status,
+            duration_ms,
+            client_info: client.map(|c| c.name.clone()),
+            details: Some(resource.uri().to_string()),
+            prev_hash: None,
+            hash: None,
         };
         AuditLog::record(entry).await;
     }
 
-    async fn is_tier_enabled(tier: &str) -> bool {
+    async fn resolve_resource(
+        client: Option<&McpClientIdentity>,
+        uri: &str,
+    ) -> Result<McpResource, ErrorData> {
+        let resource = McpResource::from_uri(uri).ok_or_else(|| {
+            ErrorData::resource_not_found(format!("Unknown resource: {uri}"), None)
+        })?;
+        if !Self::is_tier_enabled(client, resource.tier()).await {
+            return Err(ErrorData::invalid_request(
+                format!(
+                    "The {} tier required for {uri} is disabled",
+                    resource.tier()
+                ),
+                None,
+            ));
+        }
+        Ok(resource)
+    }
+
+    /// A tier must be enabled globally and, for named clients, granted to the client.
+    async fn is_tier_enabled(client: Option<&McpClientIdentity>, tier: &str) -> bool {
+        if client.is_some_and(|c| !c.has_tier(tier)) {
+            return false;
+        }
         let config = ConfigMcp::content().await;
         match tier {
             "read" => *config.read_tier_enabled(),
@@ -186,13 +515,23 @@ impl TariMcpHandler {
         name = "get_mining_status",
         description = "Get current mining status: CPU/GPU running state, mining mode, and enabled settings"
     )]
-    async fn get_mining_status(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
+    async fn get_mining_status(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
             return Err("Control tier is disabled".to_string());
         }
         let start = Instant::now();
-        self.audit_tool_call("get_mining_status", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_mining_status",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::get_mining_status().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -200,6 +539,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            client.as_ref(),
             "get_mining_status",
             "control",
             status,
@@ -214,13 +554,20 @@ impl TariMcpHandler {
         name = "get_mining_mode",
         description = "Get the currently selected mining mode and its CPU/GPU usage percentages"
     )]
-    async fn get_mining_mode(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
+    async fn get_mining_mode(&self, context: RequestContext<RoleServer>) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
             return Err("Control tier is disabled".to_string());
         }
         let start = Instant::now();
-        self.audit_tool_call("get_mining_mode", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_mining_mode",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::get_mining_mode().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -228,6 +575,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            client.as_ref(),
             "get_mining_mode",
             "control",
             status,
@@ -242,13 +590,23 @@ impl TariMcpHandler {
         name = "list_mining_modes",
         description = "List all available mining modes (Eco, Turbo, Ludicrous, Custom) with their CPU/GPU usage settings"
     )]
-    async fn list_mining_modes(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
+    async fn list_mining_modes(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
             return Err("Control tier is disabled".to_string());
         }
         let start = Instant::now();
-        self.audit_tool_call("list_mining_modes", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            client.as_ref(),
+            "list_mining_modes",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::list_mining_modes().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -256,6 +614,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            client.as_ref(),
             "list_mining_modes",
             "control",
             status,
@@ -273,14 +632,22 @@ impl TariMcpHandler {
     async fn start_mining(
         &self,
         Parameters(params): Parameters<StartStopMiningParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
             return Err("Control tier is disabled".to_string());
         }
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: start_mining called (cpu={:?}, gpu={:?})", params.cpu, params.gpu);
-        self.audit_tool_call("start_mining", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            client.as_ref(),
+            "start_mining",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::start_mining(params.cpu, params.gpu).await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -288,6 +655,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            client.as_ref(),
             "start_mining",
             "control",
             status,
@@ -305,14 +673,22 @@ impl TariMcpHandler {
     async fn stop_mining(
         &self,
         Parameters(params): Parameters<StartStopMiningParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
             return Err("Control tier is disabled".to_string());
         }
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: stop_mining called (cpu={:?}, gpu={:?})", params.cpu, params.gpu);
-        self.audit_tool_call("stop_mining", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            client.as_ref(),
+            "stop_mining",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::stop_mining(params.cpu, params.gpu).await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -320,6 +696,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            client.as_ref(),
             "stop_mining",
             "control",
             status,
@@ -337,14 +714,22 @@ impl TariMcpHandler {
     async fn set_mining_mode(
         &self,
         Parameters(params): Parameters<SetMiningModeParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
             return Err("Control tier is disabled".to_string());
         }
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: set_mining_mode called (mode={})", params.mode);
-        self.audit_tool_call("set_mining_mode", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            client.as_ref(),
+            "set_mining_mode",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::set_mining_mode(params.mode).await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -352,6 +737,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            client.as_ref(),
             "set_mining_mode",
             "control",
             status,
@@ -366,13 +752,20 @@ impl TariMcpHandler {
         name = "get_gpu_devices",
         description = "List GPU devices with vendor, name, availability, and current parameters (temperature, usage)"
     )]
-    async fn get_gpu_devices(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
+    async fn get_gpu_devices(&self, context: RequestContext<RoleServer>) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
             return Err("Control tier is disabled".to_string());
         }
         let start = Instant::now();
-        self.audit_tool_call("get_gpu_devices", "control", AuditStatus::Started, None)
-            .await;
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_gpu_devices",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
         let result = mining::get_gpu_devices().await;
         let status = if result.is_ok() {
             AuditStatus::Success
@@ -380,6 +773,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            client.as_ref(),
             "get_gpu_devices",
             "control",
             status,
@@ -389,28 +783,36 @@ impl TariMcpHandler {
         result
     }
 
-    // ==================== Wallet Tools (Read tier) ====================
+    // ==================== Pool Tools (Read/Control tier) ====================
 
-    /// Get the wallet's Tari address in multiple formats.
+    /// Get the selected pools and their latest stats.
     #[tool(
-        name = "get_wallet_address",
-        description = "Get the wallet's Tari address in emoji, base58, and hex formats"
+        name = "get_pool_status",
+        description = "Get CPU and GPU pool mining state: selected pool, pool vs. solo mining, accepted shares, unpaid amount, balance and minimum payout"
     )]
-    async fn get_wallet_address(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
+    async fn get_pool_status(&self, context: RequestContext<RoleServer>) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "read").await {
             return Err("Read tier is disabled".to_string());
         }
         let start = Instant::now();
-        self.audit_tool_call("get_wallet_address", "read", AuditStatus::Started, None)
-            .await;
-        let result = wallet::get_wallet_address().await;
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_pool_status",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = pools::get_pool_status().await;
         let status = if result.is_ok() {
             AuditStatus::Success
         } else {
             AuditStatus::Error
         };
         self.audit_tool_call(
-            "get_wallet_address",
+            client.as_ref(),
+            "get_pool_status",
             "read",
             status,
             Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
@@ -419,26 +821,34 @@ impl TariMcpHandler {
         result
     }
 
-    /// Get the wallet balance.
+    /// List the available CPU and GPU pools.
     #[tool(
-        name = "get_wallet_balance",
-        description = "Get the wallet balance including available, pending, and timelocked amounts"
+        name = "list_pools",
+        description = "List the available CPU and GPU pools and which one is selected for each"
     )]
-    async fn get_wallet_balance(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
+    async fn list_pools(&self, context: RequestContext<RoleServer>) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "read").await {
             return Err("Read tier is disabled".to_string());
         }
         let start = Instant::now();
-        self.audit_tool_call("get_wallet_balance", "read", AuditStatus::Started, None)
-            .await;
-        let result = wallet::get_wallet_balance(&self.wallet_manager).await;
+        self.audit_tool_call(
+            client.as_ref(),
+            "list_pools",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = pools::list_pools().await;
         let status = if result.is_ok() {
             AuditStatus::Success
         } else {
             AuditStatus::Error
         };
         self.audit_tool_call(
-            "get_wallet_balance",
+            client.as_ref(),
+            "list_pools",
             "read",
             status,
             Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
@@ -447,35 +857,40 @@ impl TariMcpHandler {
         result
     }
 
-    /// Get recent transaction history.
+    /// Switch the CPU or GPU pool.
     #[tool(
-        name = "get_transaction_history",
-        description = "Get recent transaction history with configurable limit"
+        name = "change_pool",
+        description = "Switch the CPU or GPU miner to another pool from list_pools"
     )]
-    async fn get_transaction_history(
+    async fn change_pool(
         &self,
-        Parameters(params): Parameters<GetTransactionHistoryParams>,
+        Parameters(params): Parameters<ChangePoolParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
-            return Err("Read tier is disabled".to_string());
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
+            return Err("Control tier is disabled".to_string());
         }
         let start = Instant::now();
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: change_pool called (kind={}, pool={})", params.kind, params.pool);
         self.audit_tool_call(
-            "get_transaction_history",
-            "read",
+            client.as_ref(),
+            "change_pool",
+            "control",
             AuditStatus::Started,
             None,
         )
         .await;
-        let result = wallet::get_transaction_history(&self.wallet_manager, params.limit).await;
+        let result = pools::change_pool(&params.kind, params.pool).await;
         let status = if result.is_ok() {
             AuditStatus::Success
         } else {
             AuditStatus::Error
         };
         self.audit_tool_call(
-            "get_transaction_history",
-            "read",
+            client.as_ref(),
+            "change_pool",
+            "control",
             status,
             Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
         )
@@ -483,94 +898,120 @@ impl TariMcpHandler {
         result
     }
 
-    // ==================== Chain Tools (Read tier) ====================
-
-    /// Get the current chain status.
+    /// Toggle pool vs. solo mining for the CPU or GPU miner.
     #[tool(
-        name = "get_chain_status",
-        description = "Get chain status: block height, block time, block reward, sync status, peer count"
+        name = "toggle_pool_mining",
+        description = "Switch the CPU or GPU miner between pool mining (enabled=true) and solo mining (enabled=false)"
     )]
-    async fn get_chain_status(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
-            return Err("Read tier is disabled".to_string());
+    async fn toggle_pool_mining(
+        &self,
+        Parameters(params): Parameters<TogglePoolMiningParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
+            return Err("Control tier is disabled".to_string());
         }
         let start = Instant::now();
-        self.audit_tool_call("get_chain_status", "read", AuditStatus::Started, None)
-            .await;
-        let status = *self.node_status_rx.borrow();
-        let result = chain::get_chain_status(&status);
-        let audit_status = if result.is_ok() {
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: toggle_pool_mining called (kind={}, enabled={})", params.kind, params.enabled);
+        self.audit_tool_call(
+            client.as_ref(),
+            "toggle_pool_mining",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = pools::toggle_pool_mining(&params.kind, params.enabled).await;
+        let status = if result.is_ok() {
             AuditStatus::Success
         } else {
             AuditStatus::Error
         };
         self.audit_tool_call(
-            "get_chain_status",
-            "read",
-            audit_status,
+            client.as_ref(),
+            "toggle_pool_mining",
+            "control",
+            status,
             Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
         )
         .await;
         result
     }
 
-    /// Get network information.
+    // ==================== Wallet Tools (Read tier) ====================
+
+    /// Get the wallet's Tari address in multiple formats.
     #[tool(
-        name = "get_network_info",
-        description = "Get network info: network name, sync status, connection count"
+        name = "get_wallet_address",
+        description = "Get the wallet's Tari address in emoji, base58, and hex formats"
     )]
-    async fn get_network_info(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("read").await {
+    async fn get_wallet_address(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "read").await {
             return Err("Read tier is disabled".to_string());
         }
         let start = Instant::now();
-        self.audit_tool_call("get_network_info", "read", AuditStatus::Started, None)
-            .await;
-        let status = *self.node_status_rx.borrow();
-        let result = chain::get_network_info(&status);
-        let audit_status = if result.is_ok() {
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_wallet_address",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = wallet::get_wallet_address().await;
+        let status = if result.is_ok() {
             AuditStatus::Success
         } else {
             AuditStatus::Error
         };
         self.audit_tool_call(
-            "get_network_info",
+            client.as_ref(),
+            "get_wallet_address",
             "read",
-            audit_status,
+            status,
             Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
         )
         .await;
         result
     }
 
-    // ==================== Scheduler Tools (Control tier) ====================
-
-    /// List all scheduled mining events.
+    /// Get the wallet balance.
     #[tool(
-        name = "list_scheduled_events",
-        description = "List all scheduled mining events with their timing and state"
+        name = "get_wallet_balance",
+        description = "Get the wallet balance including available, pending, and timelocked amounts"
     )]
-    async fn list_scheduled_events(&self) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
-            return Err("Control tier is disabled".to_string());
+    async fn get_wallet_balance(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "read").await {
+            return Err("Read tier is disabled".to_string());
         }
         let start = Instant::now();
         self.audit_tool_call(
-            "list_scheduled_events",
-            "control",
+            client.as_ref(),
+            "get_wallet_balance",
+            "read",
             AuditStatus::Started,
             None,
         )
         .await;
-        let result = scheduler::list_scheduled_events().await;
+        let result = wallet::get_wallet_balance(&self.wallet_manager).await;
         let status = if result.is_ok() {
             AuditStatus::Success
         } else {
             AuditStatus::Error
         };
         self.audit_tool_call(
-            "list_scheduled_events",
-            "control",
+            client.as_ref(),
+            "get_wallet_balance",
+            "read",
             status,
             Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
         )
@@ -578,16 +1019,516 @@ impl TariMcpHandler {
         result
     }
 
-    /// Schedule recurring daily mining during a time window.
+    /// Get recent transaction history.
     #[tool(
-        name = "schedule_mining_window",
-        description = "Schedule recurring daily mining during a time window (e.g., 10 PM to 6 AM in Ludicrous mode)"
+        name = "get_transaction_history",
+        description = "Get recent transaction history with configurable limit"
+    )]
+    async fn get_transaction_history(
+        &self,
+        Parameters(params): Parameters<GetTransactionHistoryParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "read").await {
+            return Err("Read tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_transaction_history",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = wallet::get_transaction_history(&self.wallet_manager, params.limit).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_transaction_history",
+            "read",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    // ==================== Audit Tools (Read tier) ====================
+
+    /// Query the MCP audit log.
+    #[tool(
+        name = "query_audit_log",
+        description = "Query the MCP audit log by tool, tier, status and time range (unix seconds). Returns matching entries newest first. Set verify_chain to also check whether the log's hash chain is intact"
+    )]
+    async fn query_audit_log(
+        &self,
+        Parameters(params): Parameters<QueryAuditLogParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "read").await {
+            return Err("Read tier is disabled".to_string());
+        }
+        let query = AuditQuery::from_filters(
+            params.tool_name,
+            params.tier,
+            params.status,
+            params.since,
+            params.until,
+            params.limit,
+        )?;
+        let start = Instant::now();
+        self.audit_tool_call(
+            client.as_ref(),
+            "query_audit_log",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = audit_log::query_audit_log(query, params.verify_chain.unwrap_or(false)).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "query_audit_log",
+            "read",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    // ==================== Chain Tools (Read tier) ====================
+
+    /// Get the current chain status.
+    #[tool(
+        name = "get_chain_status",
+        description = "Get chain status: block height, block time, block reward, sync status, peer count"
+    )]
+    async fn get_chain_status(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "read").await {
+            return Err("Read tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_chain_status",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let status = *self.node_status_rx.borrow();
+        let result = chain::get_chain_status(&status);
+        let audit_status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_chain_status",
+            "read",
+            audit_status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// Get network information.
+    #[tool(
+        name = "get_network_info",
+        description = "Get network info: network name, sync status, connection count"
+    )]
+    async fn get_network_info(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "read").await {
+            return Err("Read tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_network_info",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let status = *self.node_status_rx.borrow();
+        let result = chain::get_network_info(&status);
+        let audit_status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_network_info",
+            "read",
+            audit_status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    // ==================== Node Tools (Read/Control tier) ====================
+
+    /// List the base node's connected peers.
+    #[tool(
+        name = "list_connected_peers",
+        description = "List the peers the local base node is connected to"
+    )]
+    async fn list_connected_peers(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "read").await {
+            return Err("Read tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        self.audit_tool_call(
+            client.as_ref(),
+            "list_connected_peers",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = chain::list_connected_peers(&self.node_manager).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "list_connected_peers",
+            "read",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// Get a page of recent block stats.
+    #[tool(
+        name = "get_recent_blocks",
+        description = "Get recent block stats (height, coinbase, outputs, PoW algorithm, timestamp), newest first. Paginate with before_height"
+    )]
+    async fn get_recent_blocks(
+        &self,
+        Parameters(params): Parameters<GetRecentBlocksParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "read").await {
+            return Err("Read tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_recent_blocks",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result =
+            chain::get_recent_blocks(&self.node_manager, params.limit, params.before_height).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_recent_blocks",
+            "read",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// Get the base node identity.
+    #[tool(
+        name = "get_node_identity",
+        description = "Get the base node's node type, public key and public addresses"
+    )]
+    async fn get_node_identity(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "read").await {
+            return Err("Read tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_node_identity",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = chain::get_node_identity(&self.node_manager).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_node_identity",
+            "read",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// Get orphan chain status.
+    #[tool(
+        name = "get_orphan_chain_status",
+        description = "Report whether the node is stuck on an orphan chain. Set recheck to run a fresh check"
+    )]
+    async fn get_orphan_chain_status(
+        &self,
+        Parameters(params): Parameters<GetOrphanChainStatusParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "read").await {
+            return Err("Read tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_orphan_chain_status",
+            "read",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result =
+            chain::get_orphan_chain_status(&self.node_manager, params.recheck.unwrap_or(false))
+                .await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_orphan_chain_status",
+            "read",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// Switch the base node type.
+    #[tool(
+        name = "set_node_type",
+        description = "Switch the base node type to Local, Remote, or RemoteUntilLocal. Restarts the node, wallet and CPU mining phases when needed"
+    )]
+    async fn set_node_type(
+        &self,
+        Parameters(params): Parameters<SetNodeTypeParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
+            return Err("Control tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: set_node_type called (node_type={})", params.node_type);
+        self.audit_tool_call(
+            client.as_ref(),
+            "set_node_type",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = chain::set_node_type(&self.node_manager, params.node_type).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "set_node_type",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// Set the remote base node address.
+    #[tool(
+        name = "set_remote_base_node_address",
+        description = "Set the remote base node gRPC address (scheme://host:port). An empty address restores the default"
+    )]
+    async fn set_remote_base_node_address(
+        &self,
+        Parameters(params): Parameters<SetRemoteBaseNodeAddressParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
+            return Err("Control tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: set_remote_base_node_address called (address={})", params.address);
+        self.audit_tool_call(
+            client.as_ref(),
+            "set_remote_base_node_address",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = chain::set_remote_base_node_address(params.address).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "set_remote_base_node_address",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    // ==================== Scheduler Tools (Control tier) ====================
+
+    /// List all scheduled mining events.
+    #[tool(
+        name = "list_scheduled_events",
+        description = "List all scheduled mining events with their timing, state, next activation time and execution journal (recent runs with their outcome)"
+    )]
+    async fn list_scheduled_events(
+        &self,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
+            return Err("Control tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        self.audit_tool_call(
+            client.as_ref(),
+            "list_scheduled_events",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = scheduler::list_scheduled_events().await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "list_scheduled_events",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// List the upcoming activations of all scheduled events.
+    #[tool(
+        name = "get_scheduled_event_activations",
+        description = "List the next start and end times of every scheduled event. Paused events have none; one-time events only have a start"
+    )]
+    async fn get_scheduled_event_activations(
+        &self,
+        Parameters(params): Parameters<GetScheduledEventActivationsParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
+            return Err("Control tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_scheduled_event_activations",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = scheduler::get_scheduled_event_activations(params.count).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "get_scheduled_event_activations",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// Schedule recurring daily mining during a time window.
+    #[tool(
+        name = "schedule_mining_window",
+        description = "Schedule recurring daily mining during a time window (e.g., 10 PM to 6 AM in Ludicrous mode)"
     )]
     async fn schedule_mining_window(
         &self,
         Parameters(params): Parameters<ScheduleMiningWindowParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
             return Err("Control tier is disabled".to_string());
         }
         let start = Instant::now();
@@ -596,6 +1537,7 @@ impl TariMcpHandler {
             params.start_hour, params.start_minute.unwrap_or(0), params.start_period,
             params.end_hour, params.end_minute.unwrap_or(0), params.end_period);
         self.audit_tool_call(
+            client.as_ref(),
             "schedule_mining_window",
             "control",
             AuditStatus::Started,
@@ -619,6 +1561,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            client.as_ref(),
             "schedule_mining_window",
             "control",
             status,
@@ -636,13 +1579,16 @@ impl TariMcpHandler {
     async fn cancel_scheduled_event(
         &self,
         Parameters(params): Parameters<CancelScheduledEventParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("control").await {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
             return Err("Control tier is disabled".to_string());
         }
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: cancel_scheduled_event called (id={})", params.event_id);
         self.audit_tool_call(
+            client.as_ref(),
             "cancel_scheduled_event",
             "control",
             AuditStatus::Started,
@@ -656,6 +1602,7 @@ impl TariMcpHandler {
             AuditStatus::Error
         };
         self.audit_tool_call(
+            client.as_ref(),
             "cancel_scheduled_event",
             "control",
             status,
@@ -665,6 +1612,147 @@ impl TariMcpHandler {
         result
     }
 
+    /// Schedule a one-off or recurring scheduler event.
+    #[tool(
+        name = "schedule_event",
+        description = "Schedule a mining, pool or node action, either once after a delay or as a recurring window given in 24-hour HH:MM times, optionally limited to weekdays and a yearly date range. Returns the event with its next activation time"
+    )]
+    async fn schedule_event(
+        &self,
+        Parameters(params): Parameters<ScheduleEventParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
+            return Err("Control tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: schedule_event called (id={}, action={})", params.event_id, params.action);
+        self.audit_tool_call(
+            client.as_ref(),
+            "schedule_event",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = scheduler::schedule_event(scheduler::ScheduleEventParams {
+            event_id: params.event_id,
+            action: params.action,
+            action_args: scheduler::EventActionArgs {
+                mining_mode: params.mining_mode,
+                enabled: params.enabled,
+                pool: params.pool,
+                node_type: params.node_type,
+            },
+            priority: params.priority,
+            delay_value: params.delay_value,
+            delay_unit: params.delay_unit,
+            start_time: params.start_time,
+            end_time: params.end_time,
+            weekdays: params.weekdays,
+            start_date: params.start_date,
+            end_date: params.end_date,
+        })
+        .await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "schedule_event",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// Pause a scheduled event.
+    #[tool(
+        name = "pause_scheduled_event",
+        description = "Pause a scheduled event without removing it. It can be resumed later with resume_scheduled_event"
+    )]
+    async fn pause_scheduled_event(
+        &self,
+        Parameters(params): Parameters<ScheduledEventIdParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
+            return Err("Control tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: pause_scheduled_event called (id={})", params.event_id);
+        self.audit_tool_call(
+            client.as_ref(),
+            "pause_scheduled_event",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = scheduler::pause_scheduled_event(params.event_id).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "pause_scheduled_event",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
+    /// Resume a paused scheduled event.
+    #[tool(
+        name = "resume_scheduled_event",
+        description = "Resume a paused scheduled event. Returns the event with its next activation time"
+    )]
+    async fn resume_scheduled_event(
+        &self,
+        Parameters(params): Parameters<ScheduledEventIdParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<String, String> {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "control").await {
+            return Err("Control tier is disabled".to_string());
+        }
+        let start = Instant::now();
+        info!(target: LOG_TARGET_APP_LOGIC, "MCP: resume_scheduled_event called (id={})", params.event_id);
+        self.audit_tool_call(
+            client.as_ref(),
+            "resume_scheduled_event",
+            "control",
+            AuditStatus::Started,
+            None,
+        )
+        .await;
+        let result = scheduler::resume_scheduled_event(params.event_id).await;
+        let status = if result.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::Error
+        };
+        self.audit_tool_call(
+            client.as_ref(),
+            "resume_scheduled_event",
+            "control",
+            status,
+            Some(u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        )
+        .await;
+        result
+    }
+
     // ==================== Transaction Tools (Transaction tier) ====================
 
     /// Send a one-sided stealth transaction to a Tari address.
@@ -675,13 +1763,16 @@ impl TariMcpHandler {
     async fn send_transaction(
         &self,
         Parameters(params): Parameters<SendTransactionParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<String, String> {
-        if !Self::is_tier_enabled("transaction").await {
+        let client = self.client_identity(&context);
+        if !Self::is_tier_enabled(client.as_ref(), "transaction").await {
             return Err("Transaction tier is disabled".to_string());
         }
         let start = Instant::now();
         info!(target: LOG_TARGET_APP_LOGIC, "MCP: send_transaction called (destination={}, amount={})", params.destination, params.amount);
         self.audit_tool_call(
+            client.as_ref(),
             "send_transaction",
             "transaction",
             AuditStatus::Started,
@@ -703,9 +1794,14 @@ impl TariMcpHandler {
             Ok(_) => AuditStatus::Success,
             Err(transaction::TransactionError::Denied(_)) => AuditStatus::Denied,
             Err(transaction::TransactionError::RateLimited(_)) => AuditStatus::RateLimited,
+            Err(transaction::TransactionError::BudgetExceeded(_)) => AuditStatus::BudgetExceeded,
+            Err(transaction::TransactionError::DestinationNotAllowed(_)) => {
+                AuditStatus::DestinationNotAllowed
+            }
             Err(_) => AuditStatus::Error,
         };
         self.audit_tool_call(
+            client.as_ref(),
             "send_transaction",
             "transaction",
             status,
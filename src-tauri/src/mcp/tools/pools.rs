@@ -0,0 +1,210 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use serde::Serialize;
+use serde_json::json;
+
+use crate::commands;
+use crate::configs::config_pools::ConfigPools;
+use crate::configs::pools::BasePoolData;
+use crate::configs::pools::{cpu_pools::CpuPool, gpu_pools::GpuPool};
+use crate::configs::trait_config::ConfigImpl;
+use crate::events_emitter::EventsEmitter;
+use crate::mining::cpu::manager::CpuManager;
+use crate::mining::gpu::manager::GpuManager;
+use crate::mining::pools::PoolManagerInterfaceTrait;
+use crate::mining::pools::PoolStatus;
+use crate::mining::pools::cpu_pool_manager::CpuPoolManager;
+use crate::mining::pools::gpu_pool_manager::GpuPoolManager;
+
+pub(super) fn pool_summary<T: Display>(
+    enabled: bool,
+    current: &BasePoolData<T>,
+    statuses: &HashMap<String, PoolStatus>,
+) -> serde_json::Value {
+    let pool_key = current.pool_type.to_string();
+    json!({
+        "pool_mining_enabled": enabled,
+        "selected_pool": pool_key,
+        "pool_name": current.pool_name.trim(),
+        "pool_url": current.pool_url,
+        "status": statuses.get(&pool_key),
+    })
+}
+
+pub(super) fn pool_list<T: Display + Serialize + Eq>(
+    pools: &HashMap<T, BasePoolData<T>>,
+    selected: &T,
+) -> Vec<serde_json::Value> {
+    let mut result: Vec<serde_json::Value> = pools
+        .values()
+        .map(|pool| {
+            json!({
+                "pool": pool.pool_type.to_string(),
+                "pool_name": pool.pool_name.trim(),
+                "pool_url": pool.pool_url,
+                "origin": pool.pool_origin,
+                "selected": &pool.pool_type == selected,
+            })
+        })
+        .collect();
+    result.sort_by(|a, b| a["pool"].as_str().cmp(&b["pool"].as_str()));
+    result
+}
+
+pub async fn get_pool_status() -> Result<String, String> {
+    let config = ConfigPools::content().await;
+    let cpu_statuses = CpuPoolManager::get_pool_statuses().await;
+    let gpu_statuses = GpuPoolManager::get_pool_statuses().await;
+
+    let result = json!({
+        "cpu": pool_summary(*config.cpu_pool_enabled(), &config.current_cpu_pool(), &cpu_statuses),
+        "gpu": pool_summary(*config.gpu_pool_enabled(), &config.current_gpu_pool(), &gpu_statuses),
+    });
+
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+pub async fn list_pools() -> Result<String, String> {
+    let config = ConfigPools::content().await;
+
+    let result = json!({
+        "cpu": pool_list(config.cpu_pools(), &config.current_cpu_pool().pool_type),
+        "gpu": pool_list(config.gpu_pools(), &config.current_gpu_pool().pool_type),
+    });
+
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+/// Goes through the same command the settings screen uses, so the config is persisted and the
+/// pool stats watcher picks up the new adapter.
+pub async fn change_pool(kind: &str, pool: String) -> Result<String, String> {
+    match kind {
+        "cpu" => {
+            CpuPool::from_string(&pool).map_err(|e| format!("{e}: {pool}"))?;
+            change_cpu_pool(pool).await?;
+        }
+        "gpu" => {
+            GpuPool::from_string(&pool).map_err(|e| format!("{e}: {pool}"))?;
+            change_gpu_pool(pool).await?;
+        }
+        _ => {
+            return Err(format!(
+                "Invalid pool kind '{kind}', expected 'cpu' or 'gpu'"
+            ));
+        }
+    }
+    EventsEmitter::emit_pools_config_loaded(&ConfigPools::content().await).await;
+
+    get_pool_status().await
+}
+
+/// Same sequence as the frontend: stop a running miner, change the pool, then start the
+/// miner again, also when the change failed, so it keeps mining on the previous pool.
+pub(super) async fn restart_around_pool_change(
+    was_running: bool,
+    stop: impl Future<Output = Result<(), String>>,
+    change: impl Future<Output = Result<(), String>>,
+    start: impl Future<Output = Result<(), String>>,
+) -> Result<(), String> {
+    if was_running {
+        stop.await?;
+    }
+    let result = change.await;
+    if was_running {
+        start.await?;
+    }
+    result
+}
+
+/// Changes the cpu pool and restarts a running miner so it picks the pool up. Shared with the
+/// scheduler's pool switch events.
+pub(crate) async fn change_cpu_pool(pool: String) -> Result<(), String> {
+    restart_around_pool_change(
+        CpuManager::read().await.is_running(),
+        async {
+            CpuManager::write()
+                .await
+                .stop_mining()
+                .await
+                .map_err(|e| format!("Failed to stop CPU mining: {e}"))
+        },
+        async {
+            commands::change_cpu_pool(pool)
+                .await
+                .map_err(|e| format!("Failed to change CPU pool: {}", e.0))
+        },
+        async {
+            CpuManager::write()
+                .await
+                .start_mining()
+                .await
+                .map_err(|e| format!("Failed to restart CPU mining: {e}"))
+        },
+    )
+    .await
+}
+
+/// Changes the gpu pool and restarts a running miner so it picks the pool up. Shared with the
+/// scheduler's pool switch events.
+pub(crate) async fn change_gpu_pool(pool: String) -> Result<(), String> {
+    restart_around_pool_change(
+        GpuManager::read().await.is_running(),
+        async {
+            GpuManager::write()
+                .await
+                .stop_mining()
+                .await
+                .map_err(|e| format!("Failed to stop GPU mining: {e}"))
+        },
+        async {
+            commands::change_gpu_pool(pool)
+                .await
+                .map_err(|e| format!("Failed to change GPU pool: {}", e.0))
+        },
+        async {
+            GpuManager::write()
+                .await
+                .start_mining()
+                .await
+                .map_err(|e| format!("Failed to restart GPU mining: {e}"))
+        },
+    )
+    .await
+}
+
+pub async fn toggle_pool_mining(kind: &str, enabled: bool) -> Result<String, String> {
+    match kind {
+        "cpu" => commands::toggle_cpu_pool_mining(enabled).await?,
+        "gpu" => commands::toggle_gpu_pool_mining(enabled).await?,
+        _ => {
+            return Err(format!(
+                "Invalid pool kind '{kind}', expected 'cpu' or 'gpu'"
+            ));
+        }
+    }
+
+    get_pool_status().await
+}
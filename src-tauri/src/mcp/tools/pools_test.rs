@@ -0,0 +1,130 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::configs::pools::cpu_pools::CpuPool;
+use crate::configs::pools::gpu_pools::GpuPool;
+use crate::mining::pools::PoolStatus;
+
+use super::pools::{pool_list, pool_summary, restart_around_pool_change};
+
+#[test]
+fn pool_summary_includes_status_of_selected_pool() {
+    let current = CpuPool::KryptexPoolRANDOMX.default_content();
+    let mut statuses = HashMap::new();
+    statuses.insert(
+        "KryptexPoolRANDOMX".to_string(),
+        PoolStatus {
+            accepted_shares: 42,
+            unpaid: 1.5,
+            balance: 3.0,
+            min_payout: 10,
+        },
+    );
+    statuses.insert("LuckyPoolRANDOMX".to_string(), PoolStatus::default());
+
+    let summary = pool_summary(true, &current, &statuses);
+
+    assert_eq!(summary["pool_mining_enabled"], true);
+    assert_eq!(summary["selected_pool"], "KryptexPoolRANDOMX");
+    assert_eq!(summary["status"]["accepted_shares"], 42);
+    assert_eq!(summary["status"]["unpaid"], 1.5);
+    assert_eq!(summary["status"]["min_payout"], 10);
+}
+
+#[test]
+fn pool_summary_without_fetched_status_is_null() {
+    let current = GpuPool::LuckyPoolC29.default_content();
+
+    let summary = pool_summary(false, &current, &HashMap::new());
+
+    assert_eq!(summary["pool_mining_enabled"], false);
+    assert!(summary["status"].is_null());
+}
+
+#[test]
+fn pool_list_marks_selected_pool_and_is_sorted() {
+    let pools = CpuPool::load_default_pools_data();
+
+    let list = pool_list(&pools, &CpuPool::LuckyPoolRANDOMX);
+
+    let names: Vec<&str> = list.iter().filter_map(|p| p["pool"].as_str()).collect();
+    assert_eq!(
+        names,
+        vec![
+            "KryptexPoolRANDOMX",
+            "LuckyPoolRANDOMX",
+            "SupportXTMPoolRANDOMX"
+        ]
+    );
+    let selected: Vec<&str> = list
+        .iter()
+        .filter(|p| p["selected"] == true)
+        .filter_map(|p| p["pool"].as_str())
+        .collect();
+    assert_eq!(selected, vec!["LuckyPoolRANDOMX"]);
+}
+
+#[tokio::test]
+async fn running_miner_is_restarted_on_the_new_pool() {
+    let steps = Mutex::new(vec![]);
+    let step = |name: &'static str, result: Result<(), String>| {
+        let steps = &steps;
+        async move {
+            steps.lock().unwrap().push(name);
+            result
+        }
+    };
+
+    let result = restart_around_pool_change(
+        true,
+        step("stop", Ok(())),
+        step("change", Ok(())),
+        step("start", Ok(())),
+    )
+    .await;
+    assert_eq!(result, Ok(()));
+    assert_eq!(*steps.lock().unwrap(), vec!["stop", "change", "start"]);
+
+    steps.lock().unwrap().clear();
+    let result = restart_around_pool_change(
+        true,
+        step("stop", Ok(())),
+        step("change", Err("unknown pool".to_string())),
+        step("start", Ok(())),
+    )
+    .await;
+    assert_eq!(result, Err("unknown pool".to_string()));
+    assert_eq!(*steps.lock().unwrap(), vec!["stop", "change", "start"]);
+
+    steps.lock().unwrap().clear();
+    let result = restart_around_pool_change(
+        false,
+        step("stop", Ok(())),
+        step("change", Ok(())),
+        step("start", Ok(())),
+    )
+    .await;
+    assert_eq!(result, Ok(()));
+    assert_eq!(*steps.lock().unwrap(), vec!["change"]);
+}
@@ -20,10 +20,18 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use chrono::{Local, TimeZone};
+
+use super::chain::parse_node_type;
+
 use crate::configs::config_core::ConfigCore;
+use crate::configs::config_mining::ConfigMining;
+use crate::configs::pools::cpu_pools::CpuPool;
+use crate::configs::pools::gpu_pools::GpuPool;
 use crate::configs::trait_config::ConfigImpl;
 use crate::event_scheduler::{
-    EventScheduler, SchedulerEventTiming, SchedulerEventType, TimePeriod,
+    DayOfWeek, EventActivations, EventScheduler, ScheduleDateRange, ScheduledEventInfo,
+    SchedulerEventTiming, SchedulerEventType, TimePeriod, TimeUnit,
 };
 use crate::events_emitter::EventsEmitter;
 
@@ -32,13 +40,335 @@ pub async fn list_scheduled_events() -> Result<String, String> {
         .list_events()
         .await
         .map_err(|e| e.to_string())?;
-    let result: Vec<serde_json::Value> = events
-        .iter()
-        .map(|e| serde_json::to_value(e).unwrap_or_default())
-        .collect();
+    let result: Vec<serde_json::Value> = events.iter().map(event_summary).collect();
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+/// Default number of upcoming activations returned per event.
+const DEFAULT_ACTIVATION_COUNT: usize = 5;
+
+/// Lists the next start and end times of every scheduled event.
+pub async fn get_scheduled_event_activations(count: Option<usize>) -> Result<String, String> {
+    let activations = EventScheduler::instance()
+        .upcoming_activations(count.unwrap_or(DEFAULT_ACTIVATION_COUNT))
+        .await
+        .map_err(|e| e.to_string())?;
+    let result: Vec<serde_json::Value> = activations.iter().map(activations_summary).collect();
     serde_json::to_string(&result).map_err(|e| e.to_string())
 }
 
+/// Converts an event's activation timestamps to local RFC 3339 times.
+pub(super) fn activations_summary(activations: &EventActivations) -> serde_json::Value {
+    let to_times = |timestamps: &[i64]| -> Vec<String> {
+        timestamps.iter().filter_map(|ts| to_rfc3339(*ts)).collect()
+    };
+    serde_json::json!({
+        "event_id": activations.event_id,
+        "starts": to_times(&activations.starts),
+        "ends": to_times(&activations.ends),
+    })
+}
+
+fn to_rfc3339(timestamp: i64) -> Option<String> {
+    Local
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|at| at.to_rfc3339())
+}
+
+/// Serializes an event, adding its next activation as a local RFC 3339 time.
+pub(super) fn event_summary(event: &ScheduledEventInfo) -> serde_json::Value {
+    let mut value = serde_json::to_value(event).unwrap_or_default();
+    let next_activation_at = event.next_activation.and_then(to_rfc3339);
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert(
+            "next_activation_at".to_string(),
+            serde_json::json!(next_activation_at),
+        );
+    }
+    value
+}
+
+pub struct ScheduleEventParams {
+    pub event_id: String,
+    pub action: String,
+    pub action_args: EventActionArgs,
+    pub priority: Option<u32>,
+    pub delay_value: Option<i64>,
+    pub delay_unit: Option<String>,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub weekdays: Option<Vec<String>>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+}
+
+/// Schedules any event the scheduler supports: a one-off delay (`In`) or a
+/// recurring window (`Between`) given in 24-hour `HH:MM` times, optionally
+/// limited to certain weekdays and a yearly `MM-DD` date range.
+pub async fn schedule_event(params: ScheduleEventParams) -> Result<String, String> {
+    let event_type = parse_event_type(&params.action, params.action_args)?;
+    if let SchedulerEventType::Mine { mining_mode } = &event_type {
+        check_mining_mode(
+            mining_mode,
+            ConfigMining::content().await.mining_modes().keys(),
+        )?;
+    }
+    let timing = parse_timing(
+        params.delay_value,
+        params.delay_unit.as_deref(),
+        params.start_time.as_deref(),
+        params.end_time.as_deref(),
+    )?;
+    let timing = apply_calendar(
+        timing,
+        params.weekdays,
+        params.start_date.as_deref(),
+        params.end_date.as_deref(),
+    )?;
+
+    EventScheduler::instance()
+        .schedule_event_with_priority(
+            event_type,
+            params.event_id.clone(),
+            timing,
+            params.priority.unwrap_or(0),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    EventsEmitter::emit_core_config_loaded(&ConfigCore::content().await).await;
+
+    let event = find_event(&params.event_id).await?;
+    Ok(serde_json::json!({"status": "scheduled", "event": event}).to_string())
+}
+
+pub async fn pause_scheduled_event(event_id: String) -> Result<String, String> {
+    EventScheduler::instance()
+        .pause_event(event_id.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let event = find_event(&event_id).await?;
+    Ok(serde_json::json!({"status": "paused", "event": event}).to_string())
+}
+
+pub async fn resume_scheduled_event(event_id: String) -> Result<String, String> {
+    EventScheduler::instance()
+        .resume_event(event_id.clone())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let event = find_event(&event_id).await?;
+    Ok(serde_json::json!({"status": "resumed", "event": event}).to_string())
+}
+
+async fn find_event(event_id: &str) -> Result<serde_json::Value, String> {
+    EventScheduler::instance()
+        .list_events()
+        .await
+        .map_err(|e| e.to_string())?
+        .iter()
+        .find(|e| e.id == event_id)
+        .map(event_summary)
+        .ok_or_else(|| format!("Event not found: {event_id}"))
+}
+
+/// Action-specific arguments for `parse_event_type`.
+#[derive(Default)]
+pub struct EventActionArgs {
+    pub mining_mode: Option<String>,
+    pub enabled: Option<bool>,
+    pub pool: Option<String>,
+    pub node_type: Option<String>,
+}
+
+pub(super) fn parse_event_type(
+    action: &str,
+    args: EventActionArgs,
+) -> Result<SchedulerEventType, String> {
+    let action = action.to_lowercase();
+    let enabled = || {
+        args.enabled
+            .ok_or_else(|| format!("enabled is required for the '{action}' action"))
+    };
+    let pool = || {
+        args.pool
+            .clone()
+            .filter(|pool| !pool.trim().is_empty())
+            .ok_or_else(|| format!("pool is required for the '{action}' action"))
+    };
+    match action.as_str() {
+        "resume_mining" => Ok(SchedulerEventType::ResumeMining),
+        "mine" => {
+            let mining_mode = args
+                .mining_mode
+                .clone()
+                .filter(|mode| !mode.trim().is_empty())
+                .ok_or("mining_mode is required for the 'mine' action")?;
+            Ok(SchedulerEventType::Mine { mining_mode })
+        }
+        "cpu_mining" => Ok(SchedulerEventType::CpuMining {
+            enabled: enabled()?,
+        }),
+        "gpu_mining" => Ok(SchedulerEventType::GpuMining {
+            enabled: enabled()?,
+        }),
+        "switch_cpu_pool" => {
+            let pool_name = pool()?;
+            CpuPool::from_string(&pool_name).map_err(|e| e.to_string())?;
+            Ok(SchedulerEventType::SwitchCpuPool { pool_name })
+        }
+        "switch_gpu_pool" => {
+            let pool_name = pool()?;
+            GpuPool::from_string(&pool_name).map_err(|e| e.to_string())?;
+            Ok(SchedulerEventType::SwitchGpuPool { pool_name })
+        }
+        "cpu_pool_mining" => Ok(SchedulerEventType::CpuPoolMining {
+            enabled: enabled()?,
+        }),
+        "gpu_pool_mining" => Ok(SchedulerEventType::GpuPoolMining {
+            enabled: enabled()?,
+        }),
+        "switch_node_type" => {
+            let node_type = args
+                .node_type
+                .as_deref()
+                .ok_or("node_type is required for the 'switch_node_type' action")?;
+            Ok(SchedulerEventType::SwitchNodeType {
+                node_type: parse_node_type(node_type)?,
+            })
+        }
+        _ => Err(format!(
+            "Invalid action: {action}. Use 'resume_mining', 'mine', 'cpu_mining', 'gpu_mining', \
+             'switch_cpu_pool', 'switch_gpu_pool', 'cpu_pool_mining', 'gpu_pool_mining' or 'switch_node_type'"
+        )),
+    }
+}
+
+/// Checks that a `mine` action or the tariff cheap mode names one of the configured
+/// mining modes, so a typo is reported now instead of failing when the window opens.
+pub(crate) fn check_mining_mode<'a>(
+    mining_mode: &str,
+    known_modes: impl IntoIterator<Item = &'a String>,
+) -> Result<(), String> {
+    let mut known_modes: Vec<&String> = known_modes.into_iter().collect();
+    if known_modes.iter().any(|mode| *mode == mining_mode) {
+        return Ok(());
+    }
+    known_modes.sort();
+    let known_modes: Vec<&str> = known_modes.into_iter().map(String::as_str).collect();
+    Err(format!(
+        "Unknown mining mode: {mining_mode}. Available modes: {}",
+        known_modes.join(", ")
+    ))
+}
+
+pub(super) fn parse_timing(
+    delay_value: Option<i64>,
+    delay_unit: Option<&str>,
+    start_time: Option<&str>,
+    end_time: Option<&str>,
+) -> Result<SchedulerEventTiming, String> {
+    match (delay_value, start_time, end_time) {
+        (Some(value), None, None) => {
+            let unit = parse_time_unit(delay_unit.unwrap_or("minutes"))?;
+            let timing =
+                SchedulerEventTiming::parse_in_variant(value, unit).map_err(|e| e.to_string())?;
+            // parse_in_variant defers range checks to when the task is spawned
+            if let SchedulerEventTiming::In(payload) = &timing {
+                payload.to_duration().map_err(|e| e.to_string())?;
+            }
+            Ok(timing)
+        }
+        (None, Some(start), Some(end)) => {
+            let (start_hour, start_minute) = parse_clock_time(start)?;
+            let (end_hour, end_minute) = parse_clock_time(end)?;
+            SchedulerEventTiming::parse_between_variant_24h(
+                start_hour,
+                start_minute,
+                end_hour,
+                end_minute,
+            )
+            .map_err(|e| e.to_string())
+        }
+        _ => Err(
+            "Provide either delay_value (with optional delay_unit) or both start_time and end_time"
+                .to_string(),
+        ),
+    }
+}
+
+pub(super) fn apply_calendar(
+    timing: SchedulerEventTiming,
+    weekdays: Option<Vec<String>>,
+    start_date: Option<&str>,
+    end_date: Option<&str>,
+) -> Result<SchedulerEventTiming, String> {
+    let date_range = match (start_date, end_date) {
+        (None, None) => None,
+        (Some(start), Some(end)) => {
+            let (start_month, start_day) = parse_month_day(start)?;
+            let (end_month, end_day) = parse_month_day(end)?;
+            Some(ScheduleDateRange {
+                start_month,
+                start_day,
+                end_month,
+                end_day,
+            })
+        }
+        _ => return Err("Provide both start_date and end_date, or neither".to_string()),
+    };
+    let weekdays = weekdays
+        .unwrap_or_default()
+        .iter()
+        .map(|day| parse_day_of_week(day))
+        .collect::<Result<Vec<_>, _>>()?;
+    if weekdays.is_empty() && date_range.is_none() {
+        return Ok(timing);
+    }
+    timing
+        .with_calendar(weekdays, date_range)
+        .map_err(|e| e.to_string())
+}
+
+fn parse_day_of_week(day: &str) -> Result<DayOfWeek, String> {
+    DayOfWeek::parse(day)
+        .ok_or_else(|| format!("Invalid weekday: {day}. Use e.g. 'Monday' or 'Mon'"))
+}
+
+/// Parses a yearly `MM-DD` date into month and day.
+fn parse_month_day(date: &str) -> Result<(u32, u32), String> {
+    let invalid = || format!("Invalid date: {date}. Use MM-DD (e.g., 12-01)");
+    let (month, day) = date.trim().split_once('-').ok_or_else(invalid)?;
+    let month = month.parse().map_err(|_| invalid())?;
+    let day = day.parse().map_err(|_| invalid())?;
+    Ok((month, day))
+}
+
+/// Parses a 24-hour `HH:MM` clock time into hour and minute.
+pub(super) fn parse_clock_time(time: &str) -> Result<(i64, i64), String> {
+    let invalid = || format!("Invalid time: {time}. Use 24-hour HH:MM (e.g., 22:30)");
+    let (hour, minute) = time.trim().split_once(':').ok_or_else(invalid)?;
+    let hour: i64 = hour.parse().map_err(|_| invalid())?;
+    let minute: i64 = minute.parse().map_err(|_| invalid())?;
+    if !(0..=23).contains(&hour) || !(0..=59).contains(&minute) {
+        return Err(invalid());
+    }
+    Ok((hour, minute))
+}
+
+fn parse_time_unit(unit: &str) -> Result<TimeUnit, String> {
+    match unit.to_lowercase().as_str() {
+        "hours" => Ok(TimeUnit::Hours),
+        "minutes" => Ok(TimeUnit::Minutes),
+        "seconds" => Ok(TimeUnit::Seconds),
+        _ => Err(format!(
+            "Invalid delay unit: {unit}. Use 'hours', 'minutes' or 'seconds'"
+        )),
+    }
+}
+
 pub struct MiningWindowParams {
     pub event_id: String,
     pub mining_mode: String,
@@ -51,6 +381,25 @@ pub struct MiningWindowParams {
 }
 
 pub async fn schedule_mining_window(params: MiningWindowParams) -> Result<String, String> {
+    let (event_type, timing) =
+        mining_window_event(&params, ConfigMining::content().await.mining_modes().keys())?;
+
+    EventScheduler::instance()
+        .schedule_event(event_type, params.event_id.clone(), timing)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    EventsEmitter::emit_core_config_loaded(&ConfigCore::content().await).await;
+
+    Ok(serde_json::json!({"status": "scheduled", "event_id": params.event_id}).to_string())
+}
+
+/// Builds the Mine event and its window, rejecting mining modes that are not configured.
+pub(super) fn mining_window_event<'a>(
+    params: &MiningWindowParams,
+    known_modes: impl IntoIterator<Item = &'a String>,
+) -> Result<(SchedulerEventType, SchedulerEventTiming), String> {
+    check_mining_mode(&params.mining_mode, known_modes)?;
     let start_period = parse_time_period(&params.start_period)?;
     let end_period = parse_time_period(&params.end_period)?;
 
@@ -65,17 +414,9 @@ pub async fn schedule_mining_window(params: MiningWindowParams) -> Result<String
     .map_err(|e| e.to_string())?;
 
     let event_type = SchedulerEventType::Mine {
-        mining_mode: params.mining_mode,
+        mining_mode: params.mining_mode.clone(),
     };
-
-    EventScheduler::instance()
-        .schedule_event(event_type, params.event_id.clone(), timing)
-        .await
-        .map_err(|e| e.to_string())?;
-
-    EventsEmitter::emit_core_config_loaded(&ConfigCore::content().await).await;
-
-    Ok(serde_json::json!({"status": "scheduled", "event_id": params.event_id}).to_string())
+    Ok((event_type, timing))
 }
 
 fn parse_time_period(period: &str) -> Result<TimePeriod, String> {
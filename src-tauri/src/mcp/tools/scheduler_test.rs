@@ -0,0 +1,238 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+use chrono::{Local, TimeZone};
+
+use crate::event_scheduler::{
+    DayOfWeek, EventActivations, SchedulerEventTiming, SchedulerEventType,
+};
+
+use crate::node::node_manager::NodeType;
+
+use super::scheduler::{
+    EventActionArgs, MiningWindowParams, activations_summary, apply_calendar, check_mining_mode,
+    mining_window_event, parse_clock_time, parse_event_type, parse_timing,
+};
+
+#[test]
+fn parse_clock_time_accepts_24_hour_times() {
+    assert_eq!(parse_clock_time("00:00"), Ok((0, 0)));
+    assert_eq!(parse_clock_time("9:05"), Ok((9, 5)));
+    assert_eq!(parse_clock_time(" 23:59 "), Ok((23, 59)));
+}
+
+#[test]
+fn parse_clock_time_rejects_invalid_times() {
+    for time in ["24:00", "12:60", "10", "10:xx", "10pm", ""] {
+        assert!(
+            parse_clock_time(time).is_err(),
+            "Expected {time:?} to be rejected"
+        );
+    }
+}
+
+#[test]
+fn parse_event_type_requires_mining_mode_for_mine() {
+    let mining_mode = |mode: &str| EventActionArgs {
+        mining_mode: Some(mode.to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_event_type("resume_mining", EventActionArgs::default()),
+        Ok(SchedulerEventType::ResumeMining)
+    );
+    assert_eq!(
+        parse_event_type("Mine", mining_mode("Eco")),
+        Ok(SchedulerEventType::Mine {
+            mining_mode: "Eco".to_string()
+        })
+    );
+    assert!(parse_event_type("mine", EventActionArgs::default()).is_err());
+    assert!(parse_event_type("mine", mining_mode(" ")).is_err());
+    assert!(parse_event_type("stop_mining", EventActionArgs::default()).is_err());
+}
+
+#[test]
+fn check_mining_mode_rejects_unknown_modes() {
+    let modes = [
+        "Eco".to_string(),
+        "Ludicrous".to_string(),
+        "Custom".to_string(),
+    ];
+
+    assert_eq!(check_mining_mode("Ludicrous", &modes), Ok(()));
+    assert_eq!(
+        check_mining_mode("ludicrous", &modes),
+        Err("Unknown mining mode: ludicrous. Available modes: Custom, Eco, Ludicrous".to_string())
+    );
+    assert!(check_mining_mode("Eco", &[]).is_err());
+}
+
+#[test]
+fn mining_window_event_rejects_unknown_modes() {
+    let modes = ["Eco".to_string(), "Turbo".to_string()];
+    let params = |mining_mode: &str| MiningWindowParams {
+        event_id: "night".to_string(),
+        mining_mode: mining_mode.to_string(),
+        start_hour: 10,
+        start_minute: None,
+        start_period: "PM".to_string(),
+        end_hour: 6,
+        end_minute: Some(30),
+        end_period: "AM".to_string(),
+    };
+
+    let (event_type, timing) = mining_window_event(&params("Turbo"), &modes).unwrap();
+    assert!(matches!(
+        event_type,
+        SchedulerEventType::Mine { mining_mode } if mining_mode == "Turbo"
+    ));
+    assert!(matches!(timing, SchedulerEventTiming::Between(_)));
+
+    assert_eq!(
+        mining_window_event(&params("Bogus"), &modes).unwrap_err(),
+        "Unknown mining mode: Bogus. Available modes: Eco, Turbo"
+    );
+}
+
+#[test]
+fn parse_event_type_builds_toggle_actions() {
+    let enabled = |enabled: bool| EventActionArgs {
+        enabled: Some(enabled),
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_event_type("gpu_mining", enabled(true)),
+        Ok(SchedulerEventType::GpuMining { enabled: true })
+    );
+    assert_eq!(
+        parse_event_type("cpu_mining", enabled(false)),
+        Ok(SchedulerEventType::CpuMining { enabled: false })
+    );
+    assert_eq!(
+        parse_event_type("gpu_pool_mining", enabled(false)),
+        Ok(SchedulerEventType::GpuPoolMining { enabled: false })
+    );
+    assert!(parse_event_type("cpu_mining", EventActionArgs::default()).is_err());
+}
+
+#[test]
+fn parse_event_type_validates_pool_and_node_type() {
+    let pool = |pool: &str| EventActionArgs {
+        pool: Some(pool.to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_event_type("switch_cpu_pool", pool("KryptexPoolRANDOMX")),
+        Ok(SchedulerEventType::SwitchCpuPool {
+            pool_name: "KryptexPoolRANDOMX".to_string()
+        })
+    );
+    assert!(parse_event_type("switch_cpu_pool", pool("NoSuchPool")).is_err());
+    assert!(parse_event_type("switch_gpu_pool", EventActionArgs::default()).is_err());
+
+    let node_type = EventActionArgs {
+        node_type: Some("Local".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(
+        parse_event_type("switch_node_type", node_type),
+        Ok(SchedulerEventType::SwitchNodeType {
+            node_type: NodeType::Local
+        })
+    );
+    assert!(parse_event_type("switch_node_type", EventActionArgs::default()).is_err());
+}
+
+#[test]
+fn parse_timing_builds_delay_or_window() {
+    let delay = parse_timing(Some(2), Some("hours"), None, None).unwrap();
+    assert!(matches!(delay, SchedulerEventTiming::In(_)));
+
+    let window = parse_timing(None, None, Some("22:00"), Some("06:30")).unwrap();
+    let SchedulerEventTiming::Between(payload) = window else {
+        panic!("Expected Between timing");
+    };
+    assert_eq!(
+        (payload.start_hour, payload.end_hour, payload.end_minute),
+        (10, 6, 30)
+    );
+}
+
+#[test]
+fn parse_timing_rejects_ambiguous_or_out_of_range_input() {
+    assert!(parse_timing(None, None, None, None).is_err());
+    assert!(parse_timing(Some(1), None, Some("22:00"), Some("06:00")).is_err());
+    assert!(parse_timing(None, None, Some("22:00"), None).is_err());
+    assert!(parse_timing(Some(25), Some("hours"), None, None).is_err());
+    assert!(parse_timing(Some(5), Some("days"), None, None).is_err());
+}
+
+#[test]
+fn apply_calendar_sets_weekdays_and_date_range() {
+    let window = parse_timing(None, None, Some("22:00"), Some("07:00")).unwrap();
+
+    let timing = apply_calendar(
+        window,
+        Some(vec!["Sat".to_string(), "sunday".to_string()]),
+        Some("12-01"),
+        Some("12-31"),
+    )
+    .unwrap();
+
+    let SchedulerEventTiming::Between(payload) = timing else {
+        panic!("Expected Between timing");
+    };
+    assert_eq!(
+        payload.weekdays,
+        vec![DayOfWeek::Saturday, DayOfWeek::Sunday]
+    );
+    let range = payload.date_range.expect("date range");
+    assert_eq!((range.start_month, range.end_day), (12, 31));
+}
+
+#[test]
+fn apply_calendar_rejects_invalid_input() {
+    let window = || parse_timing(None, None, Some("22:00"), Some("07:00")).unwrap();
+    let delay = parse_timing(Some(5), None, None, None).unwrap();
+
+    assert!(apply_calendar(window(), Some(vec!["Funday".to_string()]), None, None).is_err());
+    assert!(apply_calendar(window(), None, Some("12-01"), None).is_err());
+    assert!(apply_calendar(window(), None, Some("02-30"), Some("03-01")).is_err());
+    assert!(apply_calendar(delay, Some(vec!["Mon".to_string()]), None, None).is_err());
+}
+
+#[test]
+fn activations_summary_formats_times_as_rfc3339() {
+    let start = Local.with_ymd_and_hms(2025, 3, 10, 22, 0, 0).unwrap();
+    let end = Local.with_ymd_and_hms(2025, 3, 11, 6, 0, 0).unwrap();
+    let activations = EventActivations {
+        event_id: "night".to_string(),
+        starts: vec![start.timestamp()],
+        ends: vec![end.timestamp()],
+    };
+
+    let summary = activations_summary(&activations);
+
+    assert_eq!(summary["event_id"], "night");
+    assert_eq!(summary["starts"], serde_json::json!([start.to_rfc3339()]));
+    assert_eq!(summary["ends"], serde_json::json!([end.to_rfc3339()]));
+}
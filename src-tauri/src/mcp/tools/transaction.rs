@@ -20,19 +20,22 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use std::collections::HashMap;
 use std::fmt;
 use std::str::FromStr;
 use std::sync::LazyLock;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use crate::LOG_TARGET_APP_LOGIC;
-use crate::configs::config_mcp::ConfigMcp;
+use crate::configs::config_mcp::{ConfigMcp, ConfigMcpContent};
+use crate::configs::config_wallet::{ConfigWallet, ExternalTariAddressBookRecord};
 use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
 use crate::mcp::rate_limiter::TransactionRateLimiter;
 use crate::pin::PinManager;
 use crate::wallet::wallet_manager::WalletManager;
 use log::{info, warn};
+use tari_common_types::tari_address::TariAddress;
 use tari_transaction_components::tari_amount::{MicroMinotari, Minotari};
 
 const DIALOG_TIMEOUT_SECS: u64 = 120;
@@ -43,6 +46,8 @@ pub enum TransactionError {
     NoPinConfigured(String),
     InvalidAmount(String),
     RateLimited(String),
+    BudgetExceeded(String),
+    DestinationNotAllowed(String),
     Denied(String),
     Timeout(String),
     WalletError(String),
@@ -56,6 +61,8 @@ impl fmt::Display for TransactionError {
             | TransactionError::NoPinConfigured(msg)
             | TransactionError::InvalidAmount(msg)
             | TransactionError::RateLimited(msg)
+            | TransactionError::BudgetExceeded(msg)
+            | TransactionError::DestinationNotAllowed(msg)
             | TransactionError::Denied(msg)
             | TransactionError::Timeout(msg)
             | TransactionError::WalletError(msg)
@@ -107,6 +114,31 @@ fn validate_amount(
     Ok(amount_u64)
 }
 
+/// With the allowlist enabled, only addresses saved in the wallet's external address book
+/// can receive MCP transactions.
+fn check_destination_allowed(
+    destination: &str,
+    config: &ConfigMcpContent,
+    address_book: &HashMap<String, ExternalTariAddressBookRecord>,
+) -> Result<(), String> {
+    if !*config.destination_allowlist_enabled() {
+        return Ok(());
+    }
+    let address = TariAddress::from_str(destination)
+        .map_err(|e| format!("Invalid destination address '{destination}': {e}"))?;
+    let base58 = address.to_base58();
+    if address_book
+        .values()
+        .any(|record| record.address.to_base58() == base58)
+    {
+        Ok(())
+    } else {
+        Err(format!(
+            "Destination {base58} is not in the address book and the MCP destination allowlist is enabled"
+        ))
+    }
+}
+
 #[derive(serde::Serialize)]
 struct SendTransactionSuccess {
     status: &'static str,
@@ -140,6 +172,14 @@ pub async fn send_transaction(
     // 3. Parse and validate amount
     let amount_u64 = validate_amount(&amount, &config).map_err(TransactionError::InvalidAmount)?;
 
+    // 3a. Destination allowlist
+    let address_book = ConfigWallet::content()
+        .await
+        .external_tari_addresses_book()
+        .clone();
+    check_destination_allowed(&destination, &config, &address_book)
+        .map_err(TransactionError::DestinationNotAllowed)?;
+
     // 4. Acquire serialization gate (one dialog at a time)
     let _permit = TXN_DIALOG_GATE
         .acquire()
@@ -158,6 +198,12 @@ pub async fn send_transaction(
         ));
     }
 
+    // 5a. Rolling spend budget, checked under the gate so concurrent sends can't both pass
+    ConfigMcp::content()
+        .await
+        .check_spend_budget(amount_u64, SystemTime::now())
+        .map_err(TransactionError::BudgetExceeded)?;
+
     // 6. Generate request ID
     let request_id = format!("mcp_tx_{}", uuid::Uuid::new_v4());
 
@@ -203,6 +249,15 @@ pub async fn send_transaction(
 
     match tx_result {
         Ok(()) => {
+            let _unused = ConfigMcp::update_field(
+                |content, amount| content.record_spend(amount, SystemTime::now()),
+                amount_u64,
+            )
+            .await
+            .inspect_err(|e| {
+                warn!(target: LOG_TARGET_APP_LOGIC, "MCP: failed to record spend of {amount_u64} µT: {e}");
+            });
+
             EventsEmitter::emit_mcp_transaction_result(
                 crate::events::McpTransactionResultPayload {
                     request_id,
@@ -374,6 +429,56 @@ mod tests {
         assert!(result.is_err());
     }
 
+    // =========================================================================
+    // check_destination_allowed
+    // =========================================================================
+
+    fn address_book_with(address: &TariAddress) -> HashMap<String, ExternalTariAddressBookRecord> {
+        let mut book = HashMap::new();
+        book.insert(
+            "Exchange".to_string(),
+            ExternalTariAddressBookRecord {
+                name: "Exchange".to_string(),
+                address: address.clone(),
+            },
+        );
+        book
+    }
+
+    #[test]
+    fn destination_allowed_when_allowlist_disabled() {
+        let config = ConfigMcpContent::default();
+        let result = check_destination_allowed("not_an_address", &config, &HashMap::new());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn destination_in_address_book_is_allowed() {
+        let mut config = ConfigMcpContent::default();
+        config.set_destination_allowlist_enabled(true);
+        let address = TariAddress::default();
+        let book = address_book_with(&address);
+        let result = check_destination_allowed(&address.to_base58(), &config, &book);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn destination_missing_from_address_book_is_rejected() {
+        let mut config = ConfigMcpContent::default();
+        config.set_destination_allowlist_enabled(true);
+        let address = TariAddress::default();
+        let result = check_destination_allowed(&address.to_base58(), &config, &HashMap::new());
+        assert!(result.unwrap_err().contains("not in the address book"));
+    }
+
+    #[test]
+    fn unparseable_destination_is_rejected_when_allowlist_enabled() {
+        let mut config = ConfigMcpContent::default();
+        config.set_destination_allowlist_enabled(true);
+        let result = check_destination_allowed("not_an_address", &config, &HashMap::new());
+        assert!(result.unwrap_err().contains("Invalid destination"));
+    }
+
     // =========================================================================
     // respond_to_transaction
     // =========================================================================
@@ -22,6 +22,7 @@
 
 use crate::internal_wallet::InternalWallet;
 use crate::wallet::wallet_manager::WalletManager;
+use crate::wallet::wallet_types::WalletBalance;
 
 pub async fn get_wallet_address() -> Result<String, String> {
     let address = InternalWallet::tari_address().await;
@@ -43,13 +44,16 @@ pub async fn get_wallet_balance(wallet_manager: &WalletManager) -> Result<String
         .await
         .map_err(|e| format!("Failed to get wallet balance: {e}"))?;
 
-    let result = serde_json::json!({
+    serde_json::to_string(&wallet_balance_json(&balance)).map_err(|e| e.to_string())
+}
+
+pub fn wallet_balance_json(balance: &WalletBalance) -> serde_json::Value {
+    serde_json::json!({
         "available_balance": balance.available_balance.as_u64(),
         "timelocked_balance": balance.timelocked_balance.as_u64(),
         "pending_incoming_balance": balance.pending_incoming_balance.as_u64(),
         "pending_outgoing_balance": balance.pending_outgoing_balance.as_u64(),
-    });
-    serde_json::to_string(&result).map_err(|e| e.to_string())
+    })
 }
 
 pub async fn get_transaction_history(
@@ -0,0 +1,169 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    configs::config_mining::{MiningMode, MiningModeType},
+    event_scheduler::EventScheduler,
+};
+
+/// Time given to the miner to settle after the thread count changed
+pub const BENCHMARK_WARMUP: Duration = Duration::from_secs(20);
+/// Window over which the hash rate of a thread count is averaged
+pub const BENCHMARK_SAMPLE_WINDOW: Duration = Duration::from_secs(30);
+/// Upper bound of thread counts measured, keeps the benchmark within a few minutes on large CPUs
+const MAX_BENCHMARK_STEPS: u32 = 8;
+/// Share of the peak hash rate used for modes at or below 1% cpu usage, matches the light mode fallback
+const LOW_USAGE_PEAK_SHARE: u32 = 10;
+
+static IS_BENCHMARK_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Whether a cpu thread benchmark is changing the thread count right now
+pub fn is_cpu_benchmark_running() -> bool {
+    IS_BENCHMARK_RUNNING.load(Ordering::SeqCst)
+}
+
+/// Held for the length of a cpu thread benchmark. Dropping it clears the running flag, also when
+/// the benchmark fails or panics, and lets the scheduler run the callbacks it held back meanwhile
+pub struct CpuBenchmarkGuard {
+    _private: (),
+}
+
+impl CpuBenchmarkGuard {
+    /// Returns `None` when another benchmark is already running
+    pub fn acquire() -> Option<Self> {
+        if IS_BENCHMARK_RUNNING.swap(true, Ordering::SeqCst) {
+            return None;
+        }
+        Some(Self { _private: () })
+    }
+}
+
+impl Drop for CpuBenchmarkGuard {
+    fn drop(&mut self) {
+        IS_BENCHMARK_RUNNING.store(false, Ordering::SeqCst);
+        EventScheduler::instance().catch_up_after_benchmark();
+    }
+}
+
+/// Hash rate of the cpu miner at several thread counts on this machine
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CpuThreadBenchmark {
+    /// Threads available when the benchmark ran, results are ignored once this changes
+    pub available_threads: u32,
+    /// Hash rate in H/s per benchmarked thread count
+    pub hash_rates: BTreeMap<u32, f64>,
+    /// Thread count used by each Eco, Turbo and Ludicrous mode, keyed by mode name
+    pub mode_threads: HashMap<String, u32>,
+    pub benchmarked_at: i64,
+}
+
+impl CpuThreadBenchmark {
+    /// Builds the thread map for the predefined mining modes out of the measured hash rates.
+    /// Ludicrous uses the thread count with the peak hash rate, other modes use the fewest threads
+    /// that reach their cpu usage percentage of the peak hash rate.
+    pub fn new(
+        available_threads: u32,
+        hash_rates: BTreeMap<u32, f64>,
+        mining_modes: &HashMap<String, MiningMode>,
+    ) -> Self {
+        let mut benchmark = Self {
+            available_threads,
+            hash_rates,
+            mode_threads: HashMap::new(),
+            benchmarked_at: Utc::now().timestamp(),
+        };
+        benchmark.mode_threads = mining_modes
+            .iter()
+            .filter_map(|(mode_name, mode)| {
+                let threads = match mode.mode_type {
+                    MiningModeType::Ludicrous => benchmark.peak_threads(),
+                    MiningModeType::Eco | MiningModeType::Turbo => benchmark
+                        .threads_for_peak_share(if mode.cpu_usage_percentage <= 1 {
+                            LOW_USAGE_PEAK_SHARE
+                        } else {
+                            mode.cpu_usage_percentage
+                        }),
+                    MiningModeType::Custom | MiningModeType::User => None,
+                }?;
+                Some((mode_name.clone(), threads))
+            })
+            .collect();
+        benchmark
+    }
+
+    /// Thread count with the highest hash rate, the lowest one wins a tie
+    pub fn peak_threads(&self) -> Option<u32> {
+        self.hash_rates
+            .iter()
+            .fold(
+                None,
+                |peak: Option<(u32, f64)>, (&threads, &hash_rate)| match peak {
+                    Some((_, peak_hash_rate)) if peak_hash_rate >= hash_rate => peak,
+                    _ => Some((threads, hash_rate)),
+                },
+            )
+            .map(|(threads, _)| threads)
+    }
+
+    /// Fewest threads reaching `share_percentage` of the peak hash rate
+    pub fn threads_for_peak_share(&self, share_percentage: u32) -> Option<u32> {
+        let peak_hash_rate = self.hash_rates.values().copied().fold(0.0, f64::max);
+        if peak_hash_rate <= 0.0 {
+            return None;
+        }
+        let target = peak_hash_rate * f64::from(share_percentage.min(100)) / 100.0;
+        self.hash_rates
+            .iter()
+            .find(|(_, hash_rate)| **hash_rate >= target)
+            .map(|(threads, _)| *threads)
+    }
+}
+
+/// Thread counts to measure, evenly spread between one thread and all available threads
+pub fn benchmark_thread_counts(available_threads: u32) -> Vec<u32> {
+    let available_threads = available_threads.max(1);
+    let step = available_threads.div_ceil(MAX_BENCHMARK_STEPS);
+    let mut thread_counts: Vec<u32> = std::iter::once(1)
+        .chain((step..=available_threads).step_by(step as usize))
+        .chain(std::iter::once(available_threads))
+        .collect();
+    thread_counts.dedup();
+    thread_counts
+}
+
+/// Average of the non zero hash rate samples, zero samples are taken while the miner restarts workers
+pub fn average_hash_rate(samples: &[f64]) -> Option<f64> {
+    let valid: Vec<f64> = samples.iter().copied().filter(|rate| *rate > 0.0).collect();
+    if valid.is_empty() {
+        return None;
+    }
+    Some(valid.iter().sum::<f64>() / valid.len() as f64)
+}
@@ -0,0 +1,107 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::configs::config_mining::{MiningMode, MiningModeType};
+
+use super::benchmark::{
+    CpuBenchmarkGuard, CpuThreadBenchmark, average_hash_rate, benchmark_thread_counts,
+    is_cpu_benchmark_running,
+};
+
+fn mining_mode(mode_type: MiningModeType, cpu_usage_percentage: u32) -> (String, MiningMode) {
+    (
+        mode_type.to_string(),
+        MiningMode {
+            mode_type,
+            mode_name: mode_type.to_string(),
+            cpu_usage_percentage,
+            gpu_usage_percentage: cpu_usage_percentage,
+        },
+    )
+}
+
+/// Hash rate peaking at 6 of 8 threads, as seen on L3 cache bound CPUs
+fn cache_bound_hash_rates() -> BTreeMap<u32, f64> {
+    BTreeMap::from([
+        (1, 500.0),
+        (2, 1000.0),
+        (4, 1900.0),
+        (6, 2400.0),
+        (8, 2100.0),
+    ])
+}
+
+#[test]
+fn thread_counts_cover_one_to_all_threads() {
+    assert_eq!(benchmark_thread_counts(1), vec![1]);
+    assert_eq!(benchmark_thread_counts(4), vec![1, 2, 3, 4]);
+    assert_eq!(
+        benchmark_thread_counts(16),
+        vec![1, 2, 4, 6, 8, 10, 12, 14, 16]
+    );
+    assert_eq!(benchmark_thread_counts(12), vec![1, 2, 4, 6, 8, 10, 12]);
+}
+
+#[test]
+fn thread_map_uses_peak_for_ludicrous_and_peak_share_for_other_modes() {
+    let mining_modes = HashMap::from([
+        mining_mode(MiningModeType::Eco, 1),
+        mining_mode(MiningModeType::Turbo, 50),
+        mining_mode(MiningModeType::Ludicrous, 85),
+        mining_mode(MiningModeType::Custom, 75),
+    ]);
+
+    let benchmark = CpuThreadBenchmark::new(8, cache_bound_hash_rates(), &mining_modes);
+
+    assert_eq!(benchmark.mode_threads.get("Eco"), Some(&1));
+    assert_eq!(benchmark.mode_threads.get("Turbo"), Some(&4));
+    assert_eq!(benchmark.mode_threads.get("Ludicrous"), Some(&6));
+    assert_eq!(benchmark.mode_threads.get("Custom"), None);
+}
+
+#[test]
+fn peak_share_is_none_without_hash_rate() {
+    let benchmark = CpuThreadBenchmark::new(8, BTreeMap::from([(1, 0.0)]), &HashMap::new());
+
+    assert_eq!(benchmark.threads_for_peak_share(50), None);
+    assert_eq!(benchmark.peak_threads(), Some(1));
+}
+
+#[test]
+fn average_ignores_zero_samples() {
+    assert_eq!(average_hash_rate(&[0.0, 100.0, 200.0]), Some(150.0));
+    assert_eq!(average_hash_rate(&[0.0, 0.0]), None);
+    assert_eq!(average_hash_rate(&[]), None);
+}
+
+#[test]
+fn benchmark_guard_is_exclusive_and_clears_the_flag_on_drop() {
+    let guard = CpuBenchmarkGuard::acquire().unwrap();
+    assert!(is_cpu_benchmark_running());
+    assert!(CpuBenchmarkGuard::acquire().is_none());
+
+    drop(guard);
+    assert!(!is_cpu_benchmark_running());
+    assert!(CpuBenchmarkGuard::acquire().is_some());
+}
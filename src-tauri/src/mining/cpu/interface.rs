@@ -0,0 +1,149 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::Duration;
+
+use axum::async_trait;
+
+use crate::{
+    mining::{
+        CpuConnectionType,
+        cpu::{
+            RandomXMode,
+            miners::xmrig::{XmrigAdapter, XmrigStatusMonitor},
+        },
+    },
+    process_adapter::{
+        HandleUnhealthyResult, HealthStatus, ProcessAdapter, ProcessInstance, StatusMonitor,
+    },
+};
+
+pub trait CpuMinerInterfaceTrait: Send + Sync {
+    async fn load_address(&mut self, address: &str) -> Result<(), anyhow::Error>;
+    async fn load_connection_type(
+        &mut self,
+        connection_type: CpuConnectionType,
+    ) -> Result<(), anyhow::Error>;
+    async fn load_cpu_threads(&mut self, cpu_threads: Option<u32>) -> Result<(), anyhow::Error>;
+    async fn load_randomx_mode(&mut self, randomx_mode: RandomXMode) -> Result<(), anyhow::Error>;
+    /// Pushes the loaded settings to the running miner process.
+    /// Returns false if the miner cannot change them without a restart.
+    async fn update_running_config(&mut self) -> Result<bool, anyhow::Error> {
+        Ok(false)
+    }
+}
+
+pub enum CpuMinerInterface {
+    Xmrig(XmrigAdapter),
+}
+
+impl CpuMinerInterfaceTrait for CpuMinerInterface {
+    async fn load_address(&mut self, address: &str) -> Result<(), anyhow::Error> {
+        match self {
+            CpuMinerInterface::Xmrig(miner) => miner.load_address(address).await,
+        }
+    }
+    async fn load_connection_type(
+        &mut self,
+        connection_type: CpuConnectionType,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            CpuMinerInterface::Xmrig(miner) => miner.load_connection_type(connection_type).await,
+        }
+    }
+    async fn load_cpu_threads(&mut self, cpu_threads: Option<u32>) -> Result<(), anyhow::Error> {
+        match self {
+            CpuMinerInterface::Xmrig(miner) => miner.load_cpu_threads(cpu_threads).await,
+        }
+    }
+    async fn load_randomx_mode(&mut self, randomx_mode: RandomXMode) -> Result<(), anyhow::Error> {
+        match self {
+            CpuMinerInterface::Xmrig(miner) => miner.load_randomx_mode(randomx_mode).await,
+        }
+    }
+    async fn update_running_config(&mut self) -> Result<bool, anyhow::Error> {
+        match self {
+            CpuMinerInterface::Xmrig(miner) => miner.update_running_config().await,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub enum CpuMinerStatusInterface {
+    Xmrig(XmrigStatusMonitor),
+}
+
+#[async_trait]
+impl StatusMonitor for CpuMinerStatusInterface {
+    async fn handle_unhealthy(
+        &self,
+        duration_since_last_healthy_status: Duration,
+    ) -> Result<HandleUnhealthyResult, anyhow::Error> {
+        match self {
+            CpuMinerStatusInterface::Xmrig(monitor) => {
+                monitor
+                    .handle_unhealthy(duration_since_last_healthy_status)
+                    .await
+            }
+        }
+    }
+    async fn check_health(&self, uptime: Duration, timeout_duration: Duration) -> HealthStatus {
+        match self {
+            CpuMinerStatusInterface::Xmrig(monitor) => {
+                monitor.check_health(uptime, timeout_duration).await
+            }
+        }
+    }
+}
+
+impl ProcessAdapter for CpuMinerInterface {
+    type ProcessInstance = ProcessInstance;
+    type StatusMonitor = CpuMinerStatusInterface;
+    fn spawn_inner(
+        &self,
+        base_folder: std::path::PathBuf,
+        config_folder: std::path::PathBuf,
+        log_folder: std::path::PathBuf,
+        binary_version_path: std::path::PathBuf,
+        is_first_start: bool,
+    ) -> Result<(Self::ProcessInstance, Self::StatusMonitor), anyhow::Error> {
+        match self {
+            CpuMinerInterface::Xmrig(miner) => miner.spawn_inner(
+                base_folder,
+                config_folder,
+                log_folder,
+                binary_version_path,
+                is_first_start,
+            ),
+        }
+    }
+    fn name(&self) -> &str {
+        match self {
+            CpuMinerInterface::Xmrig(miner) => miner.name(),
+        }
+    }
+    fn pid_file_name(&self) -> &str {
+        match self {
+            CpuMinerInterface::Xmrig(miner) => miner.pid_file_name(),
+        }
+    }
+}
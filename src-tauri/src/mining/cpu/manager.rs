@@ -20,35 +20,57 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{sync::LazyLock, thread};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::LazyLock,
+    thread,
+};
 
-use log::{error, info};
+use log::{error, info, warn};
 use tari_shutdown::Shutdown;
 use tauri::{AppHandle, Manager};
 use tauri_plugin_sentry::sentry;
 use tokio::{
-    select,
+    select, spawn,
     sync::{
         RwLock,
         watch::{Receiver, Sender},
     },
+    time::{Instant, interval_at, sleep, timeout_at},
 };
 
 use crate::{
     LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES, UniverseAppState,
     configs::{
-        config_mining::ConfigMining,
-        config_pools::ConfigPools,
+        config_mining::{ConfigMining, ConfigMiningContent},
+        config_pools::{ConfigPools, ConfigPoolsContent},
         config_wallet::ConfigWallet,
-        pools::{PoolOrigin, cpu_pools::CpuPool},
+        pools::cpu_pools::CpuPool,
         trait_config::ConfigImpl,
     },
     events_emitter::EventsEmitter,
     internal_wallet::InternalWallet,
     mining::{
         CpuConnectionType, MinerControlsState, MiningError,
-        cpu::{CpuMinerStatus, miners::xmrig::XmrigAdapter},
-        pools::{PoolManagerInterfaceTrait, cpu_pool_manager::CpuPoolManager},
+        cpu::{
+            CpuMinerStatus, RandomXMode,
+            benchmark::{
+                BENCHMARK_SAMPLE_WINDOW, BENCHMARK_WARMUP, CpuBenchmarkGuard, CpuThreadBenchmark,
+                average_hash_rate, benchmark_thread_counts,
+            },
+            consts::{CPU_MINERS_PRIORITY, CpuMiner, CpuMinerType},
+            interface::{CpuMinerInterface, CpuMinerInterfaceTrait},
+            miners::xmrig::XmrigAdapter,
+        },
+        pools::{
+            PoolManagerInterfaceTrait,
+            cpu_pool_manager::CpuPoolManager,
+            failover::{
+                PoolHealthTracker, PoolKind, PoolSwitchReason, PoolSwitchRecord, failover_order,
+                first_reachable, higher_priority, next_in_priority, probe_pool,
+            },
+        },
+        thermal::apply_throttle,
     },
     node::node_adapter::BaseNodeStatus,
     process_adapter::ProcessAdapter,
@@ -61,8 +83,11 @@ static INSTANCE: LazyLock<RwLock<CpuManager>> = LazyLock::new(|| RwLock::new(Cpu
 
 pub struct CpuManager {
     app_handle: Option<AppHandle>,
+    // ======= Miner config =======
+    selected_miner: CpuMinerType,
+    available_miners: HashMap<CpuMinerType, CpuMiner>,
     // ======= Process watcher =======
-    process_watcher: ProcessWatcher<XmrigAdapter>,
+    process_watcher: ProcessWatcher<CpuMinerInterface>,
     // ======= Parameters tracking =======
     status_thread_shutdown: Shutdown,
     process_stats_collector: Sender<ProcessWatcherStats>,
@@ -77,28 +102,37 @@ pub struct CpuManager {
     pool: Option<CpuPool>,
     #[allow(dead_code)]
     intensity_percentage: Option<u32>,
+    // ======= Thermal control =======
+    /// Share of the selected mode threads the miner runs with, lowered while the cpu is too hot
+    thermal_throttle_percentage: u32,
 }
 
 impl CpuManager {
     pub fn new() -> Self {
+        let cpu_internal_status_channel = Sender::new(CpuMinerStatus::default());
         Self {
             app_handle: None,
+            // ======= Miner config =======
+            selected_miner: CpuMinerType::Xmrig,
+            available_miners: HashMap::new(),
             // ======= Process watcher =======
             process_watcher: ProcessWatcher::new(
-                XmrigAdapter::new(Sender::new(CpuMinerStatus::default())),
+                CpuMinerInterface::Xmrig(XmrigAdapter::new(cpu_internal_status_channel.clone())),
                 Sender::new(ProcessWatcherStats::default()),
             ),
             // ======= Parameters tracking =======
             status_thread_shutdown: Shutdown::new(),
             process_stats_collector: Sender::new(ProcessWatcherStats::default()),
             cpu_external_status_channel: Sender::new(CpuMinerStatus::default()),
-            cpu_internal_status_channel: Sender::new(CpuMinerStatus::default()),
+            cpu_internal_status_channel,
             node_status_channel: None,
             // ======= Cached config =======
             connection_type: CpuConnectionType::default(),
             tari_address: None,
             pool: None,
             intensity_percentage: None,
+            // ======= Thermal control =======
+            thermal_throttle_percentage: 100,
         }
     }
 
@@ -125,12 +159,220 @@ impl CpuManager {
         instance.process_stats_collector = process_stats_collector;
         instance.cpu_external_status_channel = status_channel;
         instance.node_status_channel = node_status_channel;
+    }
+
+    /// Selects the miner saved in the config
+    /// Falls back to the healthy miner with the highest priority if the saved one is not available
+    pub async fn load_saved_miner(&mut self) -> Result<(), anyhow::Error> {
+        EventsEmitter::emit_available_cpu_miners(self.available_miners.clone()).await;
+        let saved_miner = ConfigMining::content().await.selected_cpu_miner().clone();
+
+        let is_saved_miner_healthy = self
+            .available_miners
+            .get(&saved_miner)
+            .is_some_and(|m| m.is_healthy);
+
+        let selected_cpu_miner_type = if is_saved_miner_healthy {
+            saved_miner
+        } else {
+            CPU_MINERS_PRIORITY
+                .iter()
+                .find(|miner_type| {
+                    self.available_miners
+                        .get(miner_type)
+                        .is_some_and(|m| m.is_healthy)
+                })
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("No available cpu miners to load"))?
+        };
+
+        info!(target: LOG_TARGET_APP_LOGIC, "Loaded cpu miner: {selected_cpu_miner_type}");
+        self.switch_miner(selected_cpu_miner_type).await?;
+
+        Ok(())
+    }
+
+    pub async fn load_miner(
+        &mut self,
+        miner: CpuMinerType,
+        is_healthy: bool,
+        last_error: Option<String>,
+    ) {
+        let miner = CpuMiner::new(miner, is_healthy, last_error);
+        self.available_miners
+            .insert(miner.miner_type.clone(), miner);
+    }
+
+    /// Replaces the miner used by the process watcher
+    /// Mining is restarted with the new miner if it was running before the switch
+    pub async fn switch_miner(&mut self, new_miner: CpuMinerType) -> Result<(), anyhow::Error> {
+        info!(target: LOG_TARGET_APP_LOGIC, "Switching cpu miner to: {new_miner}");
+        if !self.available_miners.contains_key(&new_miner) {
+            return Err(anyhow::anyhow!("Selected cpu miner is not available"));
+        }
+
+        let was_running = self.is_running();
+        if was_running {
+            self.stop_mining().await?;
+        }
+
+        self.selected_miner = new_miner.clone();
+        self.process_watcher.adapter = self.resolve_miner_interface(&new_miner);
+        EventsEmitter::emit_update_selected_cpu_miner(new_miner.clone()).await;
+
+        if was_running {
+            self.start_mining().await?;
+        }
+        info!(target: LOG_TARGET_APP_LOGIC, "Switched cpu miner to: {new_miner}");
+        Ok(())
+    }
+
+    /// Switches the miner and persists the choice so it is used on the next start
+    pub async fn select_miner(&mut self, new_miner: CpuMinerType) -> Result<(), anyhow::Error> {
+        self.switch_miner(new_miner.clone()).await?;
+        ConfigMining::update_field(ConfigMiningContent::set_selected_cpu_miner, new_miner).await?;
+        Ok(())
+    }
+
+    fn resolve_miner_interface(&self, miner_type: &CpuMinerType) -> CpuMinerInterface {
+        match miner_type {
+            CpuMinerType::Xmrig => CpuMinerInterface::Xmrig(XmrigAdapter::new(
+                self.cpu_internal_status_channel.clone(),
+            )),
+        }
+    }
+
+    /// Switches mining to another pool from the priority list
+    /// Mining is restarted on the new pool if it was running before the switch
+    pub async fn switch_pool(
+        &mut self,
+        pool: CpuPool,
+        reason: PoolSwitchReason,
+    ) -> Result<(), anyhow::Error> {
+        if Self::select_pool(pool, reason).await? && self.is_running() {
+            self.stop_mining().await?;
+            self.start_mining().await?;
+        }
+        Ok(())
+    }
+
+    /// Makes `pool` the current cpu pool and records the switch, returns false if it already was
+    async fn select_pool(pool: CpuPool, reason: PoolSwitchReason) -> Result<bool, anyhow::Error> {
+        let previous_pool = ConfigPools::content().await.current_cpu_pool_type().clone();
+        if previous_pool == pool {
+            return Ok(false);
+        }
+        info!(target: LOG_TARGET_APP_LOGIC, "Switching cpu pool from {previous_pool} to {pool}: {reason:?}");
+
+        ConfigPools::update_field(ConfigPoolsContent::set_current_cpu_pool, pool.clone()).await?;
+        let pools_config = ConfigPools::content().await;
+        EventsEmitter::emit_pools_config_loaded(&pools_config).await;
+
+        let record = PoolSwitchRecord::new(
+            PoolKind::Cpu,
+            previous_pool.key_string(),
+            pool.key_string(),
+            reason,
+        );
+        CpuPoolManager::handle_pool_switch(record.clone(), pools_config.current_cpu_pool()).await;
+        EventsEmitter::emit_pool_switched(record).await;
+        Ok(true)
+    }
 
-        instance.process_watcher.adapter.summary_broadcast =
-            instance.cpu_internal_status_channel.clone();
+    /// Pairs each pool with its url, pools missing from the config are skipped
+    fn pool_candidates(
+        pools_config: &ConfigPoolsContent,
+        pools: Vec<CpuPool>,
+    ) -> Vec<(CpuPool, String)> {
+        pools
+            .into_iter()
+            .filter_map(|pool| {
+                let pool_url = pools_config.cpu_pools().get(&pool)?.pool_url.clone();
+                Some((pool, pool_url))
+            })
+            .collect()
     }
+
+    /// Probes the current pool before the miner is started and moves to the first reachable pool
+    /// in failover order when it does not respond, the miner is started on the current pool if none respond
+    async fn ensure_reachable_pool(login: &str) {
+        let pools_config = ConfigPools::content().await;
+        let failover = pools_config.pool_failover();
+        if !failover.probe_before_start
+            || probe_pool(
+                &pools_config.current_cpu_pool().pool_url,
+                login,
+                PoolKind::Cpu,
+            )
+            .await
+            || !failover.enabled
+        {
+            return;
+        }
+
+        let candidates = Self::pool_candidates(
+            &pools_config,
+            failover_order(
+                pools_config.cpu_pool_priority(),
+                pools_config.current_cpu_pool_type(),
+            ),
+        );
+        match first_reachable(candidates, login, PoolKind::Cpu).await {
+            Some(pool) => {
+                if let Err(e) = Self::select_pool(pool, PoolSwitchReason::Failover).await {
+                    error!(target: LOG_TARGET_APP_LOGIC, "Failed to select reachable cpu pool: {e}");
+                }
+            }
+            None => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "No cpu pool responded, starting on the current one");
+            }
+        }
+    }
+
+    /// Moves to the next pool in the priority list after the current one stopped working
+    async fn fail_over_pool() {
+        let pools_config = ConfigPools::content().await;
+        let Some(next_pool) = next_in_priority(
+            pools_config.cpu_pool_priority(),
+            pools_config.current_cpu_pool_type(),
+        ) else {
+            warn!(target: LOG_TARGET_APP_LOGIC, "No other cpu pool to fail over to");
+            return;
+        };
+
+        if let Err(e) = Self::write()
+            .await
+            .switch_pool(next_pool, PoolSwitchReason::Failover)
+            .await
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to fail over cpu pool: {e}");
+        }
+    }
+
+    /// Returns to a higher priority pool once it is reachable again
+    async fn fail_back_pool() {
+        let pools_config = ConfigPools::content().await;
+        let candidates = Self::pool_candidates(
+            &pools_config,
+            higher_priority(
+                pools_config.cpu_pool_priority(),
+                pools_config.current_cpu_pool_type(),
+            ),
+        );
+        let login = InternalWallet::tari_address().await.to_base58();
+
+        if let Some(pool) = first_reachable(candidates, &login, PoolKind::Cpu).await
+            && let Err(e) = Self::write()
+                .await
+                .switch_pool(pool, PoolSwitchReason::Failback)
+                .await
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to fail back cpu pool: {e}");
+        }
+    }
+
     pub async fn start_mining(&mut self) -> Result<(), anyhow::Error> {
-        info!(target: LOG_TARGET_APP_LOGIC, "Starting cpu miner");
+        info!(target: LOG_TARGET_APP_LOGIC, "Starting cpu miner: {}", self.selected_miner);
         match self.start_mining_inner().await {
             Ok(_) => {
                 info!(target: LOG_TARGET_APP_LOGIC, "Started cpu miner");
@@ -196,58 +438,40 @@ impl CpuManager {
             let mmproxy_manager = &app_state.mm_proxy_manager;
 
             if *ConfigPools::content().await.cpu_pool_enabled() {
-                let pool_url = ConfigPools::content()
-                    .await
-                    .current_cpu_pool()
-                    .pool_url
-                    .clone();
                 let tari_address = InternalWallet::tari_address().await;
-
-                // Worker name format depends on the pool
-                // LuckyPool: .Tari-Universe
-                // Kryptex: /Tari-Universe
-                // SupportXTM: Not specified so we use None
-                let worker_name = match ConfigPools::content().await.current_cpu_pool().pool_origin
-                {
-                    PoolOrigin::LuckyPool => Some(".Tari-universe"),
-                    PoolOrigin::SupportXTM => None,
-                    PoolOrigin::Kryptex => Some("/Tari-universe"),
-                };
-
-                self.process_watcher.adapter.connection_type = CpuConnectionType::Pool {
-                    pool_url,
-                    worker_name: worker_name.map(|s| s.to_string()),
-                };
-                self.process_watcher.adapter.address = tari_address.to_base58();
+                Self::ensure_reachable_pool(&tari_address.to_base58()).await;
+                let current_pool = ConfigPools::content().await.current_cpu_pool();
+
+                self.process_watcher
+                    .adapter
+                    .load_connection_type(CpuConnectionType::Pool {
+                        worker_name: current_pool.worker_name(),
+                        pool_url: current_pool.pool_url,
+                    })
+                    .await?;
+                self.process_watcher
+                    .adapter
+                    .load_address(&tari_address.to_base58())
+                    .await?;
             } else {
                 let host_name = "127.0.0.1".to_string();
                 let mmproxy_port = mmproxy_manager.get_monero_port().await?;
                 let local_proxy_url = format!("{host_name}:{mmproxy_port}");
                 let monero_address = ConfigWallet::content().await.monero_address().clone();
 
-                self.process_watcher.adapter.connection_type =
-                    CpuConnectionType::LocalMMProxy { local_proxy_url };
-                self.process_watcher.adapter.address = monero_address.to_string();
+                self.process_watcher
+                    .adapter
+                    .load_connection_type(CpuConnectionType::LocalMMProxy { local_proxy_url })
+                    .await?;
+                self.process_watcher
+                    .adapter
+                    .load_address(&monero_address.to_string())
+                    .await?;
             }
 
-            let binary = crate::binaries::Binaries::Xmrig;
+            let binary = self.selected_miner.binary();
 
-            let cpu_usage_percentage = ConfigMining::content()
-                .await
-                .get_selected_cpu_usage_percentage();
-
-            if cpu_usage_percentage <= 1 {
-                self.process_watcher.adapter.cpu_threads =
-                    Some(Self::determine_number_of_cores_to_use(10).await);
-
-                self.process_watcher.adapter.extra_options =
-                    vec!["--randomx-mode=light".to_string()]
-            } else {
-                self.process_watcher.adapter.cpu_threads =
-                    Some(Self::determine_number_of_cores_to_use(cpu_usage_percentage).await);
-
-                self.process_watcher.adapter.extra_options = vec!["--randomx-mode=fast".to_string()]
-            }
+            self.load_mining_mode_settings().await?;
 
             self.process_watcher
                 .start(
@@ -274,9 +498,80 @@ impl CpuManager {
         self.process_watcher.is_running()
     }
 
-    async fn determine_number_of_cores_to_use(cpu_usage_percentage: u32) -> u32 {
-        let max_cpu_available = thread::available_parallelism();
-        let max_cpu_available = match max_cpu_available {
+    /// Loads the thread count and RandomX mode of the selected mining mode into the miner
+    async fn load_mining_mode_settings(&mut self) -> Result<(), anyhow::Error> {
+        let mining_config = ConfigMining::content().await;
+        let cpu_usage_percentage = mining_config.get_selected_cpu_usage_percentage();
+
+        let (cpu_usage_percentage, randomx_mode) = if cpu_usage_percentage <= 1 {
+            (10, RandomXMode::Light)
+        } else {
+            (cpu_usage_percentage, RandomXMode::Fast)
+        };
+        let cpu_threads = match mining_config
+            .get_selected_benchmarked_cpu_threads(Self::available_threads())
+        {
+            Some(cpu_threads) => {
+                info!(target: LOG_TARGET_APP_LOGIC, "Using {cpu_threads} CPU threads from the thread benchmark");
+                cpu_threads
+            }
+            None => Self::determine_number_of_cores_to_use(cpu_usage_percentage).await,
+        };
+        let cpu_threads = if self.thermal_throttle_percentage < 100 {
+            let throttled_threads = apply_throttle(cpu_threads, self.thermal_throttle_percentage);
+            info!(target: LOG_TARGET_APP_LOGIC, "Thermal throttle lowers cpu threads from {cpu_threads} to {throttled_threads}");
+            throttled_threads
+        } else {
+            cpu_threads
+        };
+        self.process_watcher
+            .adapter
+            .load_cpu_threads(Some(cpu_threads))
+            .await?;
+        self.process_watcher
+            .adapter
+            .load_randomx_mode(randomx_mode)
+            .await?;
+        Ok(())
+    }
+
+    /// Applies the selected mining mode to a running miner.
+    /// The miner is updated in place when it supports it, otherwise it is restarted.
+    pub async fn update_mining_mode(&mut self) -> Result<(), anyhow::Error> {
+        if !self.is_running() {
+            return Ok(());
+        }
+
+        self.load_mining_mode_settings().await?;
+        match self.process_watcher.adapter.update_running_config().await {
+            Ok(true) => {
+                info!(target: LOG_TARGET_APP_LOGIC, "Updated cpu miner mining mode without restart");
+                return Ok(());
+            }
+            Ok(false) => {
+                info!(target: LOG_TARGET_APP_LOGIC, "Cpu miner {} requires a restart to change mining mode", self.selected_miner);
+            }
+            Err(e) => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "Failed to update running cpu miner config, restarting: {e}");
+            }
+        }
+
+        self.stop_mining().await?;
+        self.start_mining().await
+    }
+
+    /// Runs the miner with the given share of the selected mode threads, 100 removes the throttle
+    /// A running miner is updated in place when it supports it, otherwise it is restarted
+    pub async fn apply_thermal_throttle(
+        &mut self,
+        throttle_percentage: u32,
+    ) -> Result<(), anyhow::Error> {
+        self.thermal_throttle_percentage = throttle_percentage;
+        self.update_mining_mode().await
+    }
+
+    fn available_threads() -> u32 {
+        match thread::available_parallelism() {
             Ok(available_cpus) => {
                 info!(target:LOG_TARGET_APP_LOGIC, "Available CPU cores: {available_cpus}");
                 u32::try_from(available_cpus.get()).unwrap_or(1)
@@ -285,7 +580,11 @@ impl CpuManager {
                 error!("Available CPU cores: Unknown, error: {err}");
                 1
             }
-        };
+        }
+    }
+
+    async fn determine_number_of_cores_to_use(cpu_usage_percentage: u32) -> u32 {
+        let max_cpu_available = Self::available_threads();
 
         let cpu_cores_to_use = max_cpu_available
             .saturating_mul(cpu_usage_percentage)
@@ -297,6 +596,113 @@ impl CpuManager {
         cpu_cores_to_use
     }
 
+    /// Measures the hash rate at several thread counts and stores the resulting thread map of the
+    /// predefined mining modes, the miner is started for the benchmark when it is not running.
+    /// Scheduled events, mode changes and thermal throttling leave the thread count alone until it
+    /// finishes
+    pub async fn benchmark_cpu_threads() -> Result<CpuThreadBenchmark, anyhow::Error> {
+        let Some(_benchmark_guard) = CpuBenchmarkGuard::acquire() else {
+            return Err(anyhow::anyhow!("CPU thread benchmark is already running"));
+        };
+        Self::benchmark_cpu_threads_inner().await
+    }
+
+    async fn benchmark_cpu_threads_inner() -> Result<CpuThreadBenchmark, anyhow::Error> {
+        let was_running = Self::read().await.is_running();
+        if !was_running {
+            Self::write().await.start_mining().await?;
+        }
+
+        let benchmark = Self::measure_and_store_benchmark().await;
+
+        // Go back to the thread count of the selected mode, now taken from the new thread map
+        let mut manager = Self::write().await;
+        if was_running {
+            manager.update_mining_mode().await?;
+        } else if manager.is_running() {
+            manager.stop_mining().await?;
+        }
+        benchmark
+    }
+
+    async fn measure_and_store_benchmark() -> Result<CpuThreadBenchmark, anyhow::Error> {
+        let hash_rates = Self::measure_thread_counts().await?;
+        let benchmark = CpuThreadBenchmark::new(
+            Self::available_threads(),
+            hash_rates,
+            ConfigMining::content().await.mining_modes(),
+        );
+        info!(target: LOG_TARGET_APP_LOGIC, "CPU thread benchmark finished: {benchmark:?}");
+
+        ConfigMining::update_field(
+            ConfigMiningContent::set_cpu_thread_benchmark,
+            Some(benchmark.clone()),
+        )
+        .await?;
+        EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+        Ok(benchmark)
+    }
+
+    /// Runs the miner at each benchmarked thread count and averages the reported hash rate
+    async fn measure_thread_counts() -> Result<BTreeMap<u32, f64>, anyhow::Error> {
+        let mut status_receiver = Self::read().await.cpu_internal_status_channel.subscribe();
+        let mut hash_rates = BTreeMap::new();
+
+        for cpu_threads in benchmark_thread_counts(Self::available_threads()) {
+            Self::write()
+                .await
+                .apply_benchmark_threads(cpu_threads)
+                .await?;
+            sleep(BENCHMARK_WARMUP).await;
+            status_receiver.mark_unchanged();
+
+            let mut samples = Vec::new();
+            let window_end = Instant::now() + BENCHMARK_SAMPLE_WINDOW;
+            while let Ok(Ok(())) = timeout_at(window_end, status_receiver.changed()).await {
+                samples.push(status_receiver.borrow_and_update().recent_hash_rate);
+            }
+
+            match average_hash_rate(&samples) {
+                Some(hash_rate) => {
+                    info!(target: LOG_TARGET_APP_LOGIC, "CPU benchmark | threads: {cpu_threads}, hash rate: {hash_rate:.2} H/s");
+                    hash_rates.insert(cpu_threads, hash_rate);
+                }
+                None => {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "CPU benchmark | no hash rate reported with {cpu_threads} threads");
+                }
+            }
+        }
+
+        if hash_rates.is_empty() {
+            return Err(anyhow::anyhow!(
+                "CPU miner did not report any hash rate during the benchmark"
+            ));
+        }
+        Ok(hash_rates)
+    }
+
+    async fn apply_benchmark_threads(&mut self, cpu_threads: u32) -> Result<(), anyhow::Error> {
+        if !self.is_running() {
+            return Err(anyhow::anyhow!("CPU miner stopped during the benchmark"));
+        }
+        self.process_watcher
+            .adapter
+            .load_cpu_threads(Some(cpu_threads))
+            .await?;
+        self.process_watcher
+            .adapter
+            .load_randomx_mode(RandomXMode::Fast)
+            .await?;
+        if self.process_watcher.adapter.update_running_config().await? {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!(
+                "CPU miner {} can not change its thread count while running",
+                self.selected_miner
+            ))
+        }
+    }
+
     pub async fn stop_mining(&mut self) -> Result<(), anyhow::Error> {
         info!(target: LOG_TARGET_APP_LOGIC, "Stopping cpu miner");
         {
@@ -339,6 +745,16 @@ impl CpuManager {
         let cpu_external_status_channel = self.cpu_external_status_channel.clone();
         let connection_type = self.connection_type.clone();
 
+        // Failover only applies to pool mining, the settings are picked up again on each miner start
+        let pools_config = ConfigPools::content().await;
+        let failover_settings = pools_config.pool_failover().clone();
+        let is_failover_enabled = *pools_config.cpu_pool_enabled() && failover_settings.enabled;
+        let mut pool_health = PoolHealthTracker::new(Instant::now());
+        let mut failback_interval = interval_at(
+            Instant::now() + failover_settings.failback_probe_interval(),
+            failover_settings.failback_probe_interval(),
+        );
+
         let mut internal_shutdown_signal = self.status_thread_shutdown.to_signal();
         let mut global_shutdown_signal =
             TasksTrackers::current().cpu_mining_phase.get_signal().await;
@@ -360,10 +776,26 @@ impl CpuManager {
                         info!(target: LOG_TARGET_STATUSES, "Shutting down cpu miner status updates");
                         break;
                     },
+                    _ = failback_interval.tick(), if is_failover_enabled => {
+                        spawn(Self::fail_back_pool());
+                    },
                     updated_status = cpu_internal_status_reciever.changed() => {
                         info!(target: LOG_TARGET_STATUSES, "Received cpu miner status update");
                         if updated_status.is_ok() {
                             let status = cpu_internal_status_reciever.borrow().clone();
+                            if is_failover_enabled
+                                && status.is_mining
+                                && pool_health.observe(
+                                    Instant::now(),
+                                    status.connection.is_connected,
+                                    status.connection.accepted_shares,
+                                    failover_settings.failover_after(),
+                                )
+                            {
+                                warn!(target: LOG_TARGET_STATUSES, "Cpu pool has not been working for {:?}, failing over", failover_settings.failover_after());
+                                pool_health = PoolHealthTracker::new(Instant::now());
+                                spawn(Self::fail_over_pool());
+                            }
                             let paresd_status = match connection_type {
                                 CpuConnectionType::LocalMMProxy { .. } => status.clone(),
                                 CpuConnectionType::Pool { .. } => Self::handle_pool_connection_type_status_change(status.clone()).await,
@@ -21,3 +21,6 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 pub mod xmrig;
+pub mod xmrig_config;
+#[cfg(test)]
+mod xmrig_config_test;
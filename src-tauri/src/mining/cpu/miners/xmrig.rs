@@ -24,6 +24,7 @@ use anyhow::Error;
 use async_trait::async_trait;
 use log::{info, warn};
 use serde::Deserialize;
+use serde_json::Map;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
@@ -32,7 +33,11 @@ use tokio::sync::watch::Sender;
 use uuid::Uuid;
 
 use crate::mining::CpuConnectionType;
-use crate::mining::cpu::{CpuMinerConnectionStatus, CpuMinerStatus};
+use crate::mining::cpu::interface::{CpuMinerInterfaceTrait, CpuMinerStatusInterface};
+use crate::mining::cpu::miners::xmrig_config::{
+    XmrigConfig, XmrigCpuConfig, XmrigHttpConfig, XmrigPoolConfig, XmrigRandomXConfig,
+};
+use crate::mining::cpu::{CpuMinerConnectionStatus, CpuMinerStatus, RandomXMode};
 use crate::port_allocator::PortAllocator;
 use crate::process_adapter::{
     HandleUnhealthyResult, HealthStatus, ProcessAdapter, ProcessInstance, ProcessStartupSpec,
@@ -47,7 +52,7 @@ pub struct XmrigAdapter {
     pub http_api_token: String,
     pub http_api_port: u16,
     pub cpu_threads: Option<u32>,
-    pub extra_options: Vec<String>,
+    pub randomx_mode: RandomXMode,
     pub summary_broadcast: Sender<CpuMinerStatus>,
 }
 
@@ -61,14 +66,122 @@ impl XmrigAdapter {
             http_api_token: http_api_token.clone(),
             http_api_port,
             cpu_threads: None,
-            extra_options: Vec::new(),
+            randomx_mode: RandomXMode::default(),
             summary_broadcast,
         }
     }
+
+    /// Builds the xmrig config from the currently loaded settings
+    pub fn build_config(&self, log_file: Option<String>) -> XmrigConfig {
+        let (pool, retry_pause) = match &self.connection_type {
+            CpuConnectionType::LocalMMProxy { local_proxy_url } => (
+                XmrigPoolConfig {
+                    url: local_proxy_url.clone(),
+                    user: self.address.clone(),
+                    coin: Some("monero".to_string()),
+                    daemon: true,
+                    ..XmrigPoolConfig::default()
+                },
+                1,
+            ),
+            CpuConnectionType::Pool {
+                pool_url,
+                worker_name,
+            } => (
+                XmrigPoolConfig {
+                    url: pool_url.clone(),
+                    user: match worker_name {
+                        Some(worker_name) => format!("{}{}", self.address, worker_name),
+                        None => self.address.clone(),
+                    },
+                    ..XmrigPoolConfig::default()
+                },
+                5,
+            ),
+        };
+
+        XmrigConfig {
+            http: XmrigHttpConfig {
+                enabled: true,
+                host: "127.0.0.1".to_string(),
+                port: self.http_api_port,
+                access_token: Some(self.http_api_token.clone()),
+                restricted: false,
+                other: Map::new(),
+            },
+            // Lets xmrig write back changes applied through the HTTP API
+            autosave: true,
+            donate_level: 1,
+            log_file,
+            verbose: 1,
+            retries: 5,
+            retry_pause,
+            randomx: XmrigRandomXConfig {
+                mode: self.randomx_mode,
+                ..XmrigRandomXConfig::default()
+            },
+            cpu: XmrigCpuConfig::with_threads(self.cpu_threads),
+            pools: vec![pool],
+            other: Map::new(),
+        }
+    }
+
+    fn config_api_url(&self) -> String {
+        format!("http://127.0.0.1:{}/1/config", self.http_api_port)
+    }
+}
+
+impl CpuMinerInterfaceTrait for XmrigAdapter {
+    async fn load_address(&mut self, address: &str) -> Result<(), anyhow::Error> {
+        self.address = address.to_string();
+        Ok(())
+    }
+    async fn load_connection_type(
+        &mut self,
+        connection_type: CpuConnectionType,
+    ) -> Result<(), anyhow::Error> {
+        self.connection_type = connection_type;
+        Ok(())
+    }
+    async fn load_cpu_threads(&mut self, cpu_threads: Option<u32>) -> Result<(), anyhow::Error> {
+        self.cpu_threads = cpu_threads;
+        Ok(())
+    }
+    async fn load_randomx_mode(&mut self, randomx_mode: RandomXMode) -> Result<(), anyhow::Error> {
+        self.randomx_mode = randomx_mode;
+        Ok(())
+    }
+
+    /// Reads the config of the running process, replaces the reloadable sections and sends it back.
+    /// xmrig applies pools, threads and RandomX mode changes without restarting.
+    async fn update_running_config(&mut self) -> Result<bool, anyhow::Error> {
+        let client = reqwest::Client::new();
+        let mut config: XmrigConfig = client
+            .get(self.config_api_url())
+            .header("Authorization", format!("Bearer {}", self.http_api_token))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        config.apply_reloadable_sections(self.build_config(None));
+
+        client
+            .put(self.config_api_url())
+            .header("Authorization", format!("Bearer {}", self.http_api_token))
+            .json(&config)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        info!(target: LOG_TARGET_APP_LOGIC, "Updated running xmrig config | threads: {:?}, randomx mode: {:?}", self.cpu_threads, self.randomx_mode);
+        Ok(true)
+    }
 }
 
 impl ProcessAdapter for XmrigAdapter {
-    type StatusMonitor = XmrigStatusMonitor;
+    type StatusMonitor = CpuMinerStatusInterface;
     type ProcessInstance = ProcessInstance;
 
     fn spawn_inner(
@@ -81,75 +194,26 @@ impl ProcessAdapter for XmrigAdapter {
     ) -> Result<(ProcessInstance, Self::StatusMonitor), anyhow::Error> {
         let xmrig_shutdown = Shutdown::new();
 
-        let mut args = vec![];
-
-        let connection_type_args = match &self.connection_type {
-            CpuConnectionType::LocalMMProxy { local_proxy_url } => {
-                let extra_args = vec![
-                    "--user".to_string(),
-                    self.address.to_string(),
-                    "--daemon".to_string(),
-                    "--retry-pause=1".to_string(),
-                    format!("--url={}", local_proxy_url),
-                    "--coin=monero".to_string(), // is it needed? if yes then is it needed for pool mining?
-                ];
-                extra_args
-            }
-            CpuConnectionType::Pool {
-                pool_url,
-                worker_name,
-            } => {
-                let mut extra_args = vec![];
-                let extended_user_address = match worker_name {
-                    Some(worker_name) => format!("{}{}", self.address, worker_name),
-                    None => self.address.to_string(),
-                };
-                extra_args.push(format!("--url={}", pool_url));
-                extra_args.push("--user".to_string());
-                extra_args.push(extended_user_address);
-                extra_args
-            }
-        };
-
-        args.extend(connection_type_args);
-
         let xmrig_log_file = log_dir.join("xmrig").join("xmrig.log");
         std::fs::create_dir_all(
-            xmrig_log_file
-                .parent()
-                .expect("Could not get xmrig root log dir"),
+            xmrig_log_file.parent().ok_or_else(|| {
+                anyhow::anyhow!("Could not get parent directory of xmrig log file")
+            })?,
         )?;
 
-        let xmrig_log_file_parent = xmrig_log_file
-            .parent()
-            .ok_or_else(|| anyhow::anyhow!("Could not get parent directory of xmrig log file"))?;
-
-        match xmrig_log_file.to_str() {
-            Some(log_file) => {
-                args.push(format!("--log-file={}", &log_file));
-            }
-            None => {
-                warn!(target: LOG_TARGET_APP_LOGIC, "Could not convert xmrig log file path to string");
-                warn!(target: LOG_TARGET_APP_LOGIC, "Logs argument will not be added to xmrig");
-            }
-        };
+        let log_file = xmrig_log_file.to_str().map(|log_file| log_file.to_string());
+        if log_file.is_none() {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Could not convert xmrig log file path to string");
+            warn!(target: LOG_TARGET_APP_LOGIC, "Log file will not be set in xmrig config");
+        }
 
-        std::fs::create_dir_all(xmrig_log_file_parent).unwrap_or_else(|error| {
-            warn!(target: LOG_TARGET_APP_LOGIC, "Could not create xmrig log file parent directory - {error}");
-        });
+        let config_file = data_dir.join("xmrig").join("config.json");
+        self.build_config(log_file).write_to_file(&config_file)?;
 
-        args.push(format!("--http-port={}", self.http_api_port));
-        args.push(format!("--http-access-token={}", self.http_api_token));
-        args.push("--donate-level=1".to_string());
-
-        // don't specify threads for ludicrous mode
-        if let Some(cpu_threads) = self.cpu_threads {
-            args.push(format!("--threads={cpu_threads}"));
-        }
-        args.push("--verbose".to_string());
-        for extra_option in &self.extra_options {
-            args.push(extra_option.clone());
-        }
+        let config_file = config_file
+            .to_str()
+            .ok_or_else(|| anyhow::anyhow!("Could not convert xmrig config file path to string"))?;
+        let args = vec![format!("--config={config_file}")];
 
         Ok((
             ProcessInstance {
@@ -164,11 +228,11 @@ impl ProcessAdapter for XmrigAdapter {
                     name: self.name().to_string(),
                 },
             },
-            XmrigStatusMonitor {
+            CpuMinerStatusInterface::Xmrig(XmrigStatusMonitor {
                 summary_broadcast: self.summary_broadcast.clone(),
                 access_token: self.http_api_token.clone(),
                 http_api_port: self.http_api_port.to_string(),
-            },
+            }),
         ))
     }
 
@@ -259,6 +323,8 @@ pub(crate) struct Summary {
 #[derive(Deserialize, Debug, Clone)]
 pub struct Connection {
     pub(crate) uptime: u64,
+    #[serde(default)]
+    pub(crate) accepted: u64,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -313,8 +379,10 @@ impl XmrigStatusMonitor {
             is_mining: true,
             estimated_earnings: 0,
             hash_rate: avarage_hash_rate.unwrap_or(0.0),
+            recent_hash_rate: ten_second_hash_rate.unwrap_or(0.0),
             connection: CpuMinerConnectionStatus {
                 is_connected: body.connection.uptime > 0,
+                accepted_shares: body.connection.accepted,
             },
         })
     }
@@ -0,0 +1,199 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::mining::cpu::RandomXMode;
+use crate::utils::file_utils::write_private_file;
+
+/// Typed model of the xmrig `config.json`.
+/// Keys that are not modelled here are kept in `other`, at the top level and in every
+/// section, so a config read back from the xmrig HTTP API can be sent back without losing anything.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct XmrigConfig {
+    pub http: XmrigHttpConfig,
+    pub autosave: bool,
+    pub donate_level: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub log_file: Option<String>,
+    pub verbose: u8,
+    pub retries: u32,
+    pub retry_pause: u32,
+    pub randomx: XmrigRandomXConfig,
+    pub cpu: XmrigCpuConfig,
+    pub pools: Vec<XmrigPoolConfig>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct XmrigHttpConfig {
+    pub enabled: bool,
+    pub host: String,
+    pub port: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token: Option<String>,
+    /// The config endpoints of the HTTP API are only available when not restricted
+    pub restricted: bool,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct XmrigRandomXConfig {
+    pub mode: RandomXMode,
+    #[serde(rename = "1gb-pages")]
+    pub one_gb_pages: bool,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct XmrigCpuConfig {
+    pub enabled: bool,
+    pub huge_pages: bool,
+    pub huge_pages_jit: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u8>,
+    /// RandomX thread profile, one entry per thread.
+    /// Each entry is the CPU index the thread is pinned to, or -1 for no affinity.
+    /// When not set xmrig picks the number of threads on its own.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rx: Option<Vec<i32>>,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl Default for XmrigCpuConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            huge_pages: true,
+            huge_pages_jit: false,
+            priority: None,
+            rx: None,
+            other: Map::new(),
+        }
+    }
+}
+
+impl XmrigCpuConfig {
+    pub fn with_threads(threads: Option<u32>) -> Self {
+        Self {
+            rx: threads.map(|threads| (0..threads).map(|_| -1).collect()),
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", default)]
+pub struct XmrigPoolConfig {
+    pub url: String,
+    pub user: String,
+    pub pass: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coin: Option<String>,
+    pub enabled: bool,
+    pub keepalive: bool,
+    pub tls: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls_fingerprint: Option<String>,
+    /// Solo mining against a daemon rpc (merge mining proxy) instead of a stratum pool
+    pub daemon: bool,
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl Default for XmrigPoolConfig {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            user: String::new(),
+            pass: "x".to_string(),
+            coin: None,
+            enabled: true,
+            keepalive: false,
+            tls: false,
+            tls_fingerprint: None,
+            daemon: false,
+            other: Map::new(),
+        }
+    }
+}
+
+impl XmrigConfig {
+    /// Writes the config readable only by the current user, as it holds the HTTP API access token.
+    pub fn write_to_file(&self, path: &Path) -> Result<(), anyhow::Error> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        write_private_file(path, serde_json::to_string_pretty(self)?.as_bytes())
+    }
+
+    /// Copies the sections that xmrig can reload on a running process from `desired`.
+    /// Everything else, like the HTTP server and the log file, is kept as it is.
+    /// Keys of those sections that are not modelled here are kept from the running config,
+    /// for pools only when the pool url stays the same.
+    pub fn apply_reloadable_sections(&mut self, desired: XmrigConfig) {
+        let XmrigConfig {
+            mut randomx,
+            mut cpu,
+            mut pools,
+            retries,
+            retry_pause,
+            ..
+        } = desired;
+        randomx.other = merge_other(std::mem::take(&mut self.randomx.other), randomx.other);
+        cpu.other = merge_other(std::mem::take(&mut self.cpu.other), cpu.other);
+        for pool in &mut pools {
+            if let Some(running) = self
+                .pools
+                .iter_mut()
+                .find(|running| running.url == pool.url)
+            {
+                pool.other = merge_other(
+                    std::mem::take(&mut running.other),
+                    std::mem::take(&mut pool.other),
+                );
+            }
+        }
+        self.randomx = randomx;
+        self.cpu = cpu;
+        self.pools = pools;
+        self.retries = retries;
+        self.retry_pause = retry_pause;
+    }
+}
+
+/// Unmodelled keys of a running section, with the ones set in `desired` taking precedence.
+fn merge_other(mut running: Map<String, Value>, desired: Map<String, Value>) -> Map<String, Value> {
+    running.extend(desired);
+    running
+}
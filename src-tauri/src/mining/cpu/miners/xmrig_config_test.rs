@@ -0,0 +1,164 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use serde_json::json;
+use tokio::sync::watch::Sender;
+
+use crate::mining::CpuConnectionType;
+use crate::mining::cpu::miners::xmrig::XmrigAdapter;
+use crate::mining::cpu::{CpuMinerStatus, RandomXMode};
+
+use super::xmrig_config::{XmrigConfig, XmrigCpuConfig};
+
+fn pool_adapter() -> XmrigAdapter {
+    let mut adapter = XmrigAdapter::new(Sender::new(CpuMinerStatus::default()));
+    adapter.connection_type = CpuConnectionType::Pool {
+        pool_url: "pool.example.com:3333".to_string(),
+        worker_name: Some(".Tari-universe".to_string()),
+    };
+    adapter.address = "address".to_string();
+    adapter.cpu_threads = Some(3);
+    adapter.randomx_mode = RandomXMode::Fast;
+    adapter
+}
+
+#[test]
+fn cpu_config_without_threads_leaves_thread_count_to_xmrig() {
+    assert_eq!(XmrigCpuConfig::with_threads(None).rx, None);
+    assert_eq!(XmrigCpuConfig::with_threads(Some(2)).rx, Some(vec![-1, -1]));
+}
+
+#[test]
+fn build_config_serializes_with_xmrig_keys() {
+    let adapter = pool_adapter();
+    let config = adapter.build_config(Some("/logs/xmrig.log".to_string()));
+    let value = serde_json::to_value(&config).unwrap();
+
+    assert_eq!(value["http"]["port"], json!(adapter.http_api_port));
+    assert_eq!(value["http"]["access-token"], json!(adapter.http_api_token));
+    assert_eq!(value["http"]["restricted"], json!(false));
+    assert_eq!(value["donate-level"], json!(1));
+    assert_eq!(value["log-file"], json!("/logs/xmrig.log"));
+    assert_eq!(value["randomx"]["mode"], json!("fast"));
+    assert_eq!(value["randomx"]["1gb-pages"], json!(false));
+    assert_eq!(value["cpu"]["huge-pages"], json!(true));
+    assert_eq!(value["cpu"]["rx"], json!([-1, -1, -1]));
+    assert_eq!(value["pools"][0]["url"], json!("pool.example.com:3333"));
+    assert_eq!(value["pools"][0]["user"], json!("address.Tari-universe"));
+    assert_eq!(value["pools"][0]["daemon"], json!(false));
+    assert_eq!(value["retry-pause"], json!(5));
+}
+
+#[test]
+fn build_config_for_local_proxy_mines_against_daemon() {
+    let mut adapter = pool_adapter();
+    adapter.connection_type = CpuConnectionType::LocalMMProxy {
+        local_proxy_url: "127.0.0.1:18081".to_string(),
+    };
+
+    let config = adapter.build_config(None);
+
+    assert_eq!(config.pools.len(), 1);
+    assert!(config.pools[0].daemon);
+    assert_eq!(config.pools[0].coin.as_deref(), Some("monero"));
+    assert_eq!(config.pools[0].user, "address");
+    assert_eq!(config.retry_pause, 1);
+}
+
+#[test]
+fn config_read_from_xmrig_keeps_unknown_keys() {
+    let running = json!({
+        "http": { "enabled": true, "host": "127.0.0.1", "port": 1234, "access-token": "token", "restricted": false },
+        "log-file": "/logs/xmrig.log",
+        "randomx": { "mode": "light", "1gb-pages": false },
+        "cpu": { "enabled": true, "huge-pages": true, "rx": [-1] },
+        "pools": [],
+        "print-time": 60,
+        "background": false
+    });
+
+    let config: XmrigConfig = serde_json::from_value(running).unwrap();
+    let value = serde_json::to_value(&config).unwrap();
+
+    assert_eq!(config.randomx.mode, RandomXMode::Light);
+    assert_eq!(value["print-time"], json!(60));
+    assert_eq!(value["background"], json!(false));
+}
+
+#[test]
+fn apply_reloadable_sections_keeps_http_and_log_file() {
+    let adapter = pool_adapter();
+    let mut running = adapter.build_config(Some("/logs/xmrig.log".to_string()));
+    running.http.port = 4321;
+    running.randomx.mode = RandomXMode::Light;
+    running.cpu = XmrigCpuConfig::with_threads(Some(1));
+
+    running.apply_reloadable_sections(adapter.build_config(None));
+
+    assert_eq!(running.http.port, 4321);
+    assert_eq!(running.log_file.as_deref(), Some("/logs/xmrig.log"));
+    assert_eq!(running.randomx.mode, RandomXMode::Fast);
+    assert_eq!(running.cpu.rx, Some(vec![-1, -1, -1]));
+}
+
+#[test]
+fn unknown_keys_in_sections_survive_a_reload() {
+    let adapter = pool_adapter();
+    let mut running: XmrigConfig = serde_json::from_value(json!({
+        "http": { "enabled": true, "port": 1234, "access-token": "token", "ipv6": false },
+        "randomx": { "mode": "light", "init": -1, "numa": true },
+        "cpu": { "enabled": true, "rx": [-1], "argon2-impl": null, "max-threads-hint": 100 },
+        "pools": [{ "url": "pool.example.com:3333", "user": "address", "rig-id": "rig" }]
+    }))
+    .unwrap();
+
+    running.apply_reloadable_sections(adapter.build_config(None));
+    let value = serde_json::to_value(&running).unwrap();
+
+    assert_eq!(value["http"]["ipv6"], json!(false));
+    assert_eq!(value["randomx"]["mode"], json!("fast"));
+    assert_eq!(value["randomx"]["init"], json!(-1));
+    assert_eq!(value["randomx"]["numa"], json!(true));
+    assert_eq!(value["cpu"]["rx"], json!([-1, -1, -1]));
+    assert_eq!(value["cpu"]["argon2-impl"], json!(null));
+    assert_eq!(value["cpu"]["max-threads-hint"], json!(100));
+    assert_eq!(value["pools"][0]["user"], json!("address.Tari-universe"));
+    assert_eq!(value["pools"][0]["rig-id"], json!("rig"));
+}
+
+#[cfg(unix)]
+#[test]
+fn config_file_is_only_readable_by_the_owner() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("xmrig").join("config.json");
+
+    let config = pool_adapter().build_config(None);
+    config.write_to_file(&path).unwrap();
+
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    let written: XmrigConfig =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(written, config);
+}
@@ -20,8 +20,13 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
+pub mod benchmark;
+#[cfg(test)]
+mod benchmark_test;
+pub mod consts;
+pub mod interface;
 pub mod manager;
 pub mod miners;
 
@@ -29,6 +34,9 @@ pub mod miners;
 pub struct CpuMinerStatus {
     pub is_mining: bool,
     pub hash_rate: f64,
+    /// Short window hash rate, follows thread count changes faster than `hash_rate`
+    #[serde(skip)]
+    pub recent_hash_rate: f64,
     pub estimated_earnings: u64,
     pub connection: CpuMinerConnectionStatus,
 }
@@ -38,14 +46,25 @@ impl Default for CpuMinerStatus {
         Self {
             is_mining: false,
             hash_rate: 0.0,
+            recent_hash_rate: 0.0,
             estimated_earnings: 0,
-            connection: CpuMinerConnectionStatus {
-                is_connected: false,
-            },
+            connection: CpuMinerConnectionStatus::default(),
         }
     }
 }
 #[derive(Debug, Serialize, Clone, Default)]
 pub struct CpuMinerConnectionStatus {
     pub is_connected: bool,
+    /// Shares accepted by the pool since the miner started
+    pub accepted_shares: u64,
+}
+
+/// RandomX dataset mode, light mode uses far less memory at the cost of hashrate
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum RandomXMode {
+    #[default]
+    Auto,
+    Light,
+    Fast,
 }
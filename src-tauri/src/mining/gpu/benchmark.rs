@@ -0,0 +1,205 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::BTreeMap,
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    event_scheduler::EventScheduler,
+    mining::{
+        cpu::benchmark::average_hash_rate,
+        gpu::consts::{GpuMinerStatus, GpuMinerType, GpuMiningAlgorithm},
+    },
+};
+
+/// Time given to a miner to connect and ramp up before its hash rate is sampled
+pub const GPU_BENCHMARK_WARMUP: Duration = Duration::from_secs(30);
+/// Window over which the hash rate of each miner is sampled
+pub const GPU_BENCHMARK_SAMPLE_WINDOW: Duration = Duration::from_secs(60);
+/// Share of samples that must report a hash rate for a miner to be considered stable
+const MIN_STABLE_PERCENTAGE: u32 = 80;
+
+static IS_BENCHMARK_RUNNING: AtomicBool = AtomicBool::new(false);
+
+/// Whether a gpu miner benchmark is switching and restarting miners right now
+pub fn is_gpu_benchmark_running() -> bool {
+    IS_BENCHMARK_RUNNING.load(Ordering::SeqCst)
+}
+
+/// Held for the length of a gpu miner benchmark. Dropping it clears the running flag, also when
+/// the benchmark fails or panics, and lets the scheduler run the callbacks it held back meanwhile
+pub struct GpuBenchmarkGuard {
+    _private: (),
+}
+
+impl GpuBenchmarkGuard {
+    /// Returns `None` when another benchmark is already running
+    pub fn acquire() -> Option<Self> {
+        if IS_BENCHMARK_RUNNING.swap(true, Ordering::SeqCst) {
+            return None;
+        }
+        Some(Self { _private: () })
+    }
+}
+
+impl Drop for GpuBenchmarkGuard {
+    fn drop(&mut self) {
+        IS_BENCHMARK_RUNNING.store(false, Ordering::SeqCst);
+        EventScheduler::instance().catch_up_after_benchmark();
+    }
+}
+
+/// Hash rate and stability of one device while a miner was benchmarked
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GpuDeviceBenchmark {
+    pub device_index: u32,
+    pub name: String,
+    pub hash_rate: f64,
+    /// Share of the samples in which the device reported a hash rate
+    pub stability_percentage: u32,
+}
+
+/// Result of running one gpu miner for the benchmark window
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GpuMinerBenchmark {
+    pub miner_type: GpuMinerType,
+    pub algorithm: GpuMiningAlgorithm,
+    /// Average hash rate in the unit of the miner's algorithm
+    pub hash_rate: f64,
+    /// Share of the samples in which the miner reported a hash rate
+    pub stability_percentage: u32,
+    /// Per device breakdown, empty for miners that only report totals
+    pub devices: Vec<GpuDeviceBenchmark>,
+    pub error: Option<String>,
+}
+
+impl GpuMinerBenchmark {
+    pub fn from_samples(miner_type: GpuMinerType, samples: &[GpuMinerStatus]) -> Self {
+        let hash_rates: Vec<f64> = samples.iter().map(|s| s.hash_rate).collect();
+        let hash_rate = average_hash_rate(&hash_rates);
+        let error = if samples.is_empty() {
+            Some("Miner did not report any status during the benchmark".to_string())
+        } else {
+            hash_rate
+                .is_none()
+                .then(|| "Miner did not report any hash rate".to_string())
+        };
+
+        Self {
+            algorithm: miner_type.main_algorithm(),
+            hash_rate: hash_rate.unwrap_or_default(),
+            stability_percentage: stability_percentage(&hash_rates),
+            devices: device_benchmarks(samples),
+            error,
+            miner_type,
+        }
+    }
+
+    pub fn failed(miner_type: GpuMinerType, error: String) -> Self {
+        Self {
+            algorithm: miner_type.main_algorithm(),
+            hash_rate: 0.0,
+            stability_percentage: 0,
+            devices: vec![],
+            error: Some(error),
+            miner_type,
+        }
+    }
+
+    pub fn is_stable(&self) -> bool {
+        self.error.is_none() && self.stability_percentage >= MIN_STABLE_PERCENTAGE
+    }
+}
+
+/// Results of all gpu miners benchmarked on this machine
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GpuMinersBenchmark {
+    /// Results in the order the miners were benchmarked
+    pub results: Vec<GpuMinerBenchmark>,
+    pub recommended_miner: Option<GpuMinerType>,
+    pub benchmarked_at: i64,
+}
+
+impl GpuMinersBenchmark {
+    /// Recommends among the miners of `algorithm`, the one the selected pool or node mines
+    pub fn new(results: Vec<GpuMinerBenchmark>, algorithm: &GpuMiningAlgorithm) -> Self {
+        Self {
+            recommended_miner: recommend_miner(&results, algorithm),
+            results,
+            benchmarked_at: Utc::now().timestamp(),
+        }
+    }
+}
+
+/// Picks the stable miner of `algorithm` with the highest hash rate, earlier results break ties.
+/// Hash rates of other algorithms are not comparable, so those miners are never recommended
+pub fn recommend_miner(
+    results: &[GpuMinerBenchmark],
+    algorithm: &GpuMiningAlgorithm,
+) -> Option<GpuMinerType> {
+    results
+        .iter()
+        .filter(|r| r.is_stable() && r.algorithm == *algorithm)
+        .fold(
+            None,
+            |best: Option<&GpuMinerBenchmark>, candidate| match best {
+                Some(best) if candidate.hash_rate <= best.hash_rate => Some(best),
+                _ => Some(candidate),
+            },
+        )
+        .map(|best| best.miner_type.clone())
+}
+
+fn stability_percentage(hash_rates: &[f64]) -> u32 {
+    if hash_rates.is_empty() {
+        return 0;
+    }
+    let hashing = hash_rates.iter().filter(|rate| **rate > 0.0).count();
+    u32::try_from(hashing * 100 / hash_rates.len()).unwrap_or(0)
+}
+
+fn device_benchmarks(samples: &[GpuMinerStatus]) -> Vec<GpuDeviceBenchmark> {
+    let mut devices: BTreeMap<u32, (String, Vec<f64>)> = BTreeMap::new();
+    for device in samples.iter().flat_map(|s| &s.devices) {
+        devices
+            .entry(device.device_index)
+            .or_insert_with(|| (device.name.clone(), vec![]))
+            .1
+            .push(device.hash_rate);
+    }
+
+    devices
+        .into_iter()
+        .map(|(device_index, (name, hash_rates))| GpuDeviceBenchmark {
+            device_index,
+            name,
+            hash_rate: average_hash_rate(&hash_rates).unwrap_or_default(),
+            stability_percentage: stability_percentage(&hash_rates),
+        })
+        .collect()
+}
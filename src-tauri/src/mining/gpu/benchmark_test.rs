@@ -0,0 +1,160 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::mining::gpu::{
+    consts::{GpuMinerStatus, GpuMinerType, GpuMiningAlgorithm},
+    device_status::GpuDeviceMiningStatus,
+};
+
+use super::benchmark::{
+    GpuBenchmarkGuard, GpuMinerBenchmark, is_gpu_benchmark_running, recommend_miner,
+};
+
+fn sample(device_hash_rates: &[f64]) -> GpuMinerStatus {
+    GpuMinerStatus {
+        hash_rate: device_hash_rates.iter().sum(),
+        devices: device_hash_rates
+            .iter()
+            .enumerate()
+            .map(|(index, hash_rate)| GpuDeviceMiningStatus {
+                device_index: u32::try_from(index).unwrap(),
+                name: format!("GPU {index}"),
+                hash_rate: *hash_rate,
+                ..Default::default()
+            })
+            .collect(),
+        ..GpuMinerStatus::default_with_algorithm(GpuMinerType::LolMiner.main_algorithm())
+    }
+}
+
+fn result(
+    miner_type: GpuMinerType,
+    hash_rate: f64,
+    stability_percentage: u32,
+) -> GpuMinerBenchmark {
+    GpuMinerBenchmark {
+        hash_rate,
+        stability_percentage,
+        ..GpuMinerBenchmark::from_samples(miner_type, &[sample(&[hash_rate])])
+    }
+}
+
+#[test]
+fn from_samples_averages_hash_rate_and_tracks_stability_per_device() {
+    let samples = vec![
+        sample(&[10.0, 2.0]),
+        sample(&[12.0, 0.0]),
+        sample(&[11.0, 0.0]),
+        sample(&[11.0, 0.0]),
+    ];
+
+    let benchmark = GpuMinerBenchmark::from_samples(GpuMinerType::LolMiner, &samples);
+
+    assert!(benchmark.error.is_none());
+    assert!((benchmark.hash_rate - 11.5).abs() < f64::EPSILON);
+    assert_eq!(benchmark.stability_percentage, 100);
+    assert_eq!(benchmark.devices.len(), 2);
+    assert!((benchmark.devices[0].hash_rate - 11.0).abs() < f64::EPSILON);
+    assert_eq!(benchmark.devices[0].stability_percentage, 100);
+    assert_eq!(benchmark.devices[1].stability_percentage, 25);
+}
+
+#[test]
+fn from_samples_without_hash_rate_is_an_error() {
+    let no_samples = GpuMinerBenchmark::from_samples(GpuMinerType::Glytex, &[]);
+    let idle = GpuMinerBenchmark::from_samples(GpuMinerType::Glytex, &[sample(&[0.0])]);
+
+    assert!(no_samples.error.is_some());
+    assert!(idle.error.is_some());
+    assert!(!idle.is_stable());
+}
+
+#[test]
+fn recommend_miner_skips_unstable_and_failed_miners() {
+    let results = vec![
+        result(GpuMinerType::LolMiner, 8.0, 40),
+        GpuMinerBenchmark::failed(GpuMinerType::Glytex, "crashed".to_string()),
+    ];
+
+    assert_eq!(recommend_miner(&results, &GpuMiningAlgorithm::C29), None);
+    assert_eq!(recommend_miner(&results, &GpuMiningAlgorithm::Sha3x), None);
+}
+
+#[test]
+fn recommend_miner_ignores_miners_of_other_algorithms() {
+    let results = vec![
+        result(GpuMinerType::LolMiner, 8.0, 90),
+        result(GpuMinerType::Glytex, 1_000_000.0, 100),
+    ];
+
+    assert_eq!(
+        recommend_miner(&results, &GpuMiningAlgorithm::C29),
+        Some(GpuMinerType::LolMiner)
+    );
+    assert_eq!(
+        recommend_miner(&results, &GpuMiningAlgorithm::Sha3x),
+        Some(GpuMinerType::Glytex)
+    );
+}
+
+#[test]
+fn recommend_miner_keeps_earlier_result_on_ties() {
+    let results = vec![
+        result(GpuMinerType::LolMiner, 8.0, 100),
+        GpuMinerBenchmark {
+            algorithm: GpuMiningAlgorithm::C29,
+            ..result(GpuMinerType::Glytex, 8.0, 100)
+        },
+    ];
+
+    assert_eq!(
+        recommend_miner(&results, &GpuMiningAlgorithm::C29),
+        Some(GpuMinerType::LolMiner)
+    );
+}
+
+#[test]
+fn recommend_miner_compares_hash_rate_within_an_algorithm() {
+    let results = vec![
+        result(GpuMinerType::LolMiner, 8.0, 100),
+        GpuMinerBenchmark {
+            algorithm: GpuMiningAlgorithm::C29,
+            ..result(GpuMinerType::Glytex, 9.5, 85)
+        },
+    ];
+
+    assert_eq!(
+        recommend_miner(&results, &GpuMiningAlgorithm::C29),
+        Some(GpuMinerType::Glytex)
+    );
+}
+
+#[test]
+fn benchmark_guard_is_exclusive_and_clears_the_flag_on_drop() {
+    let guard = GpuBenchmarkGuard::acquire().unwrap();
+    assert!(is_gpu_benchmark_running());
+    assert!(GpuBenchmarkGuard::acquire().is_none());
+
+    drop(guard);
+    assert!(!is_gpu_benchmark_running());
+    assert!(GpuBenchmarkGuard::acquire().is_some());
+}
@@ -23,7 +23,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    binaries::Binaries,
     configs::pools::gpu_pools::GpuPool,
+    mining::gpu::device_status::GpuDeviceMiningStatus,
     utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
 };
 
@@ -33,6 +35,12 @@ pub(crate) struct GpuMinerStatus {
     pub hash_rate: f64,
     pub estimated_earnings: u64, // Only for node connections
     pub algorithm: GpuMiningAlgorithm,
+    pub is_connected: bool,
+    /// Shares accepted by the pool since the miner started
+    pub accepted_shares: u64,
+    /// Per device breakdown, emitted separately from the aggregate status
+    #[serde(skip)]
+    pub devices: Vec<GpuDeviceMiningStatus>,
 }
 
 impl GpuMinerStatus {
@@ -42,21 +50,41 @@ impl GpuMinerStatus {
             hash_rate: 0.0,
             estimated_earnings: 0,
             algorithm,
+            is_connected: false,
+            accepted_shares: 0,
+            devices: vec![],
         }
     }
 }
 
-#[derive(Eq, Hash, PartialEq, Clone, Deserialize, Serialize, Debug)]
+#[derive(Eq, Hash, PartialEq, Clone, Deserialize, Serialize, Debug, Default)]
 pub enum GpuMinerType {
+    #[default]
     LolMiner,
+    /// Tari GPU miner, mines directly against the base node or on a user defined pool
+    Glytex,
 }
 
 impl GpuMinerType {
+    pub fn binary(&self) -> Binaries {
+        match self {
+            GpuMinerType::LolMiner => Binaries::LolMiner,
+            GpuMinerType::Glytex => Binaries::Glytex,
+        }
+    }
+
     pub fn get_expected_features(&self) -> Vec<GpuMinerFeature> {
         match self {
             GpuMinerType::LolMiner => vec![
                 GpuMinerFeature::PoolMining,
                 GpuMinerFeature::DeviceExclusion,
+                GpuMinerFeature::DeviceLimits,
+            ],
+            GpuMinerType::Glytex => vec![
+                GpuMinerFeature::SoloMining,
+                GpuMinerFeature::PoolMining,
+                GpuMinerFeature::DeviceExclusion,
+                GpuMinerFeature::MiningIntensity,
             ],
         }
     }
@@ -64,12 +92,14 @@ impl GpuMinerType {
     pub fn main_algorithm(&self) -> GpuMiningAlgorithm {
         match self {
             GpuMinerType::LolMiner => GpuMiningAlgorithm::C29,
+            GpuMinerType::Glytex => GpuMiningAlgorithm::Sha3x,
         }
     }
 
     pub fn supported_algorithms(&self) -> Vec<GpuMiningAlgorithm> {
         match self {
             GpuMinerType::LolMiner => vec![GpuMiningAlgorithm::C29],
+            GpuMinerType::Glytex => vec![GpuMiningAlgorithm::Sha3x],
         }
     }
 
@@ -79,12 +109,23 @@ impl GpuMinerType {
                 CurrentOperatingSystem::Windows,
                 CurrentOperatingSystem::Linux,
             ],
+            // GPU mining is not offered on macOS
+            GpuMinerType::Glytex => vec![
+                CurrentOperatingSystem::Windows,
+                CurrentOperatingSystem::Linux,
+            ],
         }
     }
 
     pub fn supported_pools(&self) -> Vec<GpuPool> {
         match self {
-            GpuMinerType::LolMiner => vec![GpuPool::KryptexPoolC29, GpuPool::LuckyPoolC29],
+            GpuMinerType::LolMiner => vec![
+                GpuPool::KryptexPoolC29,
+                GpuPool::LuckyPoolC29,
+                GpuPool::Custom,
+            ],
+            // The predefined pools are C29 only, Sha3x pools have to be added as a custom pool
+            GpuMinerType::Glytex => vec![GpuPool::CustomSha3x],
         }
     }
 
@@ -95,6 +136,7 @@ impl GpuMinerType {
     pub fn default_pool(&self) -> Option<GpuPool> {
         match self {
             GpuMinerType::LolMiner => Some(GpuPool::LuckyPoolC29),
+            GpuMinerType::Glytex => None,
         }
     }
 
@@ -110,12 +152,21 @@ impl GpuMinerType {
         self.get_expected_features()
             .contains(&GpuMinerFeature::SoloMining)
     }
+    pub fn is_intensity_supported(&self) -> bool {
+        self.get_expected_features()
+            .contains(&GpuMinerFeature::MiningIntensity)
+    }
+    pub fn is_device_limits_supported(&self) -> bool {
+        self.get_expected_features()
+            .contains(&GpuMinerFeature::DeviceLimits)
+    }
 }
 
 impl std::fmt::Display for GpuMinerType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let s = match self {
             GpuMinerType::LolMiner => "LolMiner",
+            GpuMinerType::Glytex => "Glytex",
         };
         write!(f, "{s}")
     }
@@ -125,6 +176,7 @@ impl std::fmt::Display for GpuMinerType {
 pub enum GpuMiningAlgorithm {
     #[default]
     C29,
+    Sha3x,
 }
 
 #[derive(Eq, Hash, PartialEq, Clone, Serialize)]
@@ -135,6 +187,10 @@ pub enum GpuMinerFeature {
     PoolMining,
     /// Support for excluding specific GPU devices
     DeviceExclusion,
+    /// Support for overriding the mining intensity per GPU device
+    MiningIntensity,
+    /// Support for power and temperature caps per GPU device
+    DeviceLimits,
 }
 
 #[derive(Clone, Serialize)]
@@ -161,4 +217,9 @@ impl GpuMiner {
 /// Defines priority of miners to be used when multiple miners are available
 /// The first miner in the list has the highest priority
 /// Used for selecting default or fallback miner
-pub const MINERS_PRIORITY: &[GpuMinerType] = &[GpuMinerType::LolMiner];
+pub const MINERS_PRIORITY: &[GpuMinerType] = &[GpuMinerType::LolMiner, GpuMinerType::Glytex];
+
+/// Bounds accepted for the per device temperature cap, in celsius.
+/// Lower caps would stop most cards right after they start hashing
+pub const MIN_GPU_TEMPERATURE_CAP: u32 = 50;
+pub const MAX_GPU_TEMPERATURE_CAP: u32 = 100;
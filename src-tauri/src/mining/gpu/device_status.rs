@@ -0,0 +1,100 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use serde::Serialize;
+
+use crate::{
+    hardware::hardware_status_monitor::{DeviceParameters, PublicDeviceGpuProperties},
+    mining::gpu::miners::GpuCommonInformation,
+};
+
+/// Mining status of a single GPU, as reported by the miner's API
+#[derive(Debug, Serialize, Clone, Default)]
+pub(crate) struct GpuDeviceMiningStatus {
+    pub device_index: u32,
+    pub name: String,
+    pub hash_rate: f64,
+    pub accepted_shares: u64,
+    pub rejected_shares: u64,
+    /// Sensor readings of the miner, None when it could not read them
+    pub temperature: Option<f32>,
+    pub fan_speed: Option<f32>,
+    pub power_watts: Option<f32>,
+    /// Readings of the hardware status monitor for the same card, None when no card of that name was found
+    pub hardware_parameters: Option<DeviceParameters>,
+}
+
+/// Attaches the hardware monitor readings to the miner's devices.
+/// The miner and the hardware monitor do not number devices the same way, e.g. when the miner
+/// skips integrated GPUs, so devices are matched by name and left without readings when no
+/// card of that name is found. Identical cards are paired up in the order they were detected.
+pub(crate) fn merge_hardware_parameters(
+    mut devices: Vec<GpuDeviceMiningStatus>,
+    hardware_devices: &[PublicDeviceGpuProperties],
+) -> Vec<GpuDeviceMiningStatus> {
+    let mut unmatched: Vec<(String, &PublicDeviceGpuProperties)> = hardware_devices
+        .iter()
+        .map(|hardware_device| (normalized_name(&hardware_device.name), hardware_device))
+        .collect();
+
+    let mut by_device_index: Vec<&mut GpuDeviceMiningStatus> = devices.iter_mut().collect();
+    by_device_index.sort_by_key(|device| device.device_index);
+    for device in by_device_index {
+        let name = normalized_name(&device.name);
+        device.hardware_parameters = unmatched
+            .iter()
+            .position(|(hardware_name, _)| !name.is_empty() && *hardware_name == name)
+            .and_then(|position| unmatched.remove(position).1.parameters.clone());
+    }
+    devices
+}
+
+/// Hardware monitor readings of the devices the miner mines on, excluded devices are left out.
+/// All detected devices are matched so identical cards keep their pairing when one is excluded
+pub(crate) fn mining_devices_parameters(
+    detected_devices: &[GpuCommonInformation],
+    excluded_devices: &[u32],
+    hardware_devices: &[PublicDeviceGpuProperties],
+) -> Vec<DeviceParameters> {
+    let devices = detected_devices
+        .iter()
+        .map(|device| GpuDeviceMiningStatus {
+            device_index: device.device_id,
+            name: device.name.clone(),
+            ..Default::default()
+        })
+        .collect();
+
+    merge_hardware_parameters(devices, hardware_devices)
+        .into_iter()
+        .filter(|device| !excluded_devices.contains(&device.device_index))
+        .filter_map(|device| device.hardware_parameters)
+        .collect()
+}
+
+/// Device names differ in case and spacing between the miners and the hardware monitor
+fn normalized_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
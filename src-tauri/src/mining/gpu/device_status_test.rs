@@ -0,0 +1,148 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{
+    hardware::hardware_status_monitor::{DeviceParameters, PublicDeviceGpuProperties},
+    mining::gpu::miners::GpuCommonInformation,
+};
+
+use super::device_status::{
+    GpuDeviceMiningStatus, merge_hardware_parameters, mining_devices_parameters,
+};
+
+fn device(device_index: u32, name: &str) -> GpuDeviceMiningStatus {
+    GpuDeviceMiningStatus {
+        device_index,
+        name: name.to_string(),
+        ..Default::default()
+    }
+}
+
+fn hardware_device(name: &str, current_temperature: Option<f32>) -> PublicDeviceGpuProperties {
+    PublicDeviceGpuProperties {
+        name: name.to_string(),
+        parameters: current_temperature.map(|current_temperature| DeviceParameters {
+            usage_percentage: 100.0,
+            current_temperature,
+            max_temperature: 90.0,
+        }),
+        ..Default::default()
+    }
+}
+
+fn temperatures(devices: &[GpuDeviceMiningStatus]) -> Vec<Option<f32>> {
+    devices
+        .iter()
+        .map(|d| {
+            d.hardware_parameters
+                .as_ref()
+                .map(|p| p.current_temperature)
+        })
+        .collect()
+}
+
+#[test]
+fn hardware_readings_are_matched_by_name() {
+    let hardware = vec![
+        hardware_device("NVIDIA GeForce RTX 3080", Some(55.0)),
+        hardware_device("AMD Radeon RX 6800", Some(81.0)),
+    ];
+
+    let devices = merge_hardware_parameters(
+        vec![
+            device(0, "AMD Radeon RX 6800"),
+            device(1, "nvidia geforce  RTX 3080"),
+        ],
+        &hardware,
+    );
+
+    assert_eq!(temperatures(&devices), vec![Some(81.0), Some(55.0)]);
+}
+
+#[test]
+fn identical_cards_are_paired_in_detection_order() {
+    let hardware = vec![
+        hardware_device("RTX 3080", Some(55.0)),
+        hardware_device("RTX 3080", Some(81.0)),
+    ];
+
+    let devices = merge_hardware_parameters(
+        vec![device(1, "RTX 3080"), device(0, "RTX 3080")],
+        &hardware,
+    );
+
+    assert_eq!(temperatures(&devices), vec![Some(81.0), Some(55.0)]);
+}
+
+#[test]
+fn devices_with_names_the_monitor_does_not_know_are_skipped() {
+    // The miner skipped the integrated GPU the monitor lists first
+    let hardware = vec![
+        hardware_device("Intel UHD Graphics 630", Some(45.0)),
+        hardware_device("RTX 3080", Some(70.0)),
+    ];
+
+    let devices =
+        merge_hardware_parameters(vec![device(0, "RTX 3080 Ti"), device(1, "")], &hardware);
+
+    assert_eq!(temperatures(&devices), vec![None, None]);
+}
+
+#[test]
+fn devices_without_hardware_readings_keep_none() {
+    let hardware = vec![hardware_device("RTX 3080", None)];
+
+    let devices = merge_hardware_parameters(
+        vec![device(0, "RTX 3080"), device(3, "RTX 3080")],
+        &hardware,
+    );
+
+    assert!(devices.iter().all(|d| d.hardware_parameters.is_none()));
+}
+
+#[test]
+fn mining_devices_parameters_leave_out_excluded_devices() {
+    let hardware = vec![
+        hardware_device("RTX 3080", Some(92.0)),
+        hardware_device("RTX 3080", Some(61.0)),
+        hardware_device("RX 6800", Some(70.0)),
+    ];
+    let detected = vec![
+        GpuCommonInformation {
+            name: "RTX 3080".to_string(),
+            device_id: 0,
+        },
+        GpuCommonInformation {
+            name: "RTX 3080".to_string(),
+            device_id: 1,
+        },
+        GpuCommonInformation {
+            name: "RX 6800".to_string(),
+            device_id: 2,
+        },
+    ];
+
+    let parameters = mining_devices_parameters(&detected, &[0], &hardware);
+
+    let temperatures: Vec<f32> = parameters.iter().map(|p| p.current_temperature).collect();
+    assert_eq!(temperatures, vec![61.0, 70.0]);
+}
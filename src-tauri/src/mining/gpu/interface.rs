@@ -25,9 +25,14 @@ use std::time::Duration;
 use axum::async_trait;
 
 use crate::{
+    configs::config_mining::GpuDevicesSettings,
     mining::{
         GpuConnectionType,
-        gpu::miners::lolminer::{LolMinerGpuMiner, LolMinerGpuMinerStatusMonitor},
+        gpu::miners::{
+            GpuCommonInformation,
+            glytex::{GlytexGpuMiner, GlytexGpuMinerStatusMonitor},
+            lolminer::{LolMinerGpuMiner, LolMinerGpuMinerStatusMonitor},
+        },
     },
     process_adapter::{
         HandleUnhealthyResult, HealthStatus, ProcessAdapter, ProcessInstance, StatusMonitor,
@@ -52,21 +57,41 @@ pub trait GpuMinerInterfaceTrait: Send + Sync {
     ) -> Result<(), anyhow::Error> {
         Ok(())
     }
+    /// Per device overrides, each miner passes on the ones it has arguments for
+    async fn load_devices_settings(
+        &mut self,
+        _devices_settings: GpuDevicesSettings,
+    ) -> Result<(), anyhow::Error> {
+        Ok(())
+    }
 }
 
 pub enum GpuMinerInterface {
     LolMiner(LolMinerGpuMiner),
+    Glytex(GlytexGpuMiner),
+}
+
+impl GpuMinerInterface {
+    /// Devices found by the miner's last detection, excluded ones included
+    pub fn gpu_devices(&self) -> &[GpuCommonInformation] {
+        match self {
+            GpuMinerInterface::LolMiner(miner) => &miner.gpu_devices,
+            GpuMinerInterface::Glytex(miner) => &miner.gpu_devices,
+        }
+    }
 }
 
 impl GpuMinerInterfaceTrait for GpuMinerInterface {
     async fn load_tari_address(&mut self, tari_address: &str) -> Result<(), anyhow::Error> {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.load_tari_address(tari_address).await,
+            GpuMinerInterface::Glytex(miner) => miner.load_tari_address(tari_address).await,
         }
     }
     async fn load_worker_name(&mut self, worker_name: Option<&str>) -> Result<(), anyhow::Error> {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.load_worker_name(worker_name).await,
+            GpuMinerInterface::Glytex(miner) => miner.load_worker_name(worker_name).await,
         }
     }
     async fn load_intensity_percentage(
@@ -77,6 +102,9 @@ impl GpuMinerInterfaceTrait for GpuMinerInterface {
             GpuMinerInterface::LolMiner(miner) => {
                 miner.load_intensity_percentage(intensity_percentage).await
             }
+            GpuMinerInterface::Glytex(miner) => {
+                miner.load_intensity_percentage(intensity_percentage).await
+            }
         }
     }
     async fn load_connection_type(
@@ -85,12 +113,14 @@ impl GpuMinerInterfaceTrait for GpuMinerInterface {
     ) -> Result<(), anyhow::Error> {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.load_connection_type(connection_type).await,
+            GpuMinerInterface::Glytex(miner) => miner.load_connection_type(connection_type).await,
         }
     }
 
     async fn detect_devices(&mut self) -> Result<(), anyhow::Error> {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.detect_devices().await,
+            GpuMinerInterface::Glytex(miner) => miner.detect_devices().await,
         }
     }
 
@@ -102,6 +132,19 @@ impl GpuMinerInterfaceTrait for GpuMinerInterface {
             GpuMinerInterface::LolMiner(miner) => {
                 miner.load_excluded_devices(excluded_devices).await
             }
+            GpuMinerInterface::Glytex(miner) => miner.load_excluded_devices(excluded_devices).await,
+        }
+    }
+
+    async fn load_devices_settings(
+        &mut self,
+        devices_settings: GpuDevicesSettings,
+    ) -> Result<(), anyhow::Error> {
+        match self {
+            GpuMinerInterface::LolMiner(miner) => {
+                miner.load_devices_settings(devices_settings).await
+            }
+            GpuMinerInterface::Glytex(miner) => miner.load_devices_settings(devices_settings).await,
         }
     }
 }
@@ -109,6 +152,7 @@ impl GpuMinerInterfaceTrait for GpuMinerInterface {
 #[derive(Clone)]
 pub enum GpuMinerStatusInterface {
     LolMiner(LolMinerGpuMinerStatusMonitor),
+    Glytex(GlytexGpuMinerStatusMonitor),
 }
 
 #[async_trait]
@@ -123,6 +167,11 @@ impl StatusMonitor for GpuMinerStatusInterface {
                     .handle_unhealthy(duration_since_last_healthy_status)
                     .await
             }
+            GpuMinerStatusInterface::Glytex(monitor) => {
+                monitor
+                    .handle_unhealthy(duration_since_last_healthy_status)
+                    .await
+            }
         }
     }
     async fn check_health(&self, uptime: Duration, timeout_duration: Duration) -> HealthStatus {
@@ -130,6 +179,9 @@ impl StatusMonitor for GpuMinerStatusInterface {
             GpuMinerStatusInterface::LolMiner(monitor) => {
                 monitor.check_health(uptime, timeout_duration).await
             }
+            GpuMinerStatusInterface::Glytex(monitor) => {
+                monitor.check_health(uptime, timeout_duration).await
+            }
         }
     }
 }
@@ -153,16 +205,25 @@ impl ProcessAdapter for GpuMinerInterface {
                 binary_version_path,
                 is_first_start,
             ),
+            GpuMinerInterface::Glytex(miner) => miner.spawn_inner(
+                base_folder,
+                config_folder,
+                log_folder,
+                binary_version_path,
+                is_first_start,
+            ),
         }
     }
     fn name(&self) -> &str {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.name(),
+            GpuMinerInterface::Glytex(miner) => miner.name(),
         }
     }
     fn pid_file_name(&self) -> &str {
         match self {
             GpuMinerInterface::LolMiner(miner) => miner.pid_file_name(),
+            GpuMinerInterface::Glytex(miner) => miner.pid_file_name(),
         }
     }
 }
@@ -20,39 +20,55 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use log::{error, info};
+use log::{error, info, warn};
 use std::{collections::HashMap, sync::LazyLock};
 use tari_shutdown::Shutdown;
 use tauri::{AppHandle, Manager};
 use tauri_plugin_sentry::sentry;
 
 use tokio::{
-    select,
+    select, spawn,
     sync::{
         RwLock,
         watch::{Receiver, Sender},
     },
+    time::{Instant, interval_at, sleep, timeout_at},
 };
 
 use crate::{
     LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES, UniverseAppState,
-    binaries::Binaries,
     configs::{
-        config_mining::ConfigMining,
-        config_pools::ConfigPools,
-        pools::{PoolOrigin, gpu_pools::GpuPool},
+        config_mining::{ConfigMining, ConfigMiningContent, GpuDevicesSettings},
+        config_pools::{ConfigPools, ConfigPoolsContent},
+        pools::gpu_pools::GpuPool,
         trait_config::ConfigImpl,
     },
     events_emitter::EventsEmitter,
+    hardware::hardware_status_monitor::HardwareStatusMonitor,
     internal_wallet::InternalWallet,
     mining::{
         GpuConnectionType, MinerControlsState, MiningError,
         gpu::{
-            consts::{GpuMiner, GpuMinerStatus, GpuMinerType, MINERS_PRIORITY},
+            benchmark::{
+                GPU_BENCHMARK_SAMPLE_WINDOW, GPU_BENCHMARK_WARMUP, GpuBenchmarkGuard,
+                GpuMinerBenchmark, GpuMinersBenchmark,
+            },
+            consts::{GpuMiner, GpuMinerStatus, GpuMinerType, GpuMiningAlgorithm, MINERS_PRIORITY},
+            device_status::{
+                GpuDeviceMiningStatus, merge_hardware_parameters, mining_devices_parameters,
+            },
             interface::{GpuMinerInterface, GpuMinerInterfaceTrait},
-            miners::lolminer::LolMinerGpuMiner,
+            miners::{devices_in_use, glytex::GlytexGpuMiner, lolminer::LolMinerGpuMiner},
         },
-        pools::{PoolManagerInterfaceTrait, gpu_pool_manager::GpuPoolManager},
+        pools::{
+            PoolManagerInterfaceTrait,
+            failover::{
+                PoolHealthTracker, PoolKind, PoolSwitchReason, PoolSwitchRecord, failover_order,
+                first_reachable, higher_priority, next_in_priority, probe_pool,
+            },
+            gpu_pool_manager::GpuPoolManager,
+        },
+        thermal::apply_throttle,
     },
     node::node_adapter::BaseNodeStatus,
     process_adapter::ProcessAdapter,
@@ -86,6 +102,11 @@ pub struct GpuManager {
     intensity_percentage: Option<u32>,
     #[allow(dead_code)]
     worker_name: Option<String>,
+    // ======= Thermal control =======
+    /// Share of the selected mode intensity the miner runs with, lowered while the gpu is too hot
+    thermal_throttle_percentage: u32,
+    /// Set when the miner was stopped by the thermal throttle because it can not lower its intensity
+    is_paused_by_thermal_throttle: bool,
 }
 
 impl GpuManager {
@@ -116,6 +137,9 @@ impl GpuManager {
             pool: None,
             intensity_percentage: None,
             worker_name: None,
+            // ======= Thermal control =======
+            thermal_throttle_percentage: 100,
+            is_paused_by_thermal_throttle: false,
         }
     }
 
@@ -143,16 +167,31 @@ impl GpuManager {
         instance.node_status_channel = node_status_channel;
     }
 
-    // Loads the saved miner - always uses LolMiner since it's the only GPU miner
+    /// Selects the miner saved in the config
+    /// Falls back to the healthy miner with the highest priority if the saved one is not available
     pub async fn load_saved_miner(&mut self) -> Result<(), anyhow::Error> {
-        let selected_gpu_miner_type = GpuMinerType::LolMiner;
+        let saved_miner = ConfigMining::content().await.selected_gpu_miner().clone();
+
+        let is_saved_miner_healthy = self
+            .available_miners
+            .get(&saved_miner)
+            .is_some_and(|m| m.is_healthy);
 
-        if self.available_miners.contains_key(&selected_gpu_miner_type) {
-            info!(target: LOG_TARGET_APP_LOGIC, "Loaded gpu miner: {selected_gpu_miner_type}");
+        let selected_gpu_miner_type = if is_saved_miner_healthy {
+            saved_miner
         } else {
-            return Err(anyhow::anyhow!("No available gpu miners to load"));
-        }
+            MINERS_PRIORITY
+                .iter()
+                .find(|miner_type| {
+                    self.available_miners
+                        .get(miner_type)
+                        .is_some_and(|m| m.is_healthy)
+                })
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("No available gpu miners to load"))?
+        };
 
+        info!(target: LOG_TARGET_APP_LOGIC, "Loaded gpu miner: {selected_gpu_miner_type}");
         self.switch_miner(selected_gpu_miner_type).await?;
 
         Ok(())
@@ -273,8 +312,145 @@ impl GpuManager {
         Ok(())
     }
 
+    /// Switches mining to another pool from the priority list
+    /// Mining is restarted on the new pool if it was running before the switch
+    pub async fn switch_pool(
+        &mut self,
+        pool: GpuPool,
+        reason: PoolSwitchReason,
+    ) -> Result<(), anyhow::Error> {
+        if Self::select_pool(pool, reason).await? && self.is_running() {
+            self.stop_mining().await?;
+            self.start_mining().await?;
+        }
+        Ok(())
+    }
+
+    /// Makes `pool` the current gpu pool and records the switch, returns false if it already was
+    async fn select_pool(pool: GpuPool, reason: PoolSwitchReason) -> Result<bool, anyhow::Error> {
+        let previous_pool = ConfigPools::content().await.current_gpu_pool_type().clone();
+        if previous_pool == pool {
+            return Ok(false);
+        }
+        info!(target: LOG_TARGET_APP_LOGIC, "Switching gpu pool from {previous_pool} to {pool}: {reason:?}");
+
+        ConfigPools::update_field(ConfigPoolsContent::set_current_gpu_pool, pool.clone()).await?;
+        let pools_config = ConfigPools::content().await;
+        EventsEmitter::emit_pools_config_loaded(&pools_config).await;
+
+        let record = PoolSwitchRecord::new(
+            PoolKind::Gpu,
+            previous_pool.key_string(),
+            pool.key_string(),
+            reason,
+        );
+        GpuPoolManager::handle_pool_switch(record.clone(), pools_config.current_gpu_pool()).await;
+        EventsEmitter::emit_pool_switched(record).await;
+        Ok(true)
+    }
+
+    /// Pairs each pool with its url, pools missing from the config are skipped
+    fn pool_candidates(
+        pools_config: &ConfigPoolsContent,
+        pools: Vec<GpuPool>,
+    ) -> Vec<(GpuPool, String)> {
+        pools
+            .into_iter()
+            .filter_map(|pool| {
+                let pool_url = pools_config.gpu_pools().get(&pool)?.pool_url.clone();
+                Some((pool, pool_url))
+            })
+            .collect()
+    }
+
+    /// Probes the current pool before the miner is started and moves to the first reachable pool
+    /// in failover order when it does not respond, the miner is started on the current pool if none respond
+    async fn ensure_reachable_pool(login: &str) {
+        let pools_config = ConfigPools::content().await;
+        let failover = pools_config.pool_failover();
+        if !failover.probe_before_start
+            || probe_pool(
+                &pools_config.current_gpu_pool().pool_url,
+                login,
+                PoolKind::Gpu,
+            )
+            .await
+            || !failover.enabled
+        {
+            return;
+        }
+
+        let candidates = Self::pool_candidates(
+            &pools_config,
+            failover_order(
+                pools_config.gpu_pool_priority(),
+                pools_config.current_gpu_pool_type(),
+            ),
+        );
+        match first_reachable(candidates, login, PoolKind::Gpu).await {
+            Some(pool) => {
+                if let Err(e) = Self::select_pool(pool, PoolSwitchReason::Failover).await {
+                    error!(target: LOG_TARGET_APP_LOGIC, "Failed to select reachable gpu pool: {e}");
+                }
+            }
+            None => {
+                warn!(target: LOG_TARGET_APP_LOGIC, "No gpu pool responded, starting on the current one");
+            }
+        }
+    }
+
+    /// Moves to the next pool in the priority list after the current one stopped working
+    async fn fail_over_pool() {
+        let pools_config = ConfigPools::content().await;
+        let Some(next_pool) = next_in_priority(
+            pools_config.gpu_pool_priority(),
+            pools_config.current_gpu_pool_type(),
+        ) else {
+            warn!(target: LOG_TARGET_APP_LOGIC, "No other gpu pool to fail over to");
+            return;
+        };
+
+        if let Err(e) = Self::write()
+            .await
+            .switch_pool(next_pool, PoolSwitchReason::Failover)
+            .await
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to fail over gpu pool: {e}");
+        }
+    }
+
+    /// Returns to a higher priority pool once it is reachable again
+    async fn fail_back_pool() {
+        let pools_config = ConfigPools::content().await;
+        let candidates = Self::pool_candidates(
+            &pools_config,
+            higher_priority(
+                pools_config.gpu_pool_priority(),
+                pools_config.current_gpu_pool_type(),
+            ),
+        );
+        let login = InternalWallet::tari_address().await.to_base58();
+
+        if let Some(pool) = first_reachable(candidates, &login, PoolKind::Gpu).await
+            && let Err(e) = Self::write()
+                .await
+                .switch_pool(pool, PoolSwitchReason::Failback)
+                .await
+        {
+            error!(target: LOG_TARGET_APP_LOGIC, "Failed to fail back gpu pool: {e}");
+        }
+    }
+
+    /// Starts the selected miner. While the thermal throttle is active a miner that can not be
+    /// slowed down stays paused instead, and is started once the gpu cools down
     #[allow(clippy::too_many_arguments)]
     pub async fn start_mining(&mut self) -> Result<(), anyhow::Error> {
+        if self.thermal_throttle_percentage < 100 && !self.can_throttle().await {
+            info!(target: LOG_TARGET_APP_LOGIC, "Gpu miner {} can not lower its intensity or power limit, keeping it paused until the gpu cools down", self.selected_miner);
+            self.is_paused_by_thermal_throttle = true;
+            EventsEmitter::emit_update_gpu_miner_state(MinerControlsState::ThermalPaused).await;
+            return Ok(());
+        }
         info!(target: LOG_TARGET_APP_LOGIC, "Starting gpu miner: {}", self.selected_miner);
         info!(target: LOG_TARGET_APP_LOGIC, "Adapter miner type: {}", self.process_watcher.adapter.name());
 
@@ -341,11 +517,10 @@ impl GpuManager {
                     .await;
 
                 let tari_address = InternalWallet::tari_address().await;
-                let gpu_usage_percentage = ConfigMining::content()
-                    .await
-                    .get_selected_gpu_usage_percentage();
+                let (gpu_usage_percentage, devices_settings) = self.load_intensity_settings().await;
 
                 if *ConfigPools::content().await.gpu_pool_enabled() {
+                    Self::ensure_reachable_pool(&tari_address.to_base58()).await;
                     self.handle_pool_connection_load().await?;
                 } else {
                     let app_state = app_handle.state::<UniverseAppState>();
@@ -353,20 +528,12 @@ impl GpuManager {
                     self.handle_node_connection_load(grpc_node_address).await?;
                 }
 
-                let binary = match self.selected_miner {
-                    GpuMinerType::LolMiner => Binaries::LolMiner,
-                };
-
-                // Worker name format depends on the pool
-                // LuckyPool: .Tari-Universe
-                // Kryptex: /Tari-Universe
-                // SupportXTM: Not specified so we use None
-                let worker_name = match ConfigPools::content().await.current_gpu_pool().pool_origin
-                {
-                    PoolOrigin::LuckyPool => Some(".Tari-universe"),
-                    PoolOrigin::SupportXTM => None,
-                    PoolOrigin::Kryptex => Some("/Tari-universe"),
-                };
+                let binary = self.selected_miner.binary();
+
+                let worker_name = ConfigPools::content()
+                    .await
+                    .current_gpu_pool()
+                    .worker_name();
 
                 let excluded_devices = ConfigMining::content().await.get_excluded_devices();
 
@@ -376,7 +543,7 @@ impl GpuManager {
                     .await?;
                 self.process_watcher
                     .adapter
-                    .load_worker_name(worker_name)
+                    .load_worker_name(worker_name.as_deref())
                     .await?;
                 self.process_watcher
                     .adapter
@@ -386,6 +553,10 @@ impl GpuManager {
                     .adapter
                     .load_excluded_devices(excluded_devices)
                     .await?;
+                self.process_watcher
+                    .adapter
+                    .load_devices_settings(devices_settings)
+                    .await?;
 
                 info!(target: LOG_TARGET_APP_LOGIC, "Starting gpu miner process watcher with binary: {:?}", binary);
 
@@ -418,12 +589,105 @@ impl GpuManager {
         Ok(())
     }
 
+    /// Gpu usage of the selected mode and the per device settings, lowered by the thermal throttle
+    /// Both the intensities and the power limits are lowered, each miner applies what it supports
+    async fn load_intensity_settings(&self) -> (u32, GpuDevicesSettings) {
+        let mining_config = ConfigMining::content().await;
+        let gpu_usage_percentage = mining_config.get_selected_gpu_usage_percentage();
+        let mut devices_settings = mining_config.gpu_devices_settings().clone();
+        if self.thermal_throttle_percentage >= 100 {
+            return (gpu_usage_percentage, devices_settings);
+        }
+
+        let throttled_percentage =
+            apply_throttle(gpu_usage_percentage, self.thermal_throttle_percentage);
+        info!(target: LOG_TARGET_APP_LOGIC, "Thermal throttle lowers gpu usage from {gpu_usage_percentage}% to {throttled_percentage}%");
+        devices_settings.throttle_intensity(self.thermal_throttle_percentage);
+        devices_settings.throttle_power_limits(self.thermal_throttle_percentage);
+        (throttled_percentage, devices_settings)
+    }
+
+    /// Runs the miner with the given share of the selected mode intensity, 100 removes the throttle
+    /// A running miner is restarted to pick up the new intensity, or the new power limits for miners
+    /// without intensity support. Miners that can lower neither are stopped while throttled, which
+    /// is reported as `ThermalPaused`, and started again once the throttle is removed
+    pub async fn apply_thermal_throttle(
+        &mut self,
+        throttle_percentage: u32,
+    ) -> Result<(), anyhow::Error> {
+        self.thermal_throttle_percentage = throttle_percentage;
+        let is_throttled = throttle_percentage < 100;
+
+        // Power limits may have been set while paused, so resume before checking how to throttle
+        if !is_throttled && self.is_paused_by_thermal_throttle {
+            info!(target: LOG_TARGET_APP_LOGIC, "Resuming gpu miner {} paused by the thermal throttle", self.selected_miner);
+            self.is_paused_by_thermal_throttle = false;
+            return self.start_mining().await;
+        }
+
+        if !self.can_throttle().await {
+            if is_throttled && self.is_running() {
+                info!(target: LOG_TARGET_APP_LOGIC, "Gpu miner {} can not lower its intensity or power limit, pausing it until the gpu cools down", self.selected_miner);
+                self.stop_mining().await?;
+                self.is_paused_by_thermal_throttle = true;
+                EventsEmitter::emit_update_gpu_miner_state(MinerControlsState::ThermalPaused).await;
+            } else {
+                info!(target: LOG_TARGET_APP_LOGIC, "Gpu miner {} keeps its current state for the thermal throttle", self.selected_miner);
+            }
+            return Ok(());
+        }
+
+        if self.is_running() {
+            self.stop_mining().await?;
+            self.start_mining().await?;
+        }
+        Ok(())
+    }
+
+    /// Whether the selected miner can be slowed down, through its intensity or through the power
+    /// limits set for the devices it mines on. Each mining device needs a power limit, otherwise
+    /// the hottest one may be left running at full power
+    async fn can_throttle(&self) -> bool {
+        if self.selected_miner.is_intensity_supported() {
+            return true;
+        }
+        if !self.selected_miner.is_device_limits_supported() {
+            return false;
+        }
+        let mining_config = ConfigMining::content().await;
+        let device_ids = devices_in_use(
+            self.process_watcher.adapter.gpu_devices(),
+            &mining_config.get_excluded_devices(),
+        );
+        mining_config
+            .gpu_devices_settings()
+            .have_power_limits(&device_ids)
+    }
+
+    /// Temperatures the hardware monitor reports for the devices the selected miner mines on
+    pub async fn mining_devices_temperatures(&self) -> Vec<f32> {
+        let excluded_devices = ConfigMining::content().await.get_excluded_devices();
+        let hardware_devices = HardwareStatusMonitor::current()
+            .get_gpu_public_properties()
+            .await
+            .unwrap_or_default();
+        mining_devices_parameters(
+            self.process_watcher.adapter.gpu_devices(),
+            &excluded_devices,
+            &hardware_devices,
+        )
+        .into_iter()
+        .map(|parameters| parameters.current_temperature)
+        .collect()
+    }
+
     pub fn is_running(&self) -> bool {
         self.process_watcher.is_running()
     }
 
     pub async fn stop_mining(&mut self) -> Result<(), anyhow::Error> {
         info!(target: LOG_TARGET_APP_LOGIC, "Stopping gpu miner");
+        self.is_paused_by_thermal_throttle = false;
         {
             let _res =
                 self.gpu_external_status_channel
@@ -470,6 +734,148 @@ impl GpuManager {
         Ok(())
     }
 
+    /// Switches the miner and persists the choice so it is used on the next start
+    /// Mining is restarted with the new miner if it was running before the switch
+    pub async fn select_miner(&mut self, new_miner: GpuMinerType) -> Result<(), anyhow::Error> {
+        let was_running = self.is_running();
+        self.switch_miner(new_miner.clone()).await?;
+        ConfigMining::update_field(ConfigMiningContent::set_selected_gpu_miner, new_miner).await?;
+
+        if was_running {
+            self.start_mining().await?;
+        }
+        Ok(())
+    }
+
+    /// Runs each healthy miner for a bounded time on the selected pool, or on the local node when pool
+    /// mining is off, and stores the results. With `auto_select` the fastest stable miner of the same
+    /// algorithm as the previously selected one is selected, otherwise the previously selected miner
+    /// is restored. Scheduled events and thermal throttling leave the gpu miner alone until it finishes
+    pub async fn benchmark_gpu_miners(
+        auto_select: bool,
+    ) -> Result<GpuMinersBenchmark, anyhow::Error> {
+        let Some(_benchmark_guard) = GpuBenchmarkGuard::acquire() else {
+            return Err(anyhow::anyhow!("GPU miner benchmark is already running"));
+        };
+        Self::benchmark_gpu_miners_inner(auto_select).await
+    }
+
+    async fn benchmark_gpu_miners_inner(
+        auto_select: bool,
+    ) -> Result<GpuMinersBenchmark, anyhow::Error> {
+        let (was_running, previous_miner, miners) = {
+            let manager = Self::read().await;
+            let miners: Vec<GpuMinerType> = MINERS_PRIORITY
+                .iter()
+                .filter(|miner_type| {
+                    manager
+                        .available_miners
+                        .get(miner_type)
+                        .is_some_and(|m| m.is_healthy)
+                })
+                .cloned()
+                .collect();
+            (manager.is_running(), manager.selected_miner.clone(), miners)
+        };
+        if miners.is_empty() {
+            return Err(anyhow::anyhow!("No healthy gpu miners to benchmark"));
+        }
+
+        let benchmark = Self::measure_miners(miners, &previous_miner.main_algorithm()).await;
+        let stored = ConfigMining::update_field(
+            ConfigMiningContent::set_gpu_miner_benchmark,
+            Some(benchmark.clone()),
+        )
+        .await;
+        let recommended_miner = benchmark
+            .recommended_miner
+            .clone()
+            .filter(|_| auto_select && stored.is_ok());
+        // Whatever happened above, put back a miner and the running state from before
+        let restored =
+            Self::restore_after_benchmark(recommended_miner, previous_miner, was_running).await;
+        EventsEmitter::emit_mining_config_loaded(&ConfigMining::content().await).await;
+
+        stored?;
+        restored?;
+        Ok(benchmark)
+    }
+
+    /// Measures each miner in turn, a miner that fails to run is recorded as failed. Only miners of
+    /// `algorithm` can be recommended
+    async fn measure_miners(
+        miners: Vec<GpuMinerType>,
+        algorithm: &GpuMiningAlgorithm,
+    ) -> GpuMinersBenchmark {
+        let mut results = vec![];
+        for miner_type in miners {
+            let result = match Self::measure_miner(miner_type.clone()).await {
+                Ok(samples) => GpuMinerBenchmark::from_samples(miner_type, &samples),
+                Err(e) => {
+                    warn!(target: LOG_TARGET_APP_LOGIC, "GPU benchmark | {miner_type} failed: {e}");
+                    GpuMinerBenchmark::failed(miner_type, e.to_string())
+                }
+            };
+            info!(target: LOG_TARGET_APP_LOGIC, "GPU benchmark | {result:?}");
+            results.push(result);
+        }
+        GpuMinersBenchmark::new(results, algorithm)
+    }
+
+    /// Selects the recommended miner, or switches back to the previous one when there is none, and
+    /// starts it again if mining was running before the benchmark
+    async fn restore_after_benchmark(
+        recommended_miner: Option<GpuMinerType>,
+        previous_miner: GpuMinerType,
+        was_running: bool,
+    ) -> Result<(), anyhow::Error> {
+        let mut manager = Self::write().await;
+        if manager.is_running() {
+            manager.stop_mining().await?;
+        }
+        match recommended_miner {
+            Some(recommended_miner) => {
+                info!(target: LOG_TARGET_APP_LOGIC, "Selecting benchmarked gpu miner: {recommended_miner}");
+                manager.select_miner(recommended_miner).await?;
+            }
+            None => manager.switch_miner(previous_miner).await?,
+        }
+        if was_running {
+            manager.start_mining().await?;
+        }
+        Ok(())
+    }
+
+    /// Starts the miner and collects its statuses over the sample window
+    async fn measure_miner(miner_type: GpuMinerType) -> Result<Vec<GpuMinerStatus>, anyhow::Error> {
+        let mut status_receiver = {
+            let mut manager = Self::write().await;
+            manager.switch_miner(miner_type.clone()).await?;
+            manager.start_mining().await?;
+            // Loading the connection falls back to another miner when this one does not support it
+            if manager.selected_miner != miner_type {
+                return Err(anyhow::anyhow!(
+                    "{miner_type} does not support the selected pool or node connection"
+                ));
+            }
+            manager.gpu_internal_status_channel.subscribe()
+        };
+
+        sleep(GPU_BENCHMARK_WARMUP).await;
+        status_receiver.mark_unchanged();
+
+        let mut samples = Vec::new();
+        let window_end = Instant::now() + GPU_BENCHMARK_SAMPLE_WINDOW;
+        while let Ok(Ok(())) = timeout_at(window_end, status_receiver.changed()).await {
+            samples.push(status_receiver.borrow_and_update().clone());
+        }
+
+        if !Self::read().await.is_running() {
+            return Err(anyhow::anyhow!("{miner_type} stopped during the benchmark"));
+        }
+        Ok(samples)
+    }
+
     /// Will need to mark current seleceted miner as unhealthy and switch to another one based on priority
     /// If no other miners are available, we will just mark the current one as unhealthy and emit the status
     pub async fn handle_unhealthy_miner(&mut self) -> Result<(), anyhow::Error> {
@@ -590,6 +996,9 @@ impl GpuManager {
             GpuMinerType::LolMiner => GpuMinerInterface::LolMiner(LolMinerGpuMiner::new(
                 self.gpu_internal_status_channel.clone(),
             )),
+            GpuMinerType::Glytex => GpuMinerInterface::Glytex(GlytexGpuMiner::new(
+                self.gpu_internal_status_channel.clone(),
+            )),
         }
     }
 
@@ -599,6 +1008,16 @@ impl GpuManager {
         let connection_type = self.connection_type.clone();
         let mut last_known_status = self.gpu_internal_status_channel.borrow().clone();
 
+        // Failover only applies to pool mining, the settings are picked up again on each miner start
+        let pools_config = ConfigPools::content().await;
+        let failover_settings = pools_config.pool_failover().clone();
+        let is_failover_enabled = *pools_config.gpu_pool_enabled() && failover_settings.enabled;
+        let mut pool_health = PoolHealthTracker::new(Instant::now());
+        let mut failback_interval = interval_at(
+            Instant::now() + failover_settings.failback_probe_interval(),
+            failover_settings.failback_probe_interval(),
+        );
+
         let mut internal_shutdown_signal = self.status_thread_shutdown.to_signal();
         let mut global_shutdown_signal =
             TasksTrackers::current().gpu_mining_phase.get_signal().await;
@@ -615,6 +1034,7 @@ impl GpuManager {
                         EventsEmitter::emit_gpu_mining_update(GpuMinerStatus::default_with_algorithm(
                             last_known_status.algorithm.clone(),
                         )).await;
+                        EventsEmitter::emit_gpu_devices_mining_update(vec![]).await;
                         SystemTrayManager::send_event(SystemTrayEvents::GpuHashrate(0.0)).await;
                         break;
                     },
@@ -622,9 +1042,25 @@ impl GpuManager {
                         info!(target: LOG_TARGET_STATUSES, "Shutting down gpu miner status updates");
                         break;
                     },
+                    _ = failback_interval.tick(), if is_failover_enabled => {
+                        spawn(Self::fail_back_pool());
+                    },
                     updated_status = gpu_internal_status_reciever.changed() => {
                         if updated_status.is_ok() {
                             let status = gpu_internal_status_reciever.borrow().clone();
+                            if is_failover_enabled
+                                && status.is_mining
+                                && pool_health.observe(
+                                    Instant::now(),
+                                    status.is_connected,
+                                    status.accepted_shares,
+                                    failover_settings.failover_after(),
+                                )
+                            {
+                                warn!(target: LOG_TARGET_STATUSES, "Gpu pool has not been working for {:?}, failing over", failover_settings.failover_after());
+                                pool_health = PoolHealthTracker::new(Instant::now());
+                                spawn(Self::fail_over_pool());
+                            }
                             let paresd_status = match connection_type {
                                 GpuConnectionType::Node { .. } => status.clone(),
                                 GpuConnectionType::Pool { .. } => Self::handle_pool_connection_type_status_change(status.clone()).await,
@@ -632,6 +1068,9 @@ impl GpuManager {
                             let _res = gpu_external_status_channel.send(paresd_status.clone());
                             last_known_status = paresd_status.clone();
                             EventsEmitter::emit_gpu_mining_update(paresd_status.clone()).await;
+                            if !paresd_status.devices.is_empty() {
+                                Self::emit_devices_mining_status(paresd_status.devices.clone()).await;
+                            }
 
                             info!(target: LOG_TARGET_STATUSES, "Gpu hashrate: {}", paresd_status.hash_rate);
                             SystemTrayManager::send_event(SystemTrayEvents::GpuHashrate(paresd_status.hash_rate)).await;
@@ -644,6 +1083,25 @@ impl GpuManager {
         });
     }
 
+    /// Publishes the per device status of the miner together with the hardware monitor readings
+    async fn emit_devices_mining_status(devices: Vec<GpuDeviceMiningStatus>) {
+        let hardware_devices = HardwareStatusMonitor::current()
+            .get_gpu_public_properties()
+            .await
+            .unwrap_or_default();
+        let devices = merge_hardware_parameters(devices, &hardware_devices);
+
+        // A card that stays idle while the others hash is the one failing in a multi gpu rig
+        let is_any_device_hashing = devices.iter().any(|d| d.hash_rate > 0.0);
+        for device in devices
+            .iter()
+            .filter(|d| is_any_device_hashing && d.hash_rate <= 0.0)
+        {
+            warn!(target: LOG_TARGET_STATUSES, "Gpu device {} ({}) is not hashing", device.device_index, device.name);
+        }
+        EventsEmitter::emit_gpu_devices_mining_update(devices).await;
+    }
+
     async fn handle_pool_connection_type_status_change(
         gpu_status: GpuMinerStatus,
     ) -> GpuMinerStatus {
@@ -0,0 +1,469 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+
+use axum::async_trait;
+use log::{info, warn};
+use serde::Deserialize;
+use tari_shutdown::Shutdown;
+use tokio::sync::watch::Sender;
+
+#[cfg(target_os = "windows")]
+use crate::utils::windows_setup_utils::add_firewall_rule;
+
+use crate::{
+    APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES,
+    binaries::{Binaries, BinaryResolver},
+    configs::config_mining::GpuDevicesSettings,
+    mining::{
+        GpuConnectionType,
+        gpu::{
+            consts::{GpuMinerStatus, GpuMinerType},
+            interface::{GpuMinerInterfaceTrait, GpuMinerStatusInterface},
+            manager::GpuManager,
+            miners::{
+                GpuCommonInformation, devices_in_use, join_device_values, store_detected_devices,
+            },
+        },
+    },
+    port_allocator::PortAllocator,
+    process_adapter::{
+        HandleUnhealthyResult, HealthStatus, ProcessAdapter, ProcessInstance, ProcessStartupSpec,
+        StatusMonitor,
+    },
+    process_utils::launch_child_process,
+};
+
+const GPU_STATUS_FILE_NAME: &str = "gpu_status.json";
+const COINBASE_EXTRA: &str = "tari_universe";
+/// Glytex ships one build per GPU api, only the OpenCL builds are downloaded
+const GLYTEX_ENGINE: &str = "OpenCL";
+
+pub struct GlytexGpuMiner {
+    pub tari_address: Option<String>,
+    pub intensity_percentage: Option<u32>,
+    pub worker_name: Option<String>,
+    pub connection_type: Option<GpuConnectionType>,
+    pub gpu_status_sender: Sender<GpuMinerStatus>,
+    pub gpu_devices: Vec<GpuCommonInformation>,
+    pub excluded_devices: Vec<u32>,
+    pub devices_settings: GpuDevicesSettings,
+}
+
+impl GlytexGpuMiner {
+    pub fn new(gpu_status_sender: Sender<GpuMinerStatus>) -> Self {
+        Self {
+            tari_address: None,
+            intensity_percentage: None,
+            worker_name: None,
+            connection_type: None,
+            gpu_status_sender,
+            gpu_devices: vec![],
+            excluded_devices: vec![],
+            devices_settings: GpuDevicesSettings::new(),
+        }
+    }
+
+    fn gpu_status_file(config_folder: &Path) -> PathBuf {
+        config_folder.join("glytex").join(GPU_STATUS_FILE_NAME)
+    }
+
+    /// Glytex takes a single percentage for all devices or one value per device in use.
+    /// Devices without an override run at the intensity of the selected mining mode
+    fn gpu_percentage_arg(&self) -> Option<String> {
+        let device_ids = devices_in_use(&self.gpu_devices, &self.excluded_devices);
+        match self
+            .devices_settings
+            .per_device_values(&device_ids, |settings| settings.intensity_percentage)
+        {
+            Some(values) => {
+                let values: Vec<Option<u32>> = values
+                    .into_iter()
+                    .map(|value| value.or(self.intensity_percentage).map(|v| v.clamp(1, 100)))
+                    .collect();
+                Some(join_device_values(&values, "100"))
+            }
+            None => self
+                .intensity_percentage
+                .map(|intensity_percentage| intensity_percentage.clamp(1, 100).to_string()),
+        }
+    }
+
+    /// Arguments selecting the node or pool the miner works for
+    fn connection_args(&self) -> Result<Vec<String>, anyhow::Error> {
+        let tari_address = self.tari_address.as_ref().ok_or_else(|| {
+            anyhow::anyhow!("Tari address must be set before starting the GlytexGpuMiner")
+        })?;
+
+        match &self.connection_type {
+            Some(GpuConnectionType::Node { node_grpc_address }) => Ok(vec![
+                "--tari-address".to_string(),
+                tari_address.clone(),
+                "--tari-node-url".to_string(),
+                node_grpc_address.clone(),
+            ]),
+            Some(GpuConnectionType::Pool { pool_url }) => {
+                let user = match &self.worker_name {
+                    Some(worker_name) => format!("{tari_address}{worker_name}"),
+                    None => tari_address.clone(),
+                };
+                Ok(vec![
+                    "--tari-address".to_string(),
+                    tari_address.clone(),
+                    "--pool".to_string(),
+                    pool_url.clone(),
+                    "--pool-user".to_string(),
+                    user,
+                ])
+            }
+            None => Err(anyhow::anyhow!(
+                "Connection type must be set before starting the GlytexGpuMiner"
+            )),
+        }
+    }
+}
+
+impl GpuMinerInterfaceTrait for GlytexGpuMiner {
+    async fn load_tari_address(&mut self, tari_address: &str) -> Result<(), anyhow::Error> {
+        self.tari_address = Some(tari_address.to_string());
+        Ok(())
+    }
+    async fn load_worker_name(&mut self, worker_name: Option<&str>) -> Result<(), anyhow::Error> {
+        self.worker_name = worker_name.map(|name| name.to_string());
+        Ok(())
+    }
+    async fn load_intensity_percentage(
+        &mut self,
+        intensity_percentage: u32,
+    ) -> Result<(), anyhow::Error> {
+        self.intensity_percentage = Some(intensity_percentage);
+        Ok(())
+    }
+    async fn load_connection_type(
+        &mut self,
+        connection_type: GpuConnectionType,
+    ) -> Result<(), anyhow::Error> {
+        self.connection_type = Some(connection_type);
+        Ok(())
+    }
+
+    async fn load_excluded_devices(
+        &mut self,
+        excluded_devices: Vec<u32>,
+    ) -> Result<(), anyhow::Error> {
+        self.excluded_devices = excluded_devices;
+        Ok(())
+    }
+
+    async fn load_devices_settings(
+        &mut self,
+        devices_settings: GpuDevicesSettings,
+    ) -> Result<(), anyhow::Error> {
+        self.devices_settings = devices_settings;
+        Ok(())
+    }
+
+    /// Glytex writes the devices it can use to the gpu status file when started in detect mode
+    async fn detect_devices(&mut self) -> Result<(), anyhow::Error> {
+        let config_path =
+            dirs::config_dir().ok_or_else(|| anyhow::anyhow!("Failed to get config directory"))?;
+        let config_dir = config_path.join(APPLICATION_FOLDER_ID);
+        let gpu_status_file = Self::gpu_status_file(&config_dir);
+        if let Some(parent) = gpu_status_file.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        let gpu_miner_binary = BinaryResolver::current()
+            .get_binary_path(Binaries::Glytex)
+            .await?;
+
+        let args = vec![
+            "--detect".to_string(),
+            "true".to_string(),
+            "--engine".to_string(),
+            GLYTEX_ENGINE.to_string(),
+            "--gpu-status-file".to_string(),
+            gpu_status_file.to_string_lossy().to_string(),
+        ];
+
+        crate::download_utils::set_permissions(&gpu_miner_binary).await?;
+        let result = launch_child_process(&gpu_miner_binary, &config_dir, None, &args, true)?;
+        let output = result.wait_with_output().await?;
+        if !output.status.success() {
+            warn!(target: LOG_TARGET_APP_LOGIC, "Glytex device detection exited with: {}", output.status);
+        }
+
+        let gpu_status = tokio::fs::read_to_string(&gpu_status_file).await?;
+        let gpu_devices = parse_gpu_status_file(&gpu_status)?;
+        if gpu_devices.is_empty() {
+            return Err(anyhow::anyhow!("No supported GPU devices found"));
+        }
+        for device in &gpu_devices {
+            info!(target: LOG_TARGET_APP_LOGIC, "Glytex detected device: {} ({})", device.name, device.device_id);
+        }
+
+        self.gpu_devices = gpu_devices;
+        store_detected_devices(self.gpu_devices.clone()).await
+    }
+}
+
+impl ProcessAdapter for GlytexGpuMiner {
+    type ProcessInstance = ProcessInstance;
+    type StatusMonitor = GpuMinerStatusInterface;
+
+    fn spawn_inner(
+        &self,
+        base_folder: PathBuf,
+        config_folder: PathBuf,
+        log_folder: PathBuf,
+        binary_version_path: PathBuf,
+        _is_first_start: bool,
+    ) -> Result<(Self::ProcessInstance, Self::StatusMonitor), anyhow::Error> {
+        let inner_shutdown = Shutdown::new();
+        let http_server_port = PortAllocator::new().assign_port_with_fallback();
+
+        let mut args = self.connection_args()?;
+        args.extend([
+            "--http-server-port".to_string(),
+            http_server_port.to_string(),
+            "--engine".to_string(),
+            GLYTEX_ENGINE.to_string(),
+            "--coinbase-extra".to_string(),
+            COINBASE_EXTRA.to_string(),
+            "--gpu-status-file".to_string(),
+            Self::gpu_status_file(&config_folder)
+                .to_string_lossy()
+                .to_string(),
+            "--log-dir".to_string(),
+            log_folder.join("glytex").to_string_lossy().to_string(),
+        ]);
+
+        if let Some(gpu_percentage) = self.gpu_percentage_arg() {
+            args.push("--gpu-percentage".to_string());
+            args.push(gpu_percentage);
+        }
+
+        if !self.excluded_devices.is_empty() && !self.gpu_devices.is_empty() {
+            let is_any_device_used = self
+                .gpu_devices
+                .iter()
+                .any(|d| !self.excluded_devices.contains(&d.device_id));
+            if !is_any_device_used {
+                return Err(crate::mining::MiningError::AllDevicesExcluded.into());
+            }
+            let excluded_devices: Vec<String> = self
+                .excluded_devices
+                .iter()
+                .map(|id| id.to_string())
+                .collect();
+            args.push("--excluded-gpus".to_string());
+            args.push(excluded_devices.join(","));
+        }
+
+        #[cfg(target_os = "windows")]
+        add_firewall_rule("glytex.exe".to_string(), binary_version_path.clone())?;
+
+        info!(target: LOG_TARGET_APP_LOGIC, "Glytex binary file path: {}", binary_version_path.display());
+
+        Ok((
+            ProcessInstance {
+                shutdown: inner_shutdown.clone(),
+                startup_spec: ProcessStartupSpec {
+                    file_path: binary_version_path,
+                    envs: None,
+                    args,
+                    data_dir: base_folder,
+                    pid_file_name: self.pid_file_name().to_string(),
+                    name: self.name().to_string(),
+                },
+                handle: None,
+            },
+            GpuMinerStatusInterface::Glytex(GlytexGpuMinerStatusMonitor {
+                http_server_port,
+                gpu_status_sender: self.gpu_status_sender.clone(),
+            }),
+        ))
+    }
+
+    fn name(&self) -> &str {
+        "glytex"
+    }
+
+    fn pid_file_name(&self) -> &str {
+        "glytex_pid"
+    }
+}
+
+#[derive(Clone)]
+pub struct GlytexGpuMinerStatusMonitor {
+    http_server_port: u16,
+    gpu_status_sender: Sender<GpuMinerStatus>,
+}
+
+// Fallback to other miner is triggered at most once per session, see the lolMiner monitor
+static WAS_FALLBACK_TO_OTHER_MINER_TRIGGERED: AtomicBool = AtomicBool::new(false);
+
+#[async_trait]
+impl StatusMonitor for GlytexGpuMinerStatusMonitor {
+    async fn handle_unhealthy(
+        &self,
+        duration_since_last_healthy_status: Duration,
+    ) -> Result<HandleUnhealthyResult, anyhow::Error> {
+        info!(target: LOG_TARGET_STATUSES, "Handling unhealthy status for Glytex | Duration since last healthy status: {:?}", duration_since_last_healthy_status.as_secs());
+        if duration_since_last_healthy_status.as_secs() <= 60 * 3
+            || WAS_FALLBACK_TO_OTHER_MINER_TRIGGERED.load(Ordering::SeqCst)
+        {
+            return Ok(HandleUnhealthyResult::Continue);
+        }
+
+        match GpuManager::write().await.handle_unhealthy_miner().await {
+            Ok(_) => {
+                WAS_FALLBACK_TO_OTHER_MINER_TRIGGERED.store(true, Ordering::SeqCst);
+                Ok(HandleUnhealthyResult::Stop)
+            }
+            Err(error) => {
+                warn!(target: LOG_TARGET_STATUSES, "Glytex: Failed to handle unhealthy miner: {error} | Continuing to monitor.");
+                Ok(HandleUnhealthyResult::Continue)
+            }
+        }
+    }
+
+    async fn check_health(&self, _uptime: Duration, timeout_duration: Duration) -> HealthStatus {
+        let status = match tokio::time::timeout(timeout_duration, self.status()).await {
+            Ok(Ok(status)) => status,
+            Ok(Err(e)) => {
+                warn!(target: LOG_TARGET_STATUSES, "Error in getting Glytex status: {e}");
+                let _ = self.gpu_status_sender.send(default_status());
+                return HealthStatus::Unhealthy;
+            }
+            Err(_) => {
+                warn!(target: LOG_TARGET_STATUSES, "Timeout error in Glytex check_health");
+                let _ = self.gpu_status_sender.send(default_status());
+                return HealthStatus::Unhealthy;
+            }
+        };
+
+        let _ = self.gpu_status_sender.send(status.clone());
+        if status.hash_rate > 0.0 {
+            if !GpuManager::read().await.is_current_miner_healthy().await {
+                info!(target: LOG_TARGET_STATUSES, "Marking current miner as healthy again");
+                let _unused = GpuManager::write().await.handle_healthy_miner().await;
+            }
+            HealthStatus::Healthy
+        } else {
+            HealthStatus::Unhealthy
+        }
+    }
+}
+
+impl GlytexGpuMinerStatusMonitor {
+    pub async fn status(&self) -> Result<GpuMinerStatus, anyhow::Error> {
+        let url = format!("http://127.0.0.1:{}/stats", self.http_server_port);
+        let response = match reqwest::Client::new().get(url).send().await {
+            Ok(response) => response,
+            Err(e) => {
+                warn!(target: LOG_TARGET_STATUSES, "Error in getting response from Glytex status: {e}");
+                return Ok(default_status());
+            }
+        };
+        let text = response.text().await?;
+        match serde_json::from_str::<GlytexHttpApiStatus>(&text) {
+            Ok(body) => Ok(body.into_status()),
+            Err(e) => {
+                warn!(target: LOG_TARGET_STATUSES, "Error decoding body from Glytex status: {e}");
+                Ok(default_status())
+            }
+        }
+    }
+}
+
+fn default_status() -> GpuMinerStatus {
+    GpuMinerStatus::default_with_algorithm(GpuMinerType::Glytex.main_algorithm())
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct GlytexHttpApiStatus {
+    hashrate_per_second: u64,
+    #[serde(default)]
+    accepted_blocks: u64,
+}
+
+impl GlytexHttpApiStatus {
+    pub(crate) fn into_status(self) -> GpuMinerStatus {
+        let hash_rate = self.hashrate_per_second as f64;
+        GpuMinerStatus {
+            is_mining: true,
+            hash_rate,
+            estimated_earnings: 0,
+            algorithm: GpuMinerType::Glytex.main_algorithm(),
+            // Glytex reports no connection state, it only hashes while it has a template or job
+            is_connected: hash_rate > 0.0,
+            accepted_shares: self.accepted_blocks,
+            devices: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GlytexGpuStatusFile {
+    gpu_devices: Vec<GlytexGpuDevice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlytexGpuDevice {
+    device_name: String,
+    device_index: u32,
+    #[serde(default)]
+    settings: GlytexGpuDeviceSettings,
+}
+
+#[derive(Debug, Deserialize)]
+struct GlytexGpuDeviceSettings {
+    is_available: bool,
+}
+
+impl Default for GlytexGpuDeviceSettings {
+    fn default() -> Self {
+        Self { is_available: true }
+    }
+}
+
+/// Reads the devices Glytex is able to mine on out of its gpu status file
+pub(crate) fn parse_gpu_status_file(
+    content: &str,
+) -> Result<Vec<GpuCommonInformation>, anyhow::Error> {
+    let status_file: GlytexGpuStatusFile = serde_json::from_str(content)?;
+    Ok(status_file
+        .gpu_devices
+        .into_iter()
+        .filter(|device| device.settings.is_available)
+        .map(|device| GpuCommonInformation {
+            name: device.device_name.trim().to_string(),
+            device_id: device.device_index,
+        })
+        .collect())
+}
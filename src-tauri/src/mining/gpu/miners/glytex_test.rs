@@ -0,0 +1,75 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::mining::gpu::consts::GpuMiningAlgorithm;
+
+use super::glytex::{GlytexHttpApiStatus, parse_gpu_status_file};
+
+#[test]
+fn parse_gpu_status_file_skips_unavailable_devices() {
+    let content = r#"{
+        "gpu_devices": [
+            { "device_name": " NVIDIA GeForce RTX 3080 ", "device_index": 0, "settings": { "is_available": true } },
+            { "device_name": "Intel(R) UHD Graphics", "device_index": 1, "settings": { "is_available": false } },
+            { "device_name": "AMD Radeon RX 6800", "device_index": 2 }
+        ]
+    }"#;
+
+    let devices = parse_gpu_status_file(content).unwrap();
+
+    assert_eq!(devices.len(), 2);
+    assert_eq!(devices[0].name, "NVIDIA GeForce RTX 3080");
+    assert_eq!(devices[0].device_id, 0);
+    assert_eq!(devices[1].name, "AMD Radeon RX 6800");
+    assert_eq!(devices[1].device_id, 2);
+}
+
+#[test]
+fn parse_gpu_status_file_rejects_malformed_content() {
+    assert!(parse_gpu_status_file("no devices").is_err());
+}
+
+#[test]
+fn http_api_status_is_converted_to_miner_status() {
+    let body: GlytexHttpApiStatus =
+        serde_json::from_str(r#"{ "hashrate_per_second": 1250000, "accepted_blocks": 3 }"#)
+            .unwrap();
+
+    let status = body.into_status();
+
+    assert!(status.is_mining);
+    assert!(status.is_connected);
+    assert!((status.hash_rate - 1_250_000.0).abs() < f64::EPSILON);
+    assert_eq!(status.accepted_shares, 3);
+    assert_eq!(status.algorithm, GpuMiningAlgorithm::Sha3x);
+}
+
+#[test]
+fn idle_http_api_status_is_not_connected() {
+    let body: GlytexHttpApiStatus =
+        serde_json::from_str(r#"{ "hashrate_per_second": 0 }"#).unwrap();
+
+    let status = body.into_status();
+
+    assert!(!status.is_connected);
+    assert_eq!(status.accepted_shares, 0);
+}
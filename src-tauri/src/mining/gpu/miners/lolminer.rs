@@ -37,18 +37,17 @@ use crate::utils::windows_setup_utils::add_firewall_rule;
 use crate::{
     APPLICATION_FOLDER_ID, LOG_TARGET_APP_LOGIC, LOG_TARGET_STATUSES,
     binaries::{Binaries, BinaryResolver},
-    configs::{
-        config_mining::{ConfigMining, ConfigMiningContent},
-        trait_config::ConfigImpl,
-    },
-    events_emitter::EventsEmitter,
+    configs::config_mining::GpuDevicesSettings,
     mining::{
         GpuConnectionType,
         gpu::{
             consts::{GpuMinerStatus, GpuMinerType},
+            device_status::GpuDeviceMiningStatus,
             interface::{GpuMinerInterfaceTrait, GpuMinerStatusInterface},
             manager::GpuManager,
-            miners::GpuCommonInformation,
+            miners::{
+                GpuCommonInformation, devices_in_use, join_device_values, store_detected_devices,
+            },
         },
     },
     port_allocator::PortAllocator,
@@ -68,6 +67,7 @@ pub struct LolMinerGpuMiner {
     pub gpu_status_sender: Sender<GpuMinerStatus>,
     pub gpu_devices: Vec<GpuCommonInformation>,
     pub excluded_devices: Vec<u32>,
+    pub devices_settings: GpuDevicesSettings,
 }
 
 impl LolMinerGpuMiner {
@@ -80,7 +80,33 @@ impl LolMinerGpuMiner {
             gpu_status_sender,
             gpu_devices: vec![],
             excluded_devices: vec![],
+            devices_settings: GpuDevicesSettings::new(),
+        }
+    }
+
+    /// Power and temperature caps of the devices in use
+    /// lolMiner skips a device with `*` in its power limit list and 0 disables its temperature stop
+    fn device_limits_args(&self) -> Vec<String> {
+        let device_ids = devices_in_use(&self.gpu_devices, &self.excluded_devices);
+        let mut args = vec![];
+
+        if let Some(power_limits) = self
+            .devices_settings
+            .per_device_values(&device_ids, |settings| settings.power_limit_watts)
+        {
+            args.push("--pl".to_string());
+            args.push(join_device_values(&power_limits, "*"));
+        }
+
+        if let Some(max_temperatures) = self
+            .devices_settings
+            .per_device_values(&device_ids, |settings| settings.max_temperature)
+        {
+            args.push("--tstop".to_string());
+            args.push(join_device_values(&max_temperatures, "0"));
         }
+
+        args
     }
 }
 
@@ -116,6 +142,14 @@ impl GpuMinerInterfaceTrait for LolMinerGpuMiner {
         Ok(())
     }
 
+    async fn load_devices_settings(
+        &mut self,
+        devices_settings: GpuDevicesSettings,
+    ) -> Result<(), anyhow::Error> {
+        self.devices_settings = devices_settings;
+        Ok(())
+    }
+
     async fn detect_devices(&mut self) -> Result<(), anyhow::Error> {
         let config_path =
             dirs::config_dir().ok_or_else(|| anyhow::anyhow!("Failed to get config directory"))?;
@@ -152,20 +186,7 @@ impl GpuMinerInterfaceTrait for LolMinerGpuMiner {
         }
 
         self.gpu_devices = gpu_devices;
-        let devices_indexes: Vec<u32> = self.gpu_devices.iter().map(|d| d.device_id).collect();
-        EventsEmitter::emit_detected_devices(self.gpu_devices.clone()).await;
-        ConfigMining::update_field(
-            ConfigMiningContent::populate_gpu_devices_settings,
-            devices_indexes,
-        )
-        .await?;
-
-        EventsEmitter::emit_update_gpu_devices_settings(
-            ConfigMining::content().await.gpu_devices_settings().clone(),
-        )
-        .await;
-
-        Ok(())
+        store_detected_devices(self.gpu_devices.clone()).await
     }
 }
 
@@ -235,13 +256,11 @@ impl ProcessAdapter for LolMinerGpuMiner {
 
         // Add device selection if there are excluded devices
         if !self.excluded_devices.is_empty() && !self.gpu_devices.is_empty() {
-            let devices_to_use: Vec<String> = self
-                .gpu_devices
-                .iter()
-                .map(|d| d.device_id)
-                .filter(|id| !self.excluded_devices.contains(id))
-                .map(|id| id.to_string())
-                .collect();
+            let devices_to_use: Vec<String> =
+                devices_in_use(&self.gpu_devices, &self.excluded_devices)
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect();
 
             if devices_to_use.is_empty() {
                 return Err(crate::mining::MiningError::AllDevicesExcluded.into());
@@ -257,6 +276,8 @@ impl ProcessAdapter for LolMinerGpuMiner {
             }
         }
 
+        args.extend(self.device_limits_args());
+
         #[cfg(target_os = "windows")]
         add_firewall_rule("lolMiner.exe".to_string(), binary_version_path.clone())?;
 
@@ -380,19 +401,13 @@ impl LolMinerGpuMinerStatusMonitor {
             Err(e) => {
                 warn!(target: LOG_TARGET_STATUSES, "Error in getting response from LolMiner status: {e}");
                 if e.is_connect() {
-                    return Ok(GpuMinerStatus {
-                        is_mining: false,
-                        hash_rate: 0.0,
-                        estimated_earnings: 0,
-                        algorithm: GpuMinerType::LolMiner.main_algorithm(),
-                    });
+                    return Ok(GpuMinerStatus::default_with_algorithm(
+                        GpuMinerType::LolMiner.main_algorithm(),
+                    ));
                 }
-                return Ok(GpuMinerStatus {
-                    is_mining: false,
-                    hash_rate: 0.0,
-                    estimated_earnings: 0,
-                    algorithm: GpuMinerType::LolMiner.main_algorithm(),
-                });
+                return Ok(GpuMinerStatus::default_with_algorithm(
+                    GpuMinerType::LolMiner.main_algorithm(),
+                ));
             }
         };
         let text = response.text().await?;
@@ -400,41 +415,109 @@ impl LolMinerGpuMinerStatusMonitor {
             Ok(body) => body,
             Err(e) => {
                 warn!(target: LOG_TARGET_STATUSES, "Error decoding body from  in LolMiner status: {e}");
-                return Ok(GpuMinerStatus {
-                    is_mining: false,
-                    hash_rate: 0.0,
-                    estimated_earnings: 0,
-                    algorithm: GpuMinerType::LolMiner.main_algorithm(),
-                });
+                return Ok(GpuMinerStatus::default_with_algorithm(
+                    GpuMinerType::LolMiner.main_algorithm(),
+                ));
             }
         };
 
-        Ok(GpuMinerStatus {
+        Ok(body.into_status())
+    }
+}
+
+fn round_hash_rate(hash_rate: f64) -> f64 {
+    (hash_rate * 100.0).round() / 100.0
+}
+
+/// lolMiner reports 0 for sensors it cannot read
+fn sensor_reading(value: Option<f32>) -> Option<f32> {
+    value.filter(|reading| *reading > 0.0)
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct LolMinerHttpApiStatus {
+    #[serde(rename = "Workers", default)]
+    workers: Vec<Worker>,
+    #[serde(rename = "Algorithms")]
+    algorithms: Vec<Algorithm>,
+}
+
+impl LolMinerHttpApiStatus {
+    pub(crate) fn into_status(self) -> GpuMinerStatus {
+        let hash_rate = round_hash_rate(self.algorithms.iter().map(|a| a.total_performance).sum());
+
+        GpuMinerStatus {
             is_mining: true,
             estimated_earnings: 0,
-            hash_rate: ((body
-                .algorithms
-                .iter()
-                .map(|a| a.total_performance)
-                .sum::<f64>()
-                * 100.0)
-                .round()
-                / 100.0),
+            hash_rate,
             algorithm: GpuMinerType::LolMiner.main_algorithm(),
-        })
+            // lolMiner has no explicit pool state, it stops hashing when it has no job from the pool
+            is_connected: hash_rate > 0.0,
+            accepted_shares: self.algorithms.iter().map(|a| a.total_accepted).sum(),
+            devices: self.device_statuses(),
+        }
+    }
+
+    /// Per device values of each algorithm are listed in the same order as the workers
+    fn device_statuses(&self) -> Vec<GpuDeviceMiningStatus> {
+        self.workers
+            .iter()
+            .enumerate()
+            .map(|(position, worker)| {
+                let per_algorithm = |values: fn(&Algorithm) -> &Vec<u64>| -> u64 {
+                    self.algorithms
+                        .iter()
+                        .filter_map(|a| values(a).get(position))
+                        .sum()
+                };
+
+                GpuDeviceMiningStatus {
+                    device_index: worker.index,
+                    name: worker.name.trim().to_string(),
+                    hash_rate: round_hash_rate(
+                        self.algorithms
+                            .iter()
+                            .filter_map(|a| a.worker_performance.get(position))
+                            .sum(),
+                    ),
+                    accepted_shares: per_algorithm(|a| &a.worker_accepted),
+                    rejected_shares: per_algorithm(|a| &a.worker_rejected),
+                    temperature: sensor_reading(worker.core_temp),
+                    fan_speed: worker.fan_speed,
+                    power_watts: sensor_reading(worker.power),
+                    hardware_parameters: None,
+                }
+            })
+            .collect()
     }
 }
 
 #[derive(Debug, Deserialize)]
-struct LolMinerHttpApiStatus {
-    #[serde(rename = "Algorithms")]
-    algorithms: Vec<Algorithm>,
+struct Worker {
+    #[serde(rename = "Index")]
+    index: u32,
+    #[serde(rename = "Name", default)]
+    name: String,
+    #[serde(rename = "Power")]
+    power: Option<f32>,
+    #[serde(rename = "Core_Temp")]
+    core_temp: Option<f32>,
+    #[serde(rename = "Fan_Speed")]
+    fan_speed: Option<f32>,
 }
 
 #[derive(Debug, Deserialize)]
 struct Algorithm {
     #[serde(rename = "Total_Performance")]
     total_performance: f64,
+    #[serde(rename = "Total_Accepted", default)]
+    total_accepted: u64,
+    #[serde(rename = "Worker_Performance", default)]
+    worker_performance: Vec<f64>,
+    #[serde(rename = "Worker_Accepted", default)]
+    worker_accepted: Vec<u64>,
+    #[serde(rename = "Worker_Rejected", default)]
+    worker_rejected: Vec<u64>,
 }
 
 fn extract_device_names(output_str: &str) -> Vec<String> {
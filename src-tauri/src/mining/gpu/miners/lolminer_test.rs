@@ -0,0 +1,99 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use super::lolminer::LolMinerHttpApiStatus;
+
+const TWO_GPU_STATUS: &str = r#"{
+    "Software": "lolMiner 1.98",
+    "Num_Workers": 2,
+    "Workers": [
+        { "Index": 0, "Name": "NVIDIA GeForce RTX 3080", "Power": 221.5, "Core_Temp": 64, "Fan_Speed": 71 },
+        { "Index": 1, "Name": "AMD Radeon RX 6600 ", "Power": 0, "Core_Temp": 0, "Fan_Speed": 0 }
+    ],
+    "Num_Algorithms": 1,
+    "Algorithms": [
+        {
+            "Algorithm": "Cuckaroo 29",
+            "Total_Performance": 9.126,
+            "Total_Accepted": 17,
+            "Total_Rejected": 1,
+            "Worker_Performance": [7.1, 2.026],
+            "Worker_Accepted": [15, 2],
+            "Worker_Rejected": [0, 1]
+        }
+    ]
+}"#;
+
+#[test]
+fn aggregate_status_sums_all_algorithms() {
+    let body: LolMinerHttpApiStatus = serde_json::from_str(TWO_GPU_STATUS).unwrap();
+
+    let status = body.into_status();
+
+    assert!((status.hash_rate - 9.13).abs() < f64::EPSILON);
+    assert_eq!(status.accepted_shares, 17);
+    assert!(status.is_connected);
+}
+
+#[test]
+fn device_statuses_follow_worker_order() {
+    let body: LolMinerHttpApiStatus = serde_json::from_str(TWO_GPU_STATUS).unwrap();
+
+    let devices = body.into_status().devices;
+
+    assert_eq!(devices.len(), 2);
+    assert_eq!(devices[0].device_index, 0);
+    assert_eq!(devices[0].name, "NVIDIA GeForce RTX 3080");
+    assert!((devices[0].hash_rate - 7.1).abs() < f64::EPSILON);
+    assert_eq!(devices[0].accepted_shares, 15);
+    assert_eq!(devices[0].rejected_shares, 0);
+    assert_eq!(devices[0].temperature, Some(64.0));
+    assert_eq!(devices[0].fan_speed, Some(71.0));
+    assert_eq!(devices[0].power_watts, Some(221.5));
+
+    assert_eq!(devices[1].name, "AMD Radeon RX 6600");
+    assert!((devices[1].hash_rate - 2.03).abs() < f64::EPSILON);
+    assert_eq!(devices[1].rejected_shares, 1);
+}
+
+#[test]
+fn unreadable_sensors_are_reported_as_missing() {
+    let body: LolMinerHttpApiStatus = serde_json::from_str(TWO_GPU_STATUS).unwrap();
+
+    let devices = body.into_status().devices;
+
+    assert_eq!(devices[1].temperature, None);
+    assert_eq!(devices[1].power_watts, None);
+    // A stopped fan is a valid reading
+    assert_eq!(devices[1].fan_speed, Some(0.0));
+}
+
+#[test]
+fn status_without_workers_has_no_devices() {
+    let body: LolMinerHttpApiStatus =
+        serde_json::from_str(r#"{ "Algorithms": [ { "Total_Performance": 0.0 } ] }"#).unwrap();
+
+    let status = body.into_status();
+
+    assert!(status.devices.is_empty());
+    assert!(!status.is_connected);
+}
@@ -22,10 +22,61 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    configs::{
+        config_mining::{ConfigMining, ConfigMiningContent},
+        trait_config::ConfigImpl,
+    },
+    events_emitter::EventsEmitter,
+};
+
+pub mod glytex;
+#[cfg(test)]
+mod glytex_test;
 pub mod lolminer;
+#[cfg(test)]
+mod lolminer_test;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GpuCommonInformation {
     pub name: String,
     pub device_id: u32,
 }
+
+/// Publishes the devices found by a miner and adds settings entries for new ones
+pub(crate) async fn store_detected_devices(
+    gpu_devices: Vec<GpuCommonInformation>,
+) -> Result<(), anyhow::Error> {
+    let devices_indexes: Vec<u32> = gpu_devices.iter().map(|d| d.device_id).collect();
+    EventsEmitter::emit_detected_devices(gpu_devices).await;
+    ConfigMining::update_field(
+        ConfigMiningContent::populate_gpu_devices_settings,
+        devices_indexes,
+    )
+    .await?;
+
+    EventsEmitter::emit_update_gpu_devices_settings(
+        ConfigMining::content().await.gpu_devices_settings().clone(),
+    )
+    .await;
+    Ok(())
+}
+
+/// Ids of the detected devices that are not excluded, in the order the miners expect per device values
+pub(crate) fn devices_in_use(gpu_devices: &[GpuCommonInformation], excluded: &[u32]) -> Vec<u32> {
+    gpu_devices
+        .iter()
+        .map(|d| d.device_id)
+        .filter(|id| !excluded.contains(id))
+        .collect()
+}
+
+/// Joins per device values into the comma separated form used by the miners' arguments
+/// Devices without a value get the miner specific `unset` placeholder
+pub(crate) fn join_device_values(values: &[Option<u32>], unset: &str) -> String {
+    values
+        .iter()
+        .map(|value| value.map_or_else(|| unset.to_string(), |v| v.to_string()))
+        .collect::<Vec<String>>()
+        .join(",")
+}
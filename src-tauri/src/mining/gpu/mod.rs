@@ -20,7 +20,13 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+pub mod benchmark;
+#[cfg(test)]
+mod benchmark_test;
 pub mod consts;
+pub mod device_status;
+#[cfg(test)]
+mod device_status_test;
 pub mod interface;
 pub mod manager;
 pub mod miners;
@@ -25,6 +25,14 @@ use serde::{Deserialize, Serialize};
 pub mod cpu;
 pub mod gpu;
 pub mod pools;
+pub mod thermal;
+#[cfg(test)]
+mod thermal_test;
+
+/// Whether a cpu or gpu benchmark is running, miners must not be restarted or reconfigured meanwhile
+pub fn is_benchmark_running() -> bool {
+    cpu::benchmark::is_cpu_benchmark_running() || gpu::benchmark::is_gpu_benchmark_running()
+}
 
 /// Errors that represent user-environment issues rather than application bugs.
 /// These should never be reported to Sentry.
@@ -34,7 +42,7 @@ pub enum MiningError {
     GpuMiningDisabled,
     #[error("CPU mining is disabled")]
     CpuMiningDisabled,
-    #[error("All GPU devices are excluded. Cannot start lolminer.")]
+    #[error("All GPU devices are excluded. Cannot start the GPU miner.")]
     AllDevicesExcluded,
 }
 
@@ -61,6 +69,8 @@ pub enum MinerControlsState {
     Stopped,
     Restarting,
     Idle,
+    /// Stopped by the thermal throttle until the device cools down
+    ThermalPaused,
 }
 
 #[derive(Clone, Serialize, PartialEq, Eq, Deserialize, Debug)]
@@ -0,0 +1,98 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::{
+    LOG_TARGET_STATUSES,
+    configs::pools::custom_pools::PoolStatsPointers,
+    mining::pools::{PoolStatus, adapters::PoolApiAdapter},
+    requests::clients::http_client::HttpClient,
+};
+use log::info;
+use serde_json::Value;
+
+/// Reads pool stats from any JSON endpoint, using the configured JSON pointers to find the fields
+#[derive(Clone, Debug)]
+pub struct CustomPoolAdapter {
+    name: String,
+    stats_url: String,
+    pointers: PoolStatsPointers,
+}
+
+impl CustomPoolAdapter {
+    pub fn new(name: String, stats_url: String, pointers: PoolStatsPointers) -> Self {
+        Self {
+            name,
+            stats_url,
+            pointers,
+        }
+    }
+
+    /// Pools often return numbers as strings, both are accepted
+    fn read_number(data: &Value, pointer: Option<&String>) -> Result<f64, anyhow::Error> {
+        let Some(pointer) = pointer else {
+            return Ok(0.0);
+        };
+        let value = data
+            .pointer(pointer)
+            .ok_or_else(|| anyhow::anyhow!("Field {pointer} not found in pool stats"))?;
+        match value {
+            Value::Number(number) => number
+                .as_f64()
+                .ok_or_else(|| anyhow::anyhow!("Field {pointer} is not a valid number")),
+            Value::String(text) => Ok(text.trim().parse::<f64>()?),
+            _ => Err(anyhow::anyhow!("Field {pointer} is not a number")),
+        }
+    }
+}
+
+impl PoolApiAdapter for CustomPoolAdapter {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn convert_api_data(&self, data: &str) -> Result<PoolStatus, anyhow::Error> {
+        let data: Value = serde_json::from_str(data)?;
+        let pool_status = PoolStatus {
+            accepted_shares: Self::read_number(&data, self.pointers.accepted_shares.as_ref())?
+                as u64,
+            unpaid: Self::read_number(&data, self.pointers.unpaid.as_ref())?,
+            balance: Self::read_number(&data, self.pointers.balance.as_ref())?,
+            min_payout: Self::read_number(&data, self.pointers.min_payout.as_ref())? as u64,
+        };
+        Ok(pool_status)
+    }
+
+    async fn request_pool_status(&self, address: String) -> Result<PoolStatus, anyhow::Error> {
+        if self.stats_url.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Custom pool has no stats endpoint configured"
+            ));
+        }
+        let url = self.stats_url.replace("%TARI_ADDRESS%", &address);
+        info!(target: LOG_TARGET_STATUSES, "Requesting custom pool status from: {url}");
+        let pool_status_response = HttpClient::with_retries(3).send_get_request(&url).await?;
+        let response_text = pool_status_response.text().await?;
+        let pool_status = self.convert_api_data(response_text.as_str())?;
+        Ok(pool_status)
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use crate::configs::pools::custom_pools::PoolStatsPointers;
+
+use super::{PoolApiAdapter, custom_pool::CustomPoolAdapter};
+
+fn adapter(pointers: PoolStatsPointers) -> CustomPoolAdapter {
+    CustomPoolAdapter::new(
+        "Custom".to_string(),
+        "https://pool.example.com/api/%TARI_ADDRESS%".to_string(),
+        pointers,
+    )
+}
+
+#[test]
+fn maps_fields_with_json_pointers() {
+    let adapter = adapter(PoolStatsPointers {
+        accepted_shares: Some("/miner/shares/0/valid".to_string()),
+        unpaid: Some("/miner/unpaid".to_string()),
+        balance: Some("/miner/paid".to_string()),
+        min_payout: Some("/config/threshold".to_string()),
+    });
+    let data = r#"{
+        "miner": { "shares": [{ "valid": 42 }], "unpaid": "1500.5", "paid": 12 },
+        "config": { "threshold": 2000000 }
+    }"#;
+
+    let status = adapter.convert_api_data(data).unwrap();
+
+    assert_eq!(status.accepted_shares, 42);
+    assert!((status.unpaid - 1500.5).abs() < f64::EPSILON);
+    assert!((status.balance - 12.0).abs() < f64::EPSILON);
+    assert_eq!(status.min_payout, 2_000_000);
+}
+
+#[test]
+fn fields_without_pointer_are_zero() {
+    let adapter = adapter(PoolStatsPointers {
+        unpaid: Some("/unpaid".to_string()),
+        ..Default::default()
+    });
+
+    let status = adapter.convert_api_data(r#"{ "unpaid": 7 }"#).unwrap();
+
+    assert_eq!(status.accepted_shares, 0);
+    assert!((status.unpaid - 7.0).abs() < f64::EPSILON);
+    assert_eq!(status.min_payout, 0);
+}
+
+#[test]
+fn missing_or_invalid_fields_are_errors() {
+    let adapter = adapter(PoolStatsPointers {
+        unpaid: Some("/unpaid".to_string()),
+        ..Default::default()
+    });
+
+    assert!(adapter.convert_api_data(r#"{ "balance": 7 }"#).is_err());
+    assert!(adapter.convert_api_data(r#"{ "unpaid": "abc" }"#).is_err());
+    assert!(adapter.convert_api_data(r#"{ "unpaid": [1] }"#).is_err());
+    assert!(adapter.convert_api_data("not json").is_err());
+}
@@ -36,8 +36,8 @@ use crate::{
     mining::pools::{
         PoolManagerInterfaceTrait, PoolStatus,
         adapters::{
-            PoolApiAdapters, kryptex_pool::KryptexPoolAdapter, lucky_pool::LuckyPoolAdapter,
-            support_xmr_pool::SupportXmrPoolAdapter,
+            PoolApiAdapters, custom_pool::CustomPoolAdapter, kryptex_pool::KryptexPoolAdapter,
+            lucky_pool::LuckyPoolAdapter, support_xmr_pool::SupportXmrPoolAdapter,
         },
         pools_manager::PoolManager,
     },
@@ -121,6 +121,11 @@ impl PoolManagerInterfaceTrait<CpuPool> for CpuPoolManager {
                 pool.pool_type.key_string(),
                 pool.stats_url,
             )),
+            CpuPool::Custom => PoolApiAdapters::Custom(CustomPoolAdapter::new(
+                pool.pool_type.key_string(),
+                pool.stats_url,
+                pool.custom.unwrap_or_default().stats_pointers,
+            )),
         }
     }
 }
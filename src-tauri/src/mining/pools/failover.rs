@@ -0,0 +1,211 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tokio::time::Instant;
+
+use crate::{LOG_TARGET_APP_LOGIC, mining::pools::stratum_probe::probe_stratum};
+
+/// Automatic pool failover, off until the user turns it on
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct PoolFailoverSettings {
+    pub enabled: bool,
+    /// Switch to the next pool after the miner reports no connection or no accepted shares for this long
+    pub failover_after_secs: u64,
+    /// How often to check if a higher priority pool is reachable again while mining on a fallback pool
+    pub failback_probe_interval_secs: u64,
+    /// Check the selected pool with a stratum handshake before starting the miner
+    pub probe_before_start: bool,
+}
+
+impl Default for PoolFailoverSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            failover_after_secs: 10 * 60,
+            failback_probe_interval_secs: 15 * 60,
+            probe_before_start: false,
+        }
+    }
+}
+
+impl PoolFailoverSettings {
+    pub fn failover_after(&self) -> Duration {
+        Duration::from_secs(self.failover_after_secs)
+    }
+
+    pub fn failback_probe_interval(&self) -> Duration {
+        Duration::from_secs(self.failback_probe_interval_secs.max(1))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum PoolKind {
+    Cpu,
+    Gpu,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum PoolSwitchReason {
+    /// The pool in use stopped working and the next one in the priority list was selected
+    Failover,
+    /// A higher priority pool became reachable again
+    Failback,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PoolSwitchRecord {
+    pub kind: PoolKind,
+    pub from: String,
+    pub to: String,
+    pub reason: PoolSwitchReason,
+    pub switched_at: i64,
+}
+
+impl PoolSwitchRecord {
+    pub fn new(kind: PoolKind, from: String, to: String, reason: PoolSwitchReason) -> Self {
+        Self {
+            kind,
+            from,
+            to,
+            reason,
+            switched_at: Utc::now().timestamp(),
+        }
+    }
+}
+
+/// Tracks when the miner was last connected and last got a share accepted
+#[derive(Debug, Clone)]
+pub struct PoolHealthTracker {
+    last_connected: Instant,
+    last_accepted_share: Instant,
+    accepted_shares: u64,
+}
+
+impl PoolHealthTracker {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            last_connected: now,
+            last_accepted_share: now,
+            accepted_shares: 0,
+        }
+    }
+
+    /// Records a status update and returns true if the pool should be failed over
+    pub fn observe(
+        &mut self,
+        now: Instant,
+        is_connected: bool,
+        accepted_shares: u64,
+        failover_after: Duration,
+    ) -> bool {
+        if is_connected {
+            self.last_connected = now;
+        }
+        // The counter restarts from zero with the miner process
+        if accepted_shares > self.accepted_shares {
+            self.last_accepted_share = now;
+        }
+        self.accepted_shares = accepted_shares;
+
+        now.saturating_duration_since(self.last_connected) >= failover_after
+            || now.saturating_duration_since(self.last_accepted_share) >= failover_after
+    }
+}
+
+/// The pool to fail over to, the one after `current` in the priority list, wrapping around
+pub fn next_in_priority<T: PartialEq + Clone>(priority: &[T], current: &T) -> Option<T> {
+    failover_order(priority, current).into_iter().next()
+}
+
+/// All pools other than `current` in the order they would be failed over to
+pub fn failover_order<T: PartialEq + Clone>(priority: &[T], current: &T) -> Vec<T> {
+    let start = priority
+        .iter()
+        .position(|pool| pool == current)
+        .map_or(0, |index| index.saturating_add(1));
+    priority
+        .iter()
+        .skip(start)
+        .chain(priority.iter().take(start))
+        .filter(|pool| *pool != current)
+        .cloned()
+        .collect()
+}
+
+/// Pools ranked above `current` in the priority list, highest priority first
+pub fn higher_priority<T: PartialEq + Clone>(priority: &[T], current: &T) -> Vec<T> {
+    priority
+        .iter()
+        .take_while(|pool| *pool != current)
+        .cloned()
+        .collect()
+}
+
+/// Parses a user supplied priority list, it has to contain at least one pool and no duplicates
+pub fn parse_pool_priority<T: PartialEq>(
+    pool_names: &[String],
+    parse: impl Fn(&str) -> Result<T, anyhow::Error>,
+) -> Result<Vec<T>, anyhow::Error> {
+    if pool_names.is_empty() {
+        return Err(anyhow::anyhow!("Pool priority list can not be empty"));
+    }
+
+    let mut priority = Vec::with_capacity(pool_names.len());
+    for pool_name in pool_names {
+        let pool = parse(pool_name)?;
+        if priority.contains(&pool) {
+            return Err(anyhow::anyhow!("Pool {pool_name} is listed more than once"));
+        }
+        priority.push(pool);
+    }
+    Ok(priority)
+}
+
+/// Checks that the pool completes the stratum handshake used for pools of this kind
+pub async fn probe_pool(pool_url: &str, login: &str, kind: PoolKind) -> bool {
+    let result = probe_stratum(pool_url, login, kind.into()).await;
+    if let Some(error) = &result.error {
+        warn!(target: LOG_TARGET_APP_LOGIC, "Pool {pool_url} is not reachable: {error}");
+    }
+    result.is_reachable()
+}
+
+/// Returns the first pool of `candidates` that is reachable, candidates are `(pool, pool_url)` pairs
+pub async fn first_reachable<T>(
+    candidates: Vec<(T, String)>,
+    login: &str,
+    kind: PoolKind,
+) -> Option<T> {
+    for (pool, pool_url) in candidates {
+        if probe_pool(&pool_url, login, kind).await {
+            info!(target: LOG_TARGET_APP_LOGIC, "Pool {pool_url} is reachable");
+            return Some(pool);
+        }
+    }
+    None
+}
@@ -0,0 +1,188 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::Duration;
+
+use tokio::{net::TcpListener, time::Instant};
+
+use crate::configs::pools::cpu_pools::CpuPool;
+
+use super::{
+    failover::{
+        PoolFailoverSettings, PoolHealthTracker, PoolKind, failover_order, first_reachable,
+        higher_priority, next_in_priority, parse_pool_priority,
+    },
+    stratum_probe_test::{LOGIN_RESPONSE, spawn_fake_stratum_server},
+};
+
+const FAILOVER_AFTER: Duration = Duration::from_secs(60);
+
+fn priority() -> Vec<CpuPool> {
+    vec![
+        CpuPool::LuckyPoolRANDOMX,
+        CpuPool::KryptexPoolRANDOMX,
+        CpuPool::SupportXTMPoolRANDOMX,
+    ]
+}
+
+#[test]
+fn failover_is_off_by_default() {
+    let settings = PoolFailoverSettings::default();
+    assert!(!settings.enabled);
+    assert!(!settings.probe_before_start);
+}
+
+#[test]
+fn tracker_fails_over_when_disconnected_for_too_long() {
+    let start = Instant::now();
+    let mut tracker = PoolHealthTracker::new(start);
+
+    assert!(!tracker.observe(start + Duration::from_secs(30), false, 0, FAILOVER_AFTER));
+    assert!(tracker.observe(start + FAILOVER_AFTER, false, 0, FAILOVER_AFTER));
+}
+
+#[test]
+fn tracker_fails_over_when_no_shares_are_accepted() {
+    let start = Instant::now();
+    let mut tracker = PoolHealthTracker::new(start);
+
+    assert!(!tracker.observe(start + Duration::from_secs(30), true, 1, FAILOVER_AFTER));
+    assert!(!tracker.observe(start + Duration::from_secs(80), true, 1, FAILOVER_AFTER));
+    assert!(tracker.observe(start + Duration::from_secs(90), true, 1, FAILOVER_AFTER));
+}
+
+#[test]
+fn tracker_stays_on_pool_while_shares_are_accepted() {
+    let start = Instant::now();
+    let mut tracker = PoolHealthTracker::new(start);
+
+    for minute in 1..10 {
+        assert!(!tracker.observe(
+            start + Duration::from_secs(minute * 50),
+            true,
+            minute,
+            FAILOVER_AFTER
+        ));
+    }
+}
+
+#[test]
+fn next_in_priority_wraps_around() {
+    let priority = priority();
+
+    assert_eq!(
+        next_in_priority(&priority, &CpuPool::LuckyPoolRANDOMX),
+        Some(CpuPool::KryptexPoolRANDOMX)
+    );
+    assert_eq!(
+        next_in_priority(&priority, &CpuPool::SupportXTMPoolRANDOMX),
+        Some(CpuPool::LuckyPoolRANDOMX)
+    );
+}
+
+#[test]
+fn next_in_priority_starts_from_primary_for_unlisted_pool() {
+    let priority = vec![CpuPool::KryptexPoolRANDOMX];
+
+    assert_eq!(
+        next_in_priority(&priority, &CpuPool::LuckyPoolRANDOMX),
+        Some(CpuPool::KryptexPoolRANDOMX)
+    );
+    assert_eq!(
+        next_in_priority(&priority, &CpuPool::KryptexPoolRANDOMX),
+        None
+    );
+}
+
+#[test]
+fn higher_priority_lists_pools_above_current() {
+    let priority = priority();
+
+    assert_eq!(
+        higher_priority(&priority, &CpuPool::SupportXTMPoolRANDOMX),
+        vec![CpuPool::LuckyPoolRANDOMX, CpuPool::KryptexPoolRANDOMX]
+    );
+    assert!(higher_priority(&priority, &CpuPool::LuckyPoolRANDOMX).is_empty());
+}
+
+#[test]
+fn parse_pool_priority_rejects_empty_and_duplicate_lists() {
+    let names = |names: &[&str]| names.iter().map(|n| (*n).to_string()).collect::<Vec<_>>();
+
+    assert_eq!(
+        parse_pool_priority(
+            &names(&["KryptexPoolRANDOMX", "LuckyPoolRANDOMX"]),
+            CpuPool::from_string
+        )
+        .unwrap(),
+        vec![CpuPool::KryptexPoolRANDOMX, CpuPool::LuckyPoolRANDOMX]
+    );
+    assert!(parse_pool_priority(&names(&[]), CpuPool::from_string).is_err());
+    assert!(
+        parse_pool_priority(
+            &names(&["LuckyPoolRANDOMX", "LuckyPoolRANDOMX"]),
+            CpuPool::from_string
+        )
+        .is_err()
+    );
+    assert!(parse_pool_priority(&names(&["UnknownPool"]), CpuPool::from_string).is_err());
+}
+
+#[test]
+fn failover_order_rotates_after_current_pool() {
+    let priority = priority();
+
+    assert_eq!(
+        failover_order(&priority, &CpuPool::KryptexPoolRANDOMX),
+        vec![CpuPool::SupportXTMPoolRANDOMX, CpuPool::LuckyPoolRANDOMX]
+    );
+    assert_eq!(failover_order(&priority, &CpuPool::Custom), priority);
+}
+
+#[tokio::test]
+async fn first_reachable_skips_unreachable_pools() {
+    let closed_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let closed_address = closed_listener.local_addr().unwrap();
+    drop(closed_listener);
+    let stratum_address = spawn_fake_stratum_server(LOGIN_RESPONSE).await;
+
+    let candidates = vec![
+        (CpuPool::LuckyPoolRANDOMX, closed_address.to_string()),
+        (
+            CpuPool::KryptexPoolRANDOMX,
+            format!("stratum+tcp://{stratum_address}"),
+        ),
+    ];
+    assert_eq!(
+        first_reachable(candidates, "login", PoolKind::Cpu).await,
+        Some(CpuPool::KryptexPoolRANDOMX)
+    );
+    assert_eq!(
+        first_reachable(
+            vec![(CpuPool::LuckyPoolRANDOMX, closed_address.to_string())],
+            "login",
+            PoolKind::Cpu
+        )
+        .await,
+        None
+    );
+}
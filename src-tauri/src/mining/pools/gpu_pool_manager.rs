@@ -38,7 +38,8 @@ use crate::{
         pools::{
             PoolManagerInterfaceTrait, PoolStatus,
             adapters::{
-                PoolApiAdapters, kryptex_pool::KryptexPoolAdapter, lucky_pool::LuckyPoolAdapter,
+                PoolApiAdapters, custom_pool::CustomPoolAdapter, kryptex_pool::KryptexPoolAdapter,
+                lucky_pool::LuckyPoolAdapter,
             },
             pools_manager::PoolManager,
         },
@@ -172,6 +173,13 @@ impl PoolManagerInterfaceTrait<GpuPool> for GpuPoolManager {
                 pool.pool_type.key_string(),
                 pool.stats_url,
             )),
+            GpuPool::Custom | GpuPool::CustomSha3x => {
+                PoolApiAdapters::Custom(CustomPoolAdapter::new(
+                    pool.pool_type.key_string(),
+                    pool.stats_url,
+                    pool.custom.unwrap_or_default().stats_pointers,
+                ))
+            }
         }
     }
 }
@@ -28,13 +28,21 @@ use tokio::sync::RwLockWriteGuard;
 
 use crate::{
     configs::pools::BasePoolData,
-    mining::pools::{adapters::PoolApiAdapters, pools_manager::PoolManager},
+    mining::pools::{
+        adapters::PoolApiAdapters, failover::PoolSwitchRecord, pools_manager::PoolManager,
+    },
 };
 
 mod adapters;
 pub mod cpu_pool_manager;
+pub mod failover;
+#[cfg(test)]
+mod failover_test;
 pub mod gpu_pool_manager;
 pub mod pools_manager;
+pub mod stratum_probe;
+#[cfg(test)]
+mod stratum_probe_test;
 
 #[derive(Clone, Debug, Serialize, Default)]
 pub(crate) struct PoolStatus {
@@ -83,6 +91,21 @@ pub trait PoolManagerInterfaceTrait<T> {
             .await;
     }
 
+    /// Handle an automatic switch to another pool from the priority list
+    /// Records the switch in the history and updates the pool adapter like a manual selection
+    /// ### Arguments
+    /// * `record` - Details of the switch
+    /// * `pool` - The pool configuration that is now in use
+    async fn handle_pool_switch(record: PoolSwitchRecord, pool: BasePoolData<T>) {
+        Self::get_write_manager().await.record_pool_switch(record);
+        Self::handle_new_selected_pool(pool).await;
+    }
+
+    /// History of automatic pool switches, oldest first
+    async fn get_pool_switches() -> Vec<PoolSwitchRecord> {
+        Self::get_write_manager().await.pool_switches()
+    }
+
     /// Handle the case when user changes the wallet address used for mining
     /// This should be called whenever the wallet address changes either in seedless or normal wallet
     /// ### Arguments
@@ -121,6 +144,11 @@ pub trait PoolManagerInterfaceTrait<T> {
         Self::get_write_manager().await.stop_background_task();
     }
 
+    /// Snapshot of the last fetched pool statuses, keyed by pool name
+    async fn get_pool_statuses() -> HashMap<String, PoolStatus> {
+        Self::get_write_manager().await.pool_statuses().await
+    }
+
     /// Force an immediate update of the current pool statuses
     /// This can be called whenever an immediate update is needed, e.g., after changing the
     /// selected pool or wallet address while not mining
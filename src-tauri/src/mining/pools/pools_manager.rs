@@ -20,7 +20,10 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+};
 
 use log::{debug, info, warn};
 use tari_common_types::tari_address::TariAddress;
@@ -34,6 +37,7 @@ use crate::{
     mining::pools::{
         PoolStatus,
         adapters::{PoolApiAdapter, PoolApiAdapters},
+        failover::PoolSwitchRecord,
     },
     tasks_tracker::TaskTrackerUtil,
 };
@@ -70,6 +74,9 @@ impl TaskState {
     }
 }
 
+/// How many pool switches are kept in the history
+const MAX_POOL_SWITCH_RECORDS: usize = 50;
+
 #[derive(Debug)]
 pub enum PoolManagerThreadCommands {
     UpdateMiningAddress(String),
@@ -98,6 +105,8 @@ pub struct PoolManager {
     task_sender: Option<mpsc::UnboundedSender<PoolManagerThreadCommands>>,
     pool_stats_event_callback:
         Arc<dyn Fn(HashMap<String, PoolStatus>, PoolStatus) + Send + Sync + 'static>,
+    // Failover history of the current session, most recent last. It is not persisted
+    pool_switches: VecDeque<PoolSwitchRecord>,
 }
 
 impl PoolManager {
@@ -115,6 +124,7 @@ impl PoolManager {
             task_sender: None,
             task_tracker,
             pool_stats_event_callback: Arc::new(callback),
+            pool_switches: VecDeque::new(),
         }
     }
 
@@ -228,6 +238,25 @@ impl PoolManager {
         }
     }
 
+    /// Last fetched statuses, keyed by pool name
+    pub async fn pool_statuses(&self) -> HashMap<String, PoolStatus> {
+        self.pool_stats.read().await.clone()
+    }
+
+    /// Keeps track of automatic pool switches so they can be shown next to the pool stats
+    pub fn record_pool_switch(&mut self, record: PoolSwitchRecord) {
+        info!(target: LOG_TARGET_APP_LOGIC, "Pool switched from {} to {} ({:?})", record.from, record.to, record.reason);
+        if self.pool_switches.len() >= MAX_POOL_SWITCH_RECORDS {
+            self.pool_switches.pop_front();
+        }
+        self.pool_switches.push_back(record);
+    }
+
+    /// Recorded pool switches, oldest first
+    pub fn pool_switches(&self) -> Vec<PoolSwitchRecord> {
+        self.pool_switches.iter().cloned().collect()
+    }
+
     /// Send a stop command to the background task
     pub fn stop_background_task(&mut self) {
         if let Some(sender) = &self.task_sender
@@ -0,0 +1,298 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{fmt::Display, io::ErrorKind, time::Duration};
+
+use futures::future::join_all;
+use serde::Serialize;
+use serde_json::{Value, json};
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader},
+    net::{TcpStream, lookup_host},
+    time::{Instant, timeout},
+};
+
+use crate::mining::pools::failover::PoolKind;
+
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+const PROBE_AGENT: &str = "tari-universe-probe";
+const TLS_SCHEMES: &[&str] = &["stratum+ssl", "stratum+tls", "ssl", "tls"];
+
+/// Stratum dialect used to greet a pool, it depends on the mining algorithm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StratumHandshake {
+    /// Monero style `login` request, used by the RandomX CPU pools
+    Login,
+    /// Classic `mining.subscribe` and `mining.authorize`, used by the C29 and SHA3X GPU pools
+    Subscribe,
+}
+
+impl From<PoolKind> for StratumHandshake {
+    fn from(kind: PoolKind) -> Self {
+        match kind {
+            PoolKind::Cpu => StratumHandshake::Login,
+            PoolKind::Gpu => StratumHandshake::Subscribe,
+        }
+    }
+}
+
+impl StratumHandshake {
+    /// Requests sent to the pool, the first reply to any of them completes the handshake
+    fn requests(self, login: &str) -> Vec<Value> {
+        match self {
+            StratumHandshake::Login => vec![json!({
+                "id": 1,
+                "jsonrpc": "2.0",
+                "method": "login",
+                "params": { "login": login, "pass": "x", "agent": PROBE_AGENT },
+            })],
+            StratumHandshake::Subscribe => vec![
+                json!({ "id": 1, "method": "mining.subscribe", "params": [PROBE_AGENT] }),
+                json!({ "id": 2, "method": "mining.authorize", "params": [login, "x"] }),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum StratumProbeErrorClass {
+    /// Host name could not be resolved
+    Dns,
+    /// Nothing accepted the TCP connection
+    Refused,
+    /// The pool did not answer within the probe timeout
+    Timeout,
+    /// TLS handshake failed
+    Tls,
+    /// The pool answered with something that is not a stratum response
+    Protocol,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StratumProbeError {
+    pub class: StratumProbeErrorClass,
+    pub message: String,
+}
+
+impl StratumProbeError {
+    fn new(class: StratumProbeErrorClass, message: impl Display) -> Self {
+        Self {
+            class,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl Display for StratumProbeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}: {}", self.class, self.message)
+    }
+}
+
+impl std::error::Error for StratumProbeError {}
+
+/// Outcome of a probe, serialized for the frontend
+#[derive(Debug, Clone, Serialize)]
+pub struct StratumProbeResult {
+    pub pool_url: String,
+    /// Time between sending the handshake and receiving the pool response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<StratumProbeError>,
+}
+
+impl StratumProbeResult {
+    pub fn is_reachable(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Connects to the pool and performs a stratum handshake
+/// ### Arguments
+/// * `pool_url` - `host:port` with an optional `stratum+tcp://` or `stratum+ssl://` scheme
+/// * `login` - Login sent to the pool, usually the mining address
+/// * `handshake` - Stratum dialect the pool speaks
+pub async fn probe_stratum(
+    pool_url: &str,
+    login: &str,
+    handshake: StratumHandshake,
+) -> StratumProbeResult {
+    match probe_stratum_inner(pool_url, login, handshake).await {
+        Ok(latency) => StratumProbeResult {
+            pool_url: pool_url.to_string(),
+            latency_ms: Some(u64::try_from(latency.as_millis()).unwrap_or(u64::MAX)),
+            error: None,
+        },
+        Err(error) => StratumProbeResult {
+            pool_url: pool_url.to_string(),
+            latency_ms: None,
+            error: Some(error),
+        },
+    }
+}
+
+async fn probe_stratum_inner(
+    pool_url: &str,
+    login: &str,
+    handshake: StratumHandshake,
+) -> Result<Duration, StratumProbeError> {
+    let (use_tls, address) = match pool_url.split_once("://") {
+        Some((scheme, address)) => (TLS_SCHEMES.contains(&scheme), address),
+        None => (false, pool_url),
+    };
+    let host = address
+        .rsplit_once(':')
+        .map_or(address, |(host, _port)| host);
+
+    let socket_addresses = timeout(PROBE_TIMEOUT, lookup_host(address))
+        .await
+        .map_err(|_| {
+            StratumProbeError::new(StratumProbeErrorClass::Timeout, "DNS lookup timed out")
+        })?
+        .map_err(|e| StratumProbeError::new(StratumProbeErrorClass::Dns, e))?
+        .collect::<Vec<_>>();
+    if socket_addresses.is_empty() {
+        return Err(StratumProbeError::new(
+            StratumProbeErrorClass::Dns,
+            format!("No addresses found for {host}"),
+        ));
+    }
+
+    let stream = timeout(
+        PROBE_TIMEOUT,
+        TcpStream::connect(socket_addresses.as_slice()),
+    )
+    .await
+    .map_err(|_| StratumProbeError::new(StratumProbeErrorClass::Timeout, "Connection timed out"))?
+    .map_err(|e| {
+        let class = if e.kind() == ErrorKind::TimedOut {
+            StratumProbeErrorClass::Timeout
+        } else {
+            StratumProbeErrorClass::Refused
+        };
+        StratumProbeError::new(class, e)
+    })?;
+
+    if use_tls {
+        // Miners do not verify pool certificates by default, so the probe does not either
+        let connector = tokio_native_tls::native_tls::TlsConnector::builder()
+            .danger_accept_invalid_certs(true)
+            .danger_accept_invalid_hostnames(true)
+            .build()
+            .map_err(|e| StratumProbeError::new(StratumProbeErrorClass::Tls, e))?;
+        let tls_stream = timeout(
+            PROBE_TIMEOUT,
+            tokio_native_tls::TlsConnector::from(connector).connect(host, stream),
+        )
+        .await
+        .map_err(|_| {
+            StratumProbeError::new(StratumProbeErrorClass::Timeout, "TLS handshake timed out")
+        })?
+        .map_err(|e| StratumProbeError::new(StratumProbeErrorClass::Tls, e))?;
+        stratum_handshake(tls_stream, handshake.requests(login)).await
+    } else {
+        stratum_handshake(stream, handshake.requests(login)).await
+    }
+}
+
+/// Sends the handshake requests, the handshake succeeds on any JSON-RPC message from the pool,
+/// a rejected login or a notification still proves the pool is up and speaks stratum
+async fn stratum_handshake<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: S,
+    requests: Vec<Value>,
+) -> Result<Duration, StratumProbeError> {
+    let mut stream = BufReader::new(stream);
+    let payload: String = requests
+        .iter()
+        .map(|request| format!("{request}\n"))
+        .collect();
+
+    let started_at = Instant::now();
+    stream
+        .get_mut()
+        .write_all(payload.as_bytes())
+        .await
+        .map_err(|e| StratumProbeError::new(StratumProbeErrorClass::Protocol, e))?;
+
+    let mut line = String::new();
+    let read = timeout(PROBE_TIMEOUT, stream.read_line(&mut line))
+        .await
+        .map_err(|_| {
+            StratumProbeError::new(StratumProbeErrorClass::Timeout, "No response to handshake")
+        })?
+        .map_err(|e| StratumProbeError::new(StratumProbeErrorClass::Protocol, e))?;
+    let latency = started_at.elapsed();
+
+    if read == 0 {
+        return Err(StratumProbeError::new(
+            StratumProbeErrorClass::Protocol,
+            "Connection closed before handshake response",
+        ));
+    }
+    let response: Value = serde_json::from_str(line.trim()).map_err(|e| {
+        StratumProbeError::new(
+            StratumProbeErrorClass::Protocol,
+            format!("Invalid response: {e}"),
+        )
+    })?;
+    if response.get("result").is_none()
+        && response.get("error").is_none()
+        && response.get("method").is_none()
+    {
+        return Err(StratumProbeError::new(
+            StratumProbeErrorClass::Protocol,
+            "Response is not a JSON-RPC reply",
+        ));
+    }
+    Ok(latency)
+}
+
+/// Latency of a configured pool, as returned by the pool ranking command
+#[derive(Debug, Clone, Serialize)]
+pub struct PoolLatencyReport {
+    pub kind: PoolKind,
+    pub pool: String,
+    pub pool_name: String,
+    #[serde(flatten)]
+    pub result: StratumProbeResult,
+}
+
+/// Probes all `pools` concurrently, reachable pools come first ordered by latency
+pub async fn rank_pools_by_latency(
+    pools: Vec<(PoolKind, String, String, String)>,
+    login: &str,
+) -> Vec<PoolLatencyReport> {
+    let mut reports = join_all(pools.into_iter().map(
+        |(kind, pool, pool_name, pool_url)| async move {
+            PoolLatencyReport {
+                kind,
+                pool,
+                pool_name,
+                result: probe_stratum(&pool_url, login, kind.into()).await,
+            }
+        },
+    ))
+    .await;
+    reports.sort_by_key(|report| report.result.latency_ms.unwrap_or(u64::MAX));
+    reports
+}
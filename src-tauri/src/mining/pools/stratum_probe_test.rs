@@ -0,0 +1,188 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::net::SocketAddr;
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::TcpListener,
+};
+
+use crate::mining::pools::failover::PoolKind;
+
+use super::stratum_probe::{StratumHandshake, StratumProbeErrorClass, probe_stratum};
+
+pub(super) const LOGIN_RESPONSE: &str =
+    r#"{"id":1,"jsonrpc":"2.0","error":null,"result":{"id":"worker","status":"OK"}}"#;
+
+/// Accepts connections on a random local port and answers the first line of each with `reply`
+pub(super) async fn spawn_fake_stratum_server(reply: &'static str) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let mut stream = BufReader::new(stream);
+                let mut request = String::new();
+                if stream.read_line(&mut request).await.unwrap_or(0) > 0 {
+                    let _ = stream
+                        .get_mut()
+                        .write_all(format!("{reply}\n").as_bytes())
+                        .await;
+                }
+            });
+        }
+    });
+    address
+}
+
+#[tokio::test]
+async fn probe_succeeds_on_login_response() {
+    let address = spawn_fake_stratum_server(LOGIN_RESPONSE).await;
+
+    let result = probe_stratum(
+        &format!("stratum+tcp://{address}"),
+        "login",
+        StratumHandshake::Login,
+    )
+    .await;
+
+    assert!(result.is_reachable(), "{:?}", result.error);
+    assert!(result.latency_ms.is_some());
+}
+
+#[tokio::test]
+async fn probe_accepts_rejected_login() {
+    let address = spawn_fake_stratum_server(
+        r#"{"id":1,"jsonrpc":"2.0","error":{"code":-1,"message":"Invalid address"}}"#,
+    )
+    .await;
+
+    assert!(
+        probe_stratum(&address.to_string(), "login", StratumHandshake::Login)
+            .await
+            .is_reachable()
+    );
+}
+
+#[test]
+fn gpu_pools_use_the_classic_handshake() {
+    assert_eq!(
+        StratumHandshake::from(PoolKind::Cpu),
+        StratumHandshake::Login
+    );
+    assert_eq!(
+        StratumHandshake::from(PoolKind::Gpu),
+        StratumHandshake::Subscribe
+    );
+}
+
+#[tokio::test]
+async fn probe_succeeds_on_subscribe_response() {
+    let address = spawn_fake_stratum_server(
+        r#"{"id":1,"result":[[["mining.notify","ae6812eb4cd7735a302a8a9dd95cf71f"]],"08000002",4],"error":null}"#,
+    )
+    .await;
+
+    let result = probe_stratum(&address.to_string(), "login", StratumHandshake::Subscribe).await;
+
+    assert!(result.is_reachable(), "{:?}", result.error);
+}
+
+#[tokio::test]
+async fn probe_accepts_notification_before_reply() {
+    let address = spawn_fake_stratum_server(
+        r#"{"id":null,"method":"mining.set_difficulty","params":[1024]}"#,
+    )
+    .await;
+
+    assert!(
+        probe_stratum(&address.to_string(), "login", StratumHandshake::Subscribe)
+            .await
+            .is_reachable()
+    );
+}
+
+#[tokio::test]
+async fn probe_reports_protocol_error_on_invalid_response() {
+    let address = spawn_fake_stratum_server("HTTP/1.1 400 Bad Request").await;
+
+    let result = probe_stratum(&address.to_string(), "login", StratumHandshake::Login).await;
+
+    assert_eq!(
+        result.error.map(|e| e.class),
+        Some(StratumProbeErrorClass::Protocol)
+    );
+    assert_eq!(result.latency_ms, None);
+}
+
+#[tokio::test]
+async fn probe_reports_refused_connection() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    drop(listener);
+
+    let result = probe_stratum(&address.to_string(), "login", StratumHandshake::Login).await;
+
+    assert_eq!(
+        result.error.map(|e| e.class),
+        Some(StratumProbeErrorClass::Refused)
+    );
+}
+
+#[tokio::test]
+async fn probe_reports_dns_failure() {
+    let result = probe_stratum(
+        "stratum+tcp://pool.nonexistent.invalid:3333",
+        "login",
+        StratumHandshake::Login,
+    )
+    .await;
+
+    assert_eq!(
+        result.error.map(|e| e.class),
+        Some(StratumProbeErrorClass::Dns)
+    );
+}
+
+#[tokio::test]
+async fn probe_reports_tls_failure_against_plain_server() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        if let Ok((mut stream, _)) = listener.accept().await {
+            let _ = stream.write_all(b"not a tls server\n").await;
+        }
+    });
+
+    let result = probe_stratum(
+        &format!("stratum+ssl://{address}"),
+        "login",
+        StratumHandshake::Login,
+    )
+    .await;
+
+    assert_eq!(
+        result.error.map(|e| e.class),
+        Some(StratumProbeErrorClass::Tls)
+    );
+}
@@ -0,0 +1,338 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    select,
+    time::{Instant, MissedTickBehavior, interval},
+};
+
+use crate::{
+    LOG_TARGET_APP_LOGIC,
+    configs::{config_mining::ConfigMining, trait_config::ConfigImpl},
+    events_emitter::EventsEmitter,
+    hardware::hardware_status_monitor::HardwareStatusMonitor,
+    mining::{
+        cpu::{benchmark::is_cpu_benchmark_running, manager::CpuManager},
+        gpu::{benchmark::is_gpu_benchmark_running, manager::GpuManager},
+    },
+    tasks_tracker::TasksTrackers,
+};
+
+/// How often device temperatures are compared against the limits
+const THERMAL_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+/// Time given to a device to react to a throttle change before the next one
+/// Temperatures lag behind load changes and the gpu miner needs a restart to pick up a new intensity
+pub const THROTTLE_SETTLE_TIME: Duration = Duration::from_secs(2 * 60);
+/// Share of the selected mode usage applied at each throttle level, the first level is unthrottled
+pub const THROTTLE_STEPS: [u32; 4] = [100, 75, 50, 25];
+/// Range accepted for the temperature limits in celsius
+const MIN_TEMPERATURE_LIMIT: u32 = 50;
+const MAX_TEMPERATURE_LIMIT: u32 = 105;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct ThermalLimitsSettings {
+    pub enabled: bool,
+    /// Temperature in celsius above which cpu threads are lowered
+    pub cpu_max_temperature: u32,
+    /// Temperature in celsius above which gpu intensity is lowered
+    pub gpu_max_temperature: u32,
+    /// Degrees below the limit a device has to cool down to before its usage is raised again
+    pub hysteresis: u32,
+}
+
+impl Default for ThermalLimitsSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            cpu_max_temperature: 90,
+            gpu_max_temperature: 85,
+            hysteresis: 10,
+        }
+    }
+}
+
+impl ThermalLimitsSettings {
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        let limits = MIN_TEMPERATURE_LIMIT..=MAX_TEMPERATURE_LIMIT;
+        if !limits.contains(&self.cpu_max_temperature)
+            || !limits.contains(&self.gpu_max_temperature)
+        {
+            return Err(anyhow::anyhow!(
+                "Temperature limits must be between {MIN_TEMPERATURE_LIMIT} and {MAX_TEMPERATURE_LIMIT}"
+            ));
+        }
+        if self.hysteresis == 0 || self.hysteresis >= MIN_TEMPERATURE_LIMIT {
+            return Err(anyhow::anyhow!(
+                "Hysteresis must be between 1 and {} degrees",
+                MIN_TEMPERATURE_LIMIT - 1
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum ThermalDeviceKind {
+    Cpu,
+    Gpu,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+pub enum ThrottleReason {
+    /// The device went over its temperature limit and its usage was lowered
+    OverTemperature,
+    /// The device cooled down below the limit minus the hysteresis and its usage was raised
+    CooledDown,
+    /// Thermal limits were turned off while the device was throttled
+    Disabled,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ThermalThrottleRecord {
+    pub kind: ThermalDeviceKind,
+    pub reason: ThrottleReason,
+    pub temperature: Option<f32>,
+    pub max_temperature: u32,
+    /// Share of the selected mode usage the miner runs with after this change
+    pub throttle_percentage: u32,
+    pub throttled_at: i64,
+}
+
+impl ThermalThrottleRecord {
+    pub fn new(
+        kind: ThermalDeviceKind,
+        reason: ThrottleReason,
+        temperature: Option<f32>,
+        max_temperature: u32,
+        throttle_percentage: u32,
+    ) -> Self {
+        Self {
+            kind,
+            reason,
+            temperature,
+            max_temperature,
+            throttle_percentage,
+            throttled_at: Utc::now().timestamp(),
+        }
+    }
+}
+
+/// Tracks the throttle level of one kind of device
+#[derive(Debug, Clone, Default)]
+pub struct ThermalThrottle {
+    level: usize,
+    last_change: Option<Instant>,
+}
+
+impl ThermalThrottle {
+    /// Share of the selected mode usage the miner should run with
+    pub fn percentage(&self) -> u32 {
+        THROTTLE_STEPS[self.level]
+    }
+
+    /// Records a temperature reading and returns the reason when the throttle level changed
+    /// The level moves one step at a time and stays put for `THROTTLE_SETTLE_TIME` after each change
+    pub fn observe(
+        &mut self,
+        now: Instant,
+        temperature: f32,
+        max_temperature: u32,
+        hysteresis: u32,
+    ) -> Option<ThrottleReason> {
+        let is_settling = self.last_change.is_some_and(|last_change| {
+            now.saturating_duration_since(last_change) < THROTTLE_SETTLE_TIME
+        });
+        if is_settling {
+            return None;
+        }
+
+        let temperature = f64::from(temperature);
+        let restore_temperature = max_temperature.saturating_sub(hysteresis);
+        let reason =
+            if temperature > f64::from(max_temperature) && self.level < THROTTLE_STEPS.len() - 1 {
+                self.level += 1;
+                ThrottleReason::OverTemperature
+            } else if temperature <= f64::from(restore_temperature) && self.level > 0 {
+                self.level -= 1;
+                ThrottleReason::CooledDown
+            } else {
+                return None;
+            };
+        self.last_change = Some(now);
+        Some(reason)
+    }
+
+    /// Drops the throttle, returns a reason only when the device was throttled
+    pub fn reset(&mut self) -> Option<ThrottleReason> {
+        let was_throttled = self.level > 0;
+        *self = Self::default();
+        was_throttled.then_some(ThrottleReason::Disabled)
+    }
+}
+
+/// Scales a usage value, like a thread count or an intensity, by the throttle percentage
+/// Never goes below 1 so a throttled miner keeps running
+pub fn apply_throttle(value: u32, throttle_percentage: u32) -> u32 {
+    (value.saturating_mul(throttle_percentage) / 100).max(1)
+}
+
+/// Highest temperature reported by any of the devices, None when no reading is available
+pub fn hottest_temperature(temperatures: impl IntoIterator<Item = f32>) -> Option<f32> {
+    temperatures.into_iter().reduce(f32::max)
+}
+
+async fn hottest_cpu_temperature() -> Option<f32> {
+    let devices = HardwareStatusMonitor::current()
+        .get_cpu_public_properties()
+        .await
+        .ok()?;
+    hottest_temperature(
+        devices
+            .into_iter()
+            .filter_map(|device| device.parameters)
+            .map(|parameters| parameters.current_temperature),
+    )
+}
+
+/// Only the devices the gpu miner mines on count, an excluded card is not slowed down by the throttle
+async fn hottest_gpu_temperature() -> Option<f32> {
+    let temperatures = GpuManager::read().await.mining_devices_temperatures().await;
+    hottest_temperature(temperatures)
+}
+
+async fn apply_throttle_change(record: ThermalThrottleRecord) {
+    let temperature = record.temperature.map_or_else(
+        || "unknown".to_string(),
+        |temperature| format!("{temperature:.1}°C"),
+    );
+    match record.reason {
+        ThrottleReason::OverTemperature => {
+            warn!(target: LOG_TARGET_APP_LOGIC, "{:?} temperature {temperature} is above the {}°C limit, lowering usage to {}% of the selected mode", record.kind, record.max_temperature, record.throttle_percentage);
+        }
+        ThrottleReason::CooledDown => {
+            info!(target: LOG_TARGET_APP_LOGIC, "{:?} cooled down to {temperature}, raising usage to {}% of the selected mode", record.kind, record.throttle_percentage);
+        }
+        ThrottleReason::Disabled => {
+            info!(target: LOG_TARGET_APP_LOGIC, "Thermal limits disabled, restoring {:?} usage of the selected mode", record.kind);
+        }
+    }
+
+    let result = match record.kind {
+        ThermalDeviceKind::Cpu => {
+            CpuManager::write()
+                .await
+                .apply_thermal_throttle(record.throttle_percentage)
+                .await
+        }
+        ThermalDeviceKind::Gpu => {
+            GpuManager::write()
+                .await
+                .apply_thermal_throttle(record.throttle_percentage)
+                .await
+        }
+    };
+    if let Err(e) = result {
+        warn!(target: LOG_TARGET_APP_LOGIC, "Failed to apply {:?} thermal throttle: {e}", record.kind);
+    }
+    EventsEmitter::emit_thermal_throttle(record).await;
+}
+
+async fn check_device(
+    kind: ThermalDeviceKind,
+    throttle: &mut ThermalThrottle,
+    limits: &ThermalLimitsSettings,
+) {
+    let max_temperature = match kind {
+        ThermalDeviceKind::Cpu => limits.cpu_max_temperature,
+        ThermalDeviceKind::Gpu => limits.gpu_max_temperature,
+    };
+    let (reason, temperature) = if limits.enabled {
+        let temperature = match kind {
+            ThermalDeviceKind::Cpu => hottest_cpu_temperature().await,
+            ThermalDeviceKind::Gpu => hottest_gpu_temperature().await,
+        };
+        let reason = temperature.and_then(|temperature| {
+            throttle.observe(
+                Instant::now(),
+                temperature,
+                max_temperature,
+                limits.hysteresis,
+            )
+        });
+        (reason, temperature)
+    } else {
+        (throttle.reset(), None)
+    };
+
+    if let Some(reason) = reason {
+        let record = ThermalThrottleRecord::new(
+            kind,
+            reason,
+            temperature,
+            max_temperature,
+            throttle.percentage(),
+        );
+        apply_throttle_change(record).await;
+    }
+}
+
+/// Periodically compares cpu and gpu temperatures against the configured limits and lowers
+/// cpu threads, or the gpu intensity or power limits, while a device is too hot
+pub async fn spawn_thermal_control() {
+    let mut check_interval = interval(THERMAL_CHECK_INTERVAL);
+    check_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let mut shutdown_signal = TasksTrackers::current().core_phase.get_signal().await;
+
+    TasksTrackers::current()
+        .core_phase
+        .get_task_tracker()
+        .await
+        .spawn(async move {
+            let mut cpu_throttle = ThermalThrottle::default();
+            let mut gpu_throttle = ThermalThrottle::default();
+            loop {
+                select! {
+                    _ = shutdown_signal.wait() => {
+                        info!(target: LOG_TARGET_APP_LOGIC, "Shutdown signal received. Stopping thermal control.");
+                        break;
+                    }
+                    _ = check_interval.tick() => {
+                        let limits = ConfigMining::content().await.thermal_limits().clone();
+                        // Throttling changes the cpu threads or restarts the gpu miner, which would
+                        // upset a running benchmark
+                        if !is_cpu_benchmark_running() {
+                            check_device(ThermalDeviceKind::Cpu, &mut cpu_throttle, &limits).await;
+                        }
+                        if !is_gpu_benchmark_running() {
+                            check_device(ThermalDeviceKind::Gpu, &mut gpu_throttle, &limits).await;
+                        }
+                    }
+                }
+            }
+        });
+}
@@ -0,0 +1,159 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use super::thermal::{
+    THROTTLE_SETTLE_TIME, THROTTLE_STEPS, ThermalLimitsSettings, ThermalThrottle, ThrottleReason,
+    apply_throttle, hottest_temperature,
+};
+
+const MAX_TEMPERATURE: u32 = 85;
+const HYSTERESIS: u32 = 10;
+
+#[test]
+fn throttle_lowers_usage_above_the_limit() {
+    let start = Instant::now();
+    let mut throttle = ThermalThrottle::default();
+
+    assert_eq!(
+        throttle.observe(start, 85.0, MAX_TEMPERATURE, HYSTERESIS),
+        None
+    );
+    assert_eq!(
+        throttle.observe(start, 86.0, MAX_TEMPERATURE, HYSTERESIS),
+        Some(ThrottleReason::OverTemperature)
+    );
+    assert_eq!(throttle.percentage(), THROTTLE_STEPS[1]);
+}
+
+#[test]
+fn throttle_waits_for_the_device_to_settle_between_changes() {
+    let start = Instant::now();
+    let mut throttle = ThermalThrottle::default();
+
+    throttle.observe(start, 90.0, MAX_TEMPERATURE, HYSTERESIS);
+    assert_eq!(
+        throttle.observe(
+            start + Duration::from_secs(30),
+            90.0,
+            MAX_TEMPERATURE,
+            HYSTERESIS
+        ),
+        None
+    );
+    assert_eq!(
+        throttle.observe(
+            start + THROTTLE_SETTLE_TIME,
+            90.0,
+            MAX_TEMPERATURE,
+            HYSTERESIS
+        ),
+        Some(ThrottleReason::OverTemperature)
+    );
+    assert_eq!(throttle.percentage(), THROTTLE_STEPS[2]);
+}
+
+#[test]
+fn throttle_stops_at_the_lowest_step() {
+    let start = Instant::now();
+    let mut throttle = ThermalThrottle::default();
+
+    for step in 0..THROTTLE_STEPS.len() + 2 {
+        let now = start + THROTTLE_SETTLE_TIME * u32::try_from(step).unwrap();
+        throttle.observe(now, 95.0, MAX_TEMPERATURE, HYSTERESIS);
+    }
+    assert_eq!(
+        throttle.percentage(),
+        THROTTLE_STEPS[THROTTLE_STEPS.len() - 1]
+    );
+}
+
+#[test]
+fn throttle_restores_usage_only_below_the_hysteresis() {
+    let start = Instant::now();
+    let mut throttle = ThermalThrottle::default();
+    throttle.observe(start, 90.0, MAX_TEMPERATURE, HYSTERESIS);
+
+    let later = start + THROTTLE_SETTLE_TIME;
+    assert_eq!(
+        throttle.observe(later, 80.0, MAX_TEMPERATURE, HYSTERESIS),
+        None
+    );
+    assert_eq!(throttle.percentage(), THROTTLE_STEPS[1]);
+    assert_eq!(
+        throttle.observe(later, 75.0, MAX_TEMPERATURE, HYSTERESIS),
+        Some(ThrottleReason::CooledDown)
+    );
+    assert_eq!(throttle.percentage(), THROTTLE_STEPS[0]);
+    assert_eq!(
+        throttle.observe(
+            later + THROTTLE_SETTLE_TIME,
+            60.0,
+            MAX_TEMPERATURE,
+            HYSTERESIS
+        ),
+        None
+    );
+}
+
+#[test]
+fn reset_reports_only_when_throttled() {
+    let mut throttle = ThermalThrottle::default();
+    assert_eq!(throttle.reset(), None);
+
+    throttle.observe(Instant::now(), 90.0, MAX_TEMPERATURE, HYSTERESIS);
+    assert_eq!(throttle.reset(), Some(ThrottleReason::Disabled));
+    assert_eq!(throttle.percentage(), THROTTLE_STEPS[0]);
+}
+
+#[test]
+fn apply_throttle_scales_and_keeps_at_least_one() {
+    assert_eq!(apply_throttle(16, 100), 16);
+    assert_eq!(apply_throttle(16, 50), 8);
+    assert_eq!(apply_throttle(3, 25), 1);
+}
+
+#[test]
+fn hottest_temperature_picks_the_highest_reading() {
+    assert_eq!(hottest_temperature([61.5, 78.0, 70.0]), Some(78.0));
+    assert_eq!(hottest_temperature([]), None);
+}
+
+#[test]
+fn limits_validation_rejects_out_of_range_values() {
+    assert!(ThermalLimitsSettings::default().validate().is_ok());
+
+    let too_low = ThermalLimitsSettings {
+        cpu_max_temperature: 30,
+        ..Default::default()
+    };
+    assert!(too_low.validate().is_err());
+
+    let no_hysteresis = ThermalLimitsSettings {
+        hysteresis: 0,
+        ..Default::default()
+    };
+    assert!(no_hysteresis.validate().is_err());
+}
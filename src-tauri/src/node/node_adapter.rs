@@ -182,17 +182,22 @@ impl NodeAdapterService {
     }
 
     pub async fn get_recent_block_stats(&self, limit: u64) -> Result<Vec<LocalBlockStats>, Error> {
-        let limit = limit.min(100);
+        self.get_block_stats_page(None, limit).await
+    }
+
+    /// Block stats below `before_height`, newest first. None starts at the tip.
+    pub async fn get_block_stats_page(
+        &self,
+        before_height: Option<u64>,
+        limit: u64,
+    ) -> Result<Vec<LocalBlockStats>, Error> {
+        let Some(request) = block_stats_request(before_height, limit) else {
+            return Ok(Vec::new());
+        };
         let mut grpc_client = BaseNodeGrpcClient::connect(self.connection_address.clone())
             .await
             .map_err(|e| anyhow!("Failed to connect to gRPC: {e}"))?;
 
-        let request = ListHeadersRequest {
-            from_height: 0,
-            num_headers: limit,
-            sorting: 0, // SORTING_DESC (newest first)
-        };
-
         let response = grpc_client
             .list_headers(request)
             .await
@@ -811,3 +816,30 @@ pub enum NodeStatusMonitorError {
     #[error("Node not started")]
     NodeNotStarted,
 }
+
+/// Headers request for the blocks below `before_height`, newest first. None starts at the tip.
+/// The node reads a `from_height` of 0 as the tip, so the genesis block alone is requested in
+/// ascending order. Returns None when there is nothing below `before_height`.
+pub fn block_stats_request(before_height: Option<u64>, limit: u64) -> Option<ListHeadersRequest> {
+    const SORTING_DESC: i32 = 0;
+    const SORTING_ASC: i32 = 1;
+    let limit = limit.min(100);
+    match before_height {
+        None => Some(ListHeadersRequest {
+            from_height: 0,
+            num_headers: limit,
+            sorting: SORTING_DESC,
+        }),
+        Some(0) => None,
+        Some(1) => Some(ListHeadersRequest {
+            from_height: 0,
+            num_headers: 1,
+            sorting: SORTING_ASC,
+        }),
+        Some(height) => Some(ListHeadersRequest {
+            from_height: height - 1,
+            num_headers: limit,
+            sorting: SORTING_DESC,
+        }),
+    }
+}
@@ -32,6 +32,7 @@ use crate::{
     UniverseAppState,
     auto_launcher::AutoLauncher,
     configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    mining::thermal::spawn_thermal_control,
     progress_trackers::{
         progress_plans::SetupStep,
         progress_stepper::{ProgressStepper, ProgressStepperBuilder},
@@ -158,6 +159,8 @@ impl SetupPhaseImpl for CoreSetupPhase {
                     .init_periodic_updates(&self.app_handle)
                     .await?;
 
+                spawn_thermal_control().await;
+
                 AutoLauncher::current()
                     .initialize_auto_launcher(self.app_configuration.is_auto_launcher_enabled)
                     .await?;
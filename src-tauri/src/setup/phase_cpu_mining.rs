@@ -26,6 +26,7 @@ use crate::{
     configs::{config_core::ConfigCore, trait_config::ConfigImpl},
     hardware::hardware_status_monitor::HardwareStatusMonitor,
     internal_wallet::InternalWallet,
+    mining::cpu::{consts::CpuMinerType, manager::CpuManager},
     mm_proxy_manager::StartConfig,
     progress_trackers::{
         progress_plans::SetupStep,
@@ -177,9 +178,26 @@ impl SetupPhaseImpl for CpuMiningSetupPhase {
 
         progress_stepper
             .complete_step(SetupStep::BinariesCpuMiner, || async {
-                binary_resolver
-                    .initialize_binary(Binaries::Xmrig, cpu_miner_binary_progress_tracker)
+                let xmrig_initialization_result = binary_resolver
+                    .initialize_binary(
+                        CpuMinerType::Xmrig.binary(),
+                        cpu_miner_binary_progress_tracker,
+                    )
+                    .await;
+
+                CpuManager::write()
                     .await
+                    .load_miner(
+                        CpuMinerType::Xmrig,
+                        xmrig_initialization_result.is_ok(),
+                        xmrig_initialization_result
+                            .as_ref()
+                            .err()
+                            .map(|e| e.to_string()),
+                    )
+                    .await;
+
+                xmrig_initialization_result
             })
             .await?;
 
@@ -261,6 +279,8 @@ impl SetupPhaseImpl for CpuMiningSetupPhase {
             })
             .await?;
 
+        CpuManager::write().await.load_saved_miner().await?;
+
         Ok(())
     }
 
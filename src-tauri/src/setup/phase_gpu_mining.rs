@@ -164,7 +164,8 @@ impl SetupPhaseImpl for GpuMiningSetupPhase {
 
         let binary_resolver = BinaryResolver::current();
 
-        let lolminer_binary_progress_tracker =
+        // Both miners report their download progress to the same step
+        let gpu_miner_binary_progress_tracker =
             progress_stepper.track_step_incrementally(SetupStep::BinariesGpuMiner);
 
         progress_stepper
@@ -174,7 +175,10 @@ impl SetupPhaseImpl for GpuMiningSetupPhase {
                 // LolMiner is supported on Windows | Linux
                 if GpuMinerType::LolMiner.is_supported_on_current_platform() {
                     let lolminer_initialization_result = binary_resolver
-                        .initialize_binary(Binaries::LolMiner, lolminer_binary_progress_tracker)
+                        .initialize_binary(
+                            Binaries::LolMiner,
+                            gpu_miner_binary_progress_tracker.clone(),
+                        )
                         .await;
 
                     let lolminer_err = lolminer_initialization_result.as_ref().err();
@@ -195,9 +199,33 @@ impl SetupPhaseImpl for GpuMiningSetupPhase {
                         .await;
                 }
 
+                // Glytex is supported on Windows | Linux
+                if GpuMinerType::Glytex.is_supported_on_current_platform() {
+                    let glytex_initialization_result = binary_resolver
+                        .initialize_binary(Binaries::Glytex, gpu_miner_binary_progress_tracker)
+                        .await;
+
+                    let glytex_err = glytex_initialization_result.as_ref().err();
+
+                    if glytex_initialization_result.is_ok() {
+                        is_any_miner_succeeded = true;
+                    } else {
+                        error!(target: LOG_TARGET_APP_LOGIC, "Glytex initialization error: {:?}", glytex_err);
+                    }
+
+                    GpuManager::write()
+                        .await
+                        .load_miner(
+                            GpuMinerType::Glytex,
+                            glytex_initialization_result.is_ok(),
+                            glytex_err.map(|e| e.to_string()),
+                        )
+                        .await;
+                }
+
                 if !is_any_miner_succeeded {
                     return Err(anyhow::anyhow!(
-                        "Failed to initialize GPU miner binary: LolMiner"
+                        "Failed to initialize any GPU miner binary"
                     ));
                 }
 
@@ -314,10 +314,14 @@ impl SetupManager {
         ConfigPools::initialize(app_handle.clone()).await;
         ConfigMcp::initialize(app_handle.clone()).await;
 
-        // Initialize MCP server with node status receiver for chain tools
+        // Initialize MCP server with the status receivers backing chain tools and resources
         crate::mcp::server::McpServerManager::initialize(
             state.node_status_watch_rx.clone(),
+            state.cpu_miner_status_watch_rx.clone(),
+            state.gpu_miner_status_watch_rx.clone(),
+            state.wallet_state_watch_rx.clone(),
             state.wallet_manager.clone(),
+            state.node_manager.clone(),
         )
         .await;
 
@@ -0,0 +1,457 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+//! Time-of-use electricity tariffs.
+//!
+//! A tariff table lists the price per kWh for bands of the day. Cheap bands are
+//! turned into `Mine` windows on the [`EventScheduler`] and expensive bands into
+//! windows that switch CPU and GPU mining off, so mining follows the tariff
+//! without any further polling.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, anyhow, bail};
+use chrono::{Duration, Local, TimeZone};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    LOG_TARGET_APP_LOGIC,
+    event_scheduler::{DayOfWeek, EventScheduler, SchedulerEventTiming, SchedulerEventType},
+};
+
+/// Prefix of the scheduler event IDs created from a tariff table.
+pub const TARIFF_EVENT_PREFIX: &str = "tariff_";
+const MINUTES_PER_DAY: u32 = 24 * 60;
+
+/// A band of the day with a single electricity price.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TariffBand {
+    /// Optional label, e.g. "Off-peak"
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Start of the band in 24-hour `HH:MM`
+    pub start: String,
+    /// End of the band in 24-hour `HH:MM`. A band ending at or before its start runs past midnight.
+    pub end: String,
+    /// Days the band starts on. Empty means every day.
+    #[serde(default)]
+    pub weekdays: Vec<DayOfWeek>,
+    pub price_per_kwh: f64,
+}
+
+impl TariffBand {
+    fn label(&self, index: usize) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("Band {} ({}-{})", index + 1, self.start, self.end))
+    }
+
+    fn clock_times(&self) -> Result<((u32, u32), (u32, u32)), anyhow::Error> {
+        Ok((parse_clock_time(&self.start)?, parse_clock_time(&self.end)?))
+    }
+
+    /// Scheduler timing covering this band.
+    pub fn to_timing(&self) -> Result<SchedulerEventTiming, anyhow::Error> {
+        let ((start_hour, start_minute), (end_hour, end_minute)) = self.clock_times()?;
+        SchedulerEventTiming::parse_between_variant_24h(
+            start_hour.into(),
+            start_minute.into(),
+            end_hour.into(),
+            end_minute.into(),
+        )
+        .and_then(|timing| timing.with_calendar(self.weekdays.clone(), None))
+        .map_err(|e| anyhow!("{}", e))
+    }
+
+    /// How many hours of the week this band covers.
+    pub fn hours_per_week(&self) -> Result<f64, anyhow::Error> {
+        let ((start_hour, start_minute), (end_hour, end_minute)) = self.clock_times()?;
+        let start = start_hour * 60 + start_minute;
+        let end = end_hour * 60 + end_minute;
+        // A band whose end equals its start covers the whole day
+        let minutes = match (end + MINUTES_PER_DAY - start) % MINUTES_PER_DAY {
+            0 => MINUTES_PER_DAY,
+            minutes => minutes,
+        };
+        let days = if self.weekdays.is_empty() {
+            7
+        } else {
+            let mut days = self.weekdays.clone();
+            days.sort_unstable();
+            days.dedup();
+            days.len()
+        };
+        Ok(f64::from(minutes) / 60.0 * days as f64)
+    }
+}
+
+/// A user-provided tariff: the price of electricity across the week.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TariffTable {
+    pub bands: Vec<TariffBand>,
+}
+
+impl TariffTable {
+    /// Loads a tariff table from a `.json` or `.csv` file.
+    ///
+    /// JSON files hold `{"bands": [...]}` with the fields of [`TariffBand`].
+    /// CSV files have a `start,end,weekdays,price_per_kwh[,name]` header and one
+    /// band per line; weekdays are separated by `;` and may be left empty.
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read tariff file {}", path.display()))?;
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_lowercase);
+        let table = match extension.as_deref() {
+            Some("json") => serde_json::from_str(&contents)
+                .with_context(|| format!("Invalid tariff JSON in {}", path.display()))?,
+            Some("csv") => Self::from_csv(&contents)?,
+            _ => bail!(
+                "Unsupported tariff file {}. Use a .json or .csv file",
+                path.display()
+            ),
+        };
+        Ok(table)
+    }
+
+    /// Parses a tariff table from CSV text. Blank lines and lines starting with `#` are ignored.
+    pub fn from_csv(contents: &str) -> Result<Self, anyhow::Error> {
+        let mut lines = contents
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+        let (_, header) = lines.next().ok_or_else(|| anyhow!("Tariff CSV is empty"))?;
+        let columns: Vec<String> = header
+            .split(',')
+            .map(|column| column.trim().to_lowercase())
+            .collect();
+        let column = |name: &str| columns.iter().position(|column| column == name);
+        let (Some(start), Some(end), Some(price)) =
+            (column("start"), column("end"), column("price_per_kwh"))
+        else {
+            bail!("Tariff CSV header must contain start, end and price_per_kwh columns");
+        };
+        let (weekdays, name) = (column("weekdays"), column("name"));
+
+        let bands = lines
+            .map(|(line_number, line)| {
+                let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+                let field = |index: Option<usize>| {
+                    index
+                        .and_then(|index| fields.get(index))
+                        .copied()
+                        .unwrap_or_default()
+                };
+                let band = TariffBand {
+                    name: Some(field(name))
+                        .filter(|name| !name.is_empty())
+                        .map(str::to_string),
+                    start: field(Some(start)).to_string(),
+                    end: field(Some(end)).to_string(),
+                    weekdays: field(weekdays)
+                        .split(';')
+                        .filter(|day| !day.trim().is_empty())
+                        .map(|day| {
+                            DayOfWeek::parse(day)
+                                .ok_or_else(|| anyhow!("Invalid weekday {:?}", day))
+                        })
+                        .collect::<Result<_, _>>()
+                        .with_context(|| format!("Tariff CSV line {}", line_number))?,
+                    price_per_kwh: field(Some(price)).parse().with_context(|| {
+                        format!("Tariff CSV line {}: invalid price_per_kwh", line_number)
+                    })?,
+                };
+                Ok(band)
+            })
+            .collect::<Result<_, anyhow::Error>>()?;
+        Ok(Self { bands })
+    }
+
+    /// Checks that every band has valid times, a non-negative price and that
+    /// no two bands cover the same time.
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        if self.bands.is_empty() {
+            bail!("Tariff has no bands");
+        }
+        let mut schedules = Vec::with_capacity(self.bands.len());
+        for (index, band) in self.bands.iter().enumerate() {
+            let label = band.label(index);
+            if !band.price_per_kwh.is_finite() || band.price_per_kwh < 0.0 {
+                bail!("{}: price per kWh must be zero or more", label);
+            }
+            let SchedulerEventTiming::Between(payload) =
+                band.to_timing().with_context(|| label.clone())?
+            else {
+                bail!("{}: expected a time window", label);
+            };
+            let schedule = payload
+                .to_cron_schedule()
+                .map_err(|e| anyhow!("{}: {}", label, e))?;
+            schedules.push((label, schedule));
+        }
+
+        // A week (plus a day for bands running past midnight) covers every combination of bands
+        let from = Local
+            .with_ymd_and_hms(2025, 1, 6, 0, 0, 0)
+            .single()
+            .unwrap_or_else(Local::now);
+        let until = from + Duration::days(8);
+        for (index, (label, schedule)) in schedules.iter().enumerate() {
+            if let Some((other_label, _)) = schedules[index + 1..]
+                .iter()
+                .find(|(_, other)| schedule.overlaps_within(other, from, until))
+            {
+                bail!("Tariff bands {:?} and {:?} overlap", label, other_label);
+            }
+        }
+        Ok(())
+    }
+
+    /// Turns the table into scheduler events: a `Mine` window for every cheap
+    /// band and CPU/GPU off windows for every expensive band.
+    ///
+    /// ### Returns
+    /// * `Vec<(event_id, event_type, timing)>` - Events to schedule
+    pub fn plan_events(
+        &self,
+        settings: &TariffSettings,
+    ) -> Result<Vec<(String, SchedulerEventType, SchedulerEventTiming)>, anyhow::Error> {
+        let mut events = Vec::new();
+        for (index, band) in self.bands.iter().enumerate() {
+            let timing = band.to_timing()?;
+            if settings.is_cheap(band) {
+                events.push((
+                    format!("{}{}_mine", TARIFF_EVENT_PREFIX, index),
+                    SchedulerEventType::Mine {
+                        mining_mode: settings.cheap_mining_mode.clone(),
+                    },
+                    timing,
+                ));
+            } else {
+                events.push((
+                    format!("{}{}_cpu_off", TARIFF_EVENT_PREFIX, index),
+                    SchedulerEventType::CpuMining { enabled: false },
+                    timing.clone(),
+                ));
+                events.push((
+                    format!("{}{}_gpu_off", TARIFF_EVENT_PREFIX, index),
+                    SchedulerEventType::GpuMining { enabled: false },
+                    timing,
+                ));
+            }
+        }
+        Ok(events)
+    }
+
+    /// Estimates what mining costs in each band with the given settings.
+    pub fn estimate_costs(
+        &self,
+        settings: &TariffSettings,
+    ) -> Result<Vec<TariffBandEstimate>, anyhow::Error> {
+        self.bands
+            .iter()
+            .enumerate()
+            .map(|(index, band)| {
+                let mining_mode = settings
+                    .is_cheap(band)
+                    .then(|| settings.cheap_mining_mode.clone());
+                let watts = mining_mode
+                    .as_ref()
+                    .map(|mode| settings.wattage_for(mode))
+                    .unwrap_or_default();
+                let cost_per_hour = f64::from(watts) / 1000.0 * band.price_per_kwh;
+                let hours_per_week = band.hours_per_week()?;
+                Ok(TariffBandEstimate {
+                    band: band.label(index),
+                    price_per_kwh: band.price_per_kwh,
+                    mining_mode,
+                    watts,
+                    hours_per_week,
+                    cost_per_hour,
+                    cost_per_week: cost_per_hour * hours_per_week,
+                })
+            })
+            .collect()
+    }
+}
+
+/// How tariff bands are turned into mining, stored in the mining config.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TariffSettings {
+    /// Tariff table file (.json or .csv)
+    pub file_path: Option<PathBuf>,
+    /// Mining mode used during cheap bands
+    pub cheap_mining_mode: String,
+    /// Bands at or below this price per kWh are cheap; the rest pause mining
+    pub max_cheap_price_per_kwh: f64,
+    /// Estimated power draw of the whole rig per mining mode, in watts
+    pub mode_wattages: HashMap<String, u32>,
+}
+
+impl Default for TariffSettings {
+    fn default() -> Self {
+        Self {
+            file_path: None,
+            cheap_mining_mode: "Ludicrous".to_string(),
+            max_cheap_price_per_kwh: 0.0,
+            // Rough figures for a desktop with one GPU; users should set their own
+            mode_wattages: HashMap::from([
+                ("Eco".to_string(), 60),
+                ("Turbo".to_string(), 120),
+                ("Ludicrous".to_string(), 350),
+                ("Custom".to_string(), 250),
+            ]),
+        }
+    }
+}
+
+impl TariffSettings {
+    pub fn is_cheap(&self, band: &TariffBand) -> bool {
+        band.price_per_kwh <= self.max_cheap_price_per_kwh
+    }
+
+    /// Configured wattage for a mining mode, or 0 if unknown.
+    pub fn wattage_for(&self, mining_mode: &str) -> u32 {
+        self.mode_wattages.get(mining_mode).copied().unwrap_or_else(|| {
+            warn!(target: LOG_TARGET_APP_LOGIC, "No wattage configured for mining mode {:?}", mining_mode);
+            0
+        })
+    }
+}
+
+/// Estimated cost of a tariff band with the current settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TariffBandEstimate {
+    pub band: String,
+    pub price_per_kwh: f64,
+    /// Mode mined in during the band, `None` if mining is paused
+    pub mining_mode: Option<String>,
+    pub watts: u32,
+    pub hours_per_week: f64,
+    pub cost_per_hour: f64,
+    pub cost_per_week: f64,
+}
+
+/// Loads the configured tariff file and estimates the cost of each band.
+pub fn estimate_tariff_costs(
+    settings: &TariffSettings,
+) -> Result<Vec<TariffBandEstimate>, anyhow::Error> {
+    load_configured_table(settings)?.estimate_costs(settings)
+}
+
+fn load_configured_table(settings: &TariffSettings) -> Result<TariffTable, anyhow::Error> {
+    let path = settings
+        .file_path
+        .as_ref()
+        .ok_or_else(|| anyhow!("No tariff file configured"))?;
+    let table = TariffTable::load(path)?;
+    table.validate()?;
+    Ok(table)
+}
+
+/// Events and cost estimates planned from a validated tariff file, ready to be scheduled.
+pub struct TariffSchedule {
+    events: Vec<(String, SchedulerEventType, SchedulerEventTiming)>,
+    estimates: Vec<TariffBandEstimate>,
+}
+
+/// Loads and validates the tariff file from `settings` and plans its events
+/// without touching the scheduler.
+///
+/// ### Returns
+/// * `Ok(TariffSchedule)` - Events and cost estimate for each band
+/// * `Err` - If the file is missing or invalid
+pub fn plan_tariff_schedule(settings: &TariffSettings) -> Result<TariffSchedule, anyhow::Error> {
+    let table = load_configured_table(settings)?;
+    Ok(TariffSchedule {
+        events: table.plan_events(settings)?,
+        estimates: table.estimate_costs(settings)?,
+    })
+}
+
+/// Replaces any previously scheduled tariff events with the planned ones.
+///
+/// ### Returns
+/// * `Ok(Vec<TariffBandEstimate>)` - Cost estimate for each band
+/// * `Err` - If an event could not be scheduled
+pub async fn apply_tariff_schedule(
+    schedule: TariffSchedule,
+) -> Result<Vec<TariffBandEstimate>, anyhow::Error> {
+    let TariffSchedule { events, estimates } = schedule;
+    let band_count = estimates.len();
+
+    clear_tariff_schedule().await?;
+    let scheduler = EventScheduler::instance();
+    for (event_id, event_type, timing) in events {
+        if let Err(e) = scheduler
+            .schedule_event(event_type, event_id.clone(), timing)
+            .await
+        {
+            // Don't leave half a tariff behind
+            clear_tariff_schedule().await?;
+            bail!("Failed to schedule tariff event {:?}: {}", event_id, e);
+        }
+    }
+    info!(target: LOG_TARGET_APP_LOGIC, "Scheduled {} tariff bands", band_count);
+    Ok(estimates)
+}
+
+/// Removes every scheduler event created from a tariff table.
+pub async fn clear_tariff_schedule() -> Result<(), anyhow::Error> {
+    let scheduler = EventScheduler::instance();
+    let events = scheduler
+        .list_events()
+        .await
+        .map_err(|e| anyhow!("{}", e))?;
+    for event in events
+        .into_iter()
+        .filter(|event| event.id.starts_with(TARIFF_EVENT_PREFIX))
+    {
+        scheduler
+            .remove_event(event.id)
+            .await
+            .map_err(|e| anyhow!("{}", e))?;
+    }
+    Ok(())
+}
+
+/// Parses a 24-hour `HH:MM` time.
+fn parse_clock_time(time: &str) -> Result<(u32, u32), anyhow::Error> {
+    let invalid = || anyhow!("Invalid time {:?}. Use 24-hour HH:MM (e.g., 07:30)", time);
+    let (hour, minute) = time.trim().split_once(':').ok_or_else(invalid)?;
+    let hour: u32 = hour.parse().map_err(|_| invalid())?;
+    let minute: u32 = minute.parse().map_err(|_| invalid())?;
+    if hour > 23 || minute > 59 {
+        return Err(invalid());
+    }
+    Ok((hour, minute))
+}
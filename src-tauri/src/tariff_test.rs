@@ -0,0 +1,273 @@
+// Copyright 2024. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::io::Write;
+
+use test_case::test_case;
+
+use crate::event_scheduler::{DayOfWeek, SchedulerEventTiming, SchedulerEventType};
+use crate::tariff::{TariffBand, TariffSettings, TariffTable, plan_tariff_schedule};
+
+fn band(start: &str, end: &str, price_per_kwh: f64) -> TariffBand {
+    TariffBand {
+        name: None,
+        start: start.to_string(),
+        end: end.to_string(),
+        weekdays: Vec::new(),
+        price_per_kwh,
+    }
+}
+
+fn settings(max_cheap_price_per_kwh: f64) -> TariffSettings {
+    TariffSettings {
+        max_cheap_price_per_kwh,
+        ..TariffSettings::default()
+    }
+}
+
+fn day_night() -> TariffTable {
+    TariffTable {
+        bands: vec![band("23:00", "07:00", 0.10), band("07:00", "23:00", 0.30)],
+    }
+}
+
+// =============================================================================
+// Loading
+// =============================================================================
+
+#[test]
+fn csv_tariff_parses_bands() {
+    let csv = "\
+# Economy 7
+start,end,weekdays,price_per_kwh,name
+00:00,07:00,,0.09,Night
+07:00,00:00,Mon;Tue,0.28,
+";
+
+    let table = TariffTable::from_csv(csv).expect("Valid CSV");
+
+    assert_eq!(
+        table.bands,
+        vec![
+            TariffBand {
+                name: Some("Night".to_string()),
+                ..band("00:00", "07:00", 0.09)
+            },
+            TariffBand {
+                weekdays: vec![DayOfWeek::Monday, DayOfWeek::Tuesday],
+                ..band("07:00", "00:00", 0.28)
+            },
+        ]
+    );
+}
+
+#[test]
+fn csv_tariff_columns_can_be_reordered() {
+    let table = TariffTable::from_csv("price_per_kwh,end,start\n0.12,06:00,22:00\n").unwrap();
+
+    assert_eq!(table.bands, vec![band("22:00", "06:00", 0.12)]);
+}
+
+#[test_case("start,end\n00:00,07:00\n" ; "missing price column")]
+#[test_case("start,end,price_per_kwh\n00:00,07:00,cheap\n" ; "invalid price")]
+#[test_case("start,end,weekdays,price_per_kwh\n00:00,07:00,Funday,0.1\n" ; "invalid weekday")]
+#[test_case("" ; "empty file")]
+fn csv_tariff_rejects_invalid_input(csv: &str) {
+    assert!(TariffTable::from_csv(csv).is_err());
+}
+
+#[test]
+fn tariff_loads_from_json_file() {
+    let mut file = tempfile::Builder::new().suffix(".json").tempfile().unwrap();
+    write!(
+        file,
+        r#"{{"bands": [{{"start": "23:00", "end": "07:00", "weekdays": ["Saturday"], "price_per_kwh": 0.1}}]}}"#
+    )
+    .unwrap();
+
+    let table = TariffTable::load(file.path()).expect("Valid JSON tariff");
+
+    assert_eq!(
+        table.bands,
+        vec![TariffBand {
+            weekdays: vec![DayOfWeek::Saturday],
+            ..band("23:00", "07:00", 0.1)
+        }]
+    );
+}
+
+#[test]
+fn tariff_schedule_is_not_planned_for_overlapping_bands() {
+    let mut file = tempfile::Builder::new().suffix(".csv").tempfile().unwrap();
+    write!(
+        file,
+        "start,end,price_per_kwh\n22:00,06:00,0.10\n05:00,09:00,0.20\n"
+    )
+    .unwrap();
+    let settings = TariffSettings {
+        file_path: Some(file.path().to_path_buf()),
+        ..settings(0.15)
+    };
+
+    assert!(plan_tariff_schedule(&settings).is_err());
+    assert!(
+        plan_tariff_schedule(&TariffSettings {
+            file_path: None,
+            ..settings
+        })
+        .is_err()
+    );
+}
+
+#[test]
+fn tariff_rejects_unknown_file_types() {
+    let file = tempfile::Builder::new().suffix(".txt").tempfile().unwrap();
+
+    assert!(TariffTable::load(file.path()).is_err());
+}
+
+// =============================================================================
+// Validation
+// =============================================================================
+
+#[test]
+fn non_overlapping_bands_are_valid() {
+    assert!(day_night().validate().is_ok());
+}
+
+#[test]
+fn overlapping_bands_are_rejected() {
+    let table = TariffTable {
+        bands: vec![band("22:00", "06:00", 0.10), band("05:00", "09:00", 0.20)],
+    };
+
+    assert!(table.validate().is_err());
+}
+
+#[test]
+fn bands_on_different_weekdays_may_share_times() {
+    let table = TariffTable {
+        bands: vec![
+            TariffBand {
+                weekdays: vec![DayOfWeek::Saturday, DayOfWeek::Sunday],
+                ..band("08:00", "20:00", 0.15)
+            },
+            TariffBand {
+                weekdays: vec![DayOfWeek::Monday, DayOfWeek::Friday],
+                ..band("08:00", "20:00", 0.30)
+            },
+        ],
+    };
+
+    assert!(table.validate().is_ok());
+}
+
+#[test_case(band("25:00", "07:00", 0.1) ; "invalid start time")]
+#[test_case(band("07:00", "7pm", 0.1) ; "invalid end time")]
+#[test_case(band("07:00", "19:00", -0.1) ; "negative price")]
+fn invalid_bands_are_rejected(band: TariffBand) {
+    let table = TariffTable { bands: vec![band] };
+
+    assert!(table.validate().is_err());
+}
+
+#[test]
+fn empty_tariff_is_rejected() {
+    assert!(TariffTable::default().validate().is_err());
+}
+
+// =============================================================================
+// Scheduling and cost estimates
+// =============================================================================
+
+#[test]
+fn cheap_bands_mine_and_expensive_bands_pause() {
+    let events = day_night().plan_events(&settings(0.15)).unwrap();
+
+    let summary: Vec<(&str, &SchedulerEventType)> = events
+        .iter()
+        .map(|(id, event_type, _)| (id.as_str(), event_type))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                "tariff_0_mine",
+                &SchedulerEventType::Mine {
+                    mining_mode: "Ludicrous".to_string()
+                }
+            ),
+            (
+                "tariff_1_cpu_off",
+                &SchedulerEventType::CpuMining { enabled: false }
+            ),
+            (
+                "tariff_1_gpu_off",
+                &SchedulerEventType::GpuMining { enabled: false }
+            ),
+        ]
+    );
+    assert!(
+        events
+            .iter()
+            .all(|(_, _, timing)| matches!(timing, SchedulerEventTiming::Between(_)))
+    );
+}
+
+#[test]
+fn band_timing_matches_band_hours() {
+    let SchedulerEventTiming::Between(payload) = band("23:00", "07:00", 0.1).to_timing().unwrap()
+    else {
+        panic!("Expected Between timing");
+    };
+
+    let schedule = payload.to_cron_schedule().unwrap();
+    assert_eq!(schedule.start_time.pattern.to_string(), "0 23 * * *");
+    assert_eq!(schedule.end_time.pattern.to_string(), "0 7 * * *");
+}
+
+#[test_case(band("23:00", "07:00", 0.1), 56.0 ; "band past midnight")]
+#[test_case(band("00:00", "00:00", 0.1), 168.0 ; "whole day")]
+#[test_case(TariffBand { weekdays: vec![DayOfWeek::Saturday, DayOfWeek::Sunday], ..band("09:30", "17:00", 0.1) }, 15.0 ; "weekends only")]
+fn hours_per_week_counts_band_length_and_days(band: TariffBand, expected: f64) {
+    assert!((band.hours_per_week().unwrap() - expected).abs() < f64::EPSILON);
+}
+
+#[test]
+fn cost_estimates_use_mode_wattage_and_zero_when_paused() {
+    let mut settings = settings(0.15);
+    settings.cheap_mining_mode = "Turbo".to_string();
+    settings.mode_wattages.insert("Turbo".to_string(), 200);
+
+    let estimates = day_night().estimate_costs(&settings).unwrap();
+
+    let night = &estimates[0];
+    assert_eq!(night.mining_mode.as_deref(), Some("Turbo"));
+    assert_eq!(night.watts, 200);
+    assert!((night.cost_per_hour - 0.02).abs() < 1e-9);
+    assert!((night.cost_per_week - 0.02 * 56.0).abs() < 1e-9);
+
+    let day = &estimates[1];
+    assert_eq!(day.mining_mode, None);
+    assert_eq!(day.watts, 0);
+    assert!(day.cost_per_week.abs() < f64::EPSILON);
+}
@@ -62,3 +62,33 @@ pub fn convert_to_string(path: PathBuf) -> Result<String, anyhow::Error> {
         .map(|s| s.to_string())
         .ok_or_else(|| anyhow!("Could not convert path to string"))
 }
+
+/// Writes a file only the current user can read, for files holding secrets.
+/// The contents go to a temporary file created with the final permissions and are then
+/// renamed into place, so the secret is never readable by others, not even briefly.
+pub fn write_private_file(path: &Path, contents: &[u8]) -> Result<(), anyhow::Error> {
+    use std::io::Write;
+
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    let temp_path = PathBuf::from(temp_path);
+    // A leftover from an interrupted write may have other permissions, create_new needs it gone
+    if temp_path.exists() {
+        std::fs::remove_file(&temp_path)?;
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(&temp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+
+    std::fs::rename(&temp_path, path)?;
+    Ok(())
+}
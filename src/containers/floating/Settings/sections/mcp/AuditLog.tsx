@@ -23,6 +23,8 @@ const statusIcons: Record<string, string> = {
     Error: '❌',
     Denied: '🚫',
     RateLimited: '⏱️',
+    BudgetExceeded: '💸',
+    DestinationNotAllowed: '⛔',
 };
 
 export default function AuditLog() {
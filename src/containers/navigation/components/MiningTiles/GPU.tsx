@@ -15,6 +15,7 @@ export default function GPUTile() {
 
     const gpuEnabled = useConfigMiningStore((s) => s.gpu_mining_enabled);
     const miningInitiated = useMiningStore((s) => s.isGpuMiningInitiated);
+    const isThermalPaused = useMiningStore((s) => s.isGpuMiningThermalPaused);
     const gpu_mining_status = useMiningMetricsStore((s) => s.gpu_mining_status);
     const isGpuPoolEnabled = useConfigPoolsStore((s) => s.gpu_pool_enabled);
     const selectedMiner = useMiningStore(getSelectedMiner);
@@ -29,6 +30,7 @@ export default function GPUTile() {
         <MinerTile
             title="GPU"
             mainLabelKey="gpu-power"
+            pausedLabelKey={isThermalPaused ? 'gpu-thermal-paused' : undefined}
             enabled={gpuEnabled}
             isMining={is_mining}
             isMiningInitiated={miningInitiated}
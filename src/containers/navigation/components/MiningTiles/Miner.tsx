@@ -20,6 +20,7 @@ import { GpuMiningAlgorithm } from '@app/types/events-payloads.ts';
 export interface MinerTileProps {
     title: PoolType;
     mainLabelKey: string;
+    pausedLabelKey?: string; // Replaces the heading while the miner is paused, e.g. by the thermal throttle
     enabled: boolean;
     isMiningInitiated: boolean;
     isMining: boolean;
@@ -37,6 +38,7 @@ export interface MinerTileProps {
 export default function MinerTile({
     title,
     mainLabelKey,
+    pausedLabelKey,
     enabled,
     hashRate,
     isMining,
@@ -64,7 +66,9 @@ export default function MinerTile({
     const mainUnit = isPoolEnabled ? 'XTM' : formattedHashRate.unit;
 
     let mainLabel: string;
-    if (enabled && isPoolEnabled) {
+    if (enabled && pausedLabelKey) {
+        mainLabel = t(pausedLabelKey);
+    } else if (enabled && isPoolEnabled) {
         const context = isMining && currentUnpaid === 0 && 'zero';
         mainLabel = t('stats.tile-heading', { context, ns: 'p2p' });
     } else if (enabled) {
@@ -4,7 +4,13 @@ import { listen } from '@tauri-apps/api/event';
 import { BACKEND_STATE_UPDATE, BackendStateUpdateEvent } from '@app/types/backend-state.ts';
 
 import { handleNewBlockPayload, useBlockchainVisualisationStore } from '@app/store/useBlockchainVisualisationStore';
-import { setCpuMiningStatus, setGpuDevices, setGpuMiningStatus } from '@app/store/actions/miningMetricsStoreActions';
+import {
+    setCpuMiningStatus,
+    setGpuDevices,
+    setGpuDevicesMiningStatus,
+    setGpuMiningStatus,
+    handleThermalThrottle,
+} from '@app/store/actions/miningMetricsStoreActions';
 import {
     handleAskForRestart,
     handleCloseSplashscreen,
@@ -18,9 +24,11 @@ import {
     setSidebarOpen,
 } from '@app/store/actions/uiStoreActions';
 import {
+    handleAvailableCpuMinersChanged,
     handleAvailableMinersChanged,
     handleCpuMinerControlsStateChanged,
     handleGpuMinerControlsStateChanged,
+    handleSelectedCpuMinerChanged,
     handleSelectedMinerChanged,
     setShowEcoAlert,
 } from '@app/store/actions/miningStoreActions';
@@ -68,7 +76,7 @@ import {
 } from '@app/store/useMcpStore';
 import { invoke } from '@tauri-apps/api/core';
 
-import { setCpuPoolStats, setGpuPoolStats } from '@app/store/actions/miningPoolsStoreActions';
+import { handlePoolSwitched, setCpuPoolStats, setGpuPoolStats } from '@app/store/actions/miningPoolsStoreActions';
 import {
     handlePinLocked,
     handleSeedBackedUp,
@@ -121,6 +129,9 @@ const useTauriEventsListener = () => {
                         case 'GpuMiningUpdate':
                             setGpuMiningStatus(event.payload);
                             break;
+                        case 'GpuDevicesMiningUpdate':
+                            setGpuDevicesMiningStatus(event.payload);
+                            break;
                         case 'CpuMiningUpdate':
                             setCpuMiningStatus(event.payload);
                             break;
@@ -130,6 +141,12 @@ const useTauriEventsListener = () => {
                         case 'GpuPoolsStatsUpdate':
                             setGpuPoolStats(event.payload);
                             break;
+                        case 'PoolSwitched':
+                            handlePoolSwitched(event.payload);
+                            break;
+                        case 'ThermalThrottle':
+                            handleThermalThrottle(event.payload);
+                            break;
                         case 'NewBlockHeight': {
                             const current = useBlockchainVisualisationStore.getState().latestBlockPayload?.block_height;
                             if (!current || current < event.payload.block_height) {
@@ -183,6 +200,12 @@ const useTauriEventsListener = () => {
                         case 'AvailableMiners':
                             handleAvailableMinersChanged(event.payload);
                             break;
+                        case 'UpdateSelectedCpuMiner':
+                            handleSelectedCpuMinerChanged(event.payload);
+                            break;
+                        case 'AvailableCpuMiners':
+                            handleAvailableCpuMinersChanged(event.payload);
+                            break;
                         case 'CriticalProblem': {
                             const isMacAppFolderError =
                                 event.payload.title === 'common:installation-problem' &&
@@ -252,16 +252,10 @@ export const selectMiningMode = async (mode: string) => {
 
     useMiningStore.setState((c) => ({ ...c, isChangingMode: true }));
 
-    const cpu_mining_status = useMiningMetricsStore.getState().cpu_mining_status;
     const gpu_mining_status = useMiningMetricsStore.getState().gpu_mining_status;
-    const wasCpuMiningInitiated = useMiningStore.getState().isCpuMiningInitiated;
     const wasGpuMiningInitiated = useMiningStore.getState().isGpuMiningInitiated;
 
-    if (cpu_mining_status.is_mining) {
-        console.info('Stopping CPU mining...');
-        await stopCpuMining();
-    }
-
+    // The CPU miner is updated in place by the backend, only the GPU miner needs a restart
     if (gpu_mining_status.is_mining || wasGpuMiningInitiated) {
         console.info('Stopping GPU mining...');
         await stopGpuMining();
@@ -270,11 +264,6 @@ export const selectMiningMode = async (mode: string) => {
     invoke('select_mining_mode', { mode })
         .then(() => {
             useConfigMiningStore.setState((c) => ({ ...c, selected_mining_mode: mode }));
-            if (wasCpuMiningInitiated) {
-                console.info('Restarting CPU mining...');
-                startCpuMining();
-            }
-
             if (wasGpuMiningInitiated) {
                 console.info('Restarting GPU mining...');
                 startGpuMining();
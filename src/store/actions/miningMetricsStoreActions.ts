@@ -1,13 +1,25 @@
-import { CpuMinerStatus, GpuDevice, GpuMinerStatus } from '@app/types/app-status.ts';
+import { CpuMinerStatus, GpuDevice, GpuDeviceMiningStatus, GpuMinerStatus } from '@app/types/app-status.ts';
+import { ThermalThrottleRecord } from '@app/types/events-payloads.ts';
 
 import { useMiningMetricsStore } from '../useMiningMetricsStore.ts';
 
 export const setGpuDevices = (gpu_devices: GpuDevice[]) => {
     useMiningMetricsStore.setState({ gpu_devices });
 };
+export const setGpuDevicesMiningStatus = (gpu_devices_mining_status: GpuDeviceMiningStatus[]) => {
+    useMiningMetricsStore.setState({ gpu_devices_mining_status });
+};
 export const setGpuMiningStatus = (gpu_mining_status: GpuMinerStatus) => {
     useMiningMetricsStore.setState((c) => ({ ...c, gpu_mining_status }));
 };
 export const setCpuMiningStatus = (cpu_mining_status: CpuMinerStatus) => {
     useMiningMetricsStore.setState((c) => ({ ...c, cpu_mining_status }));
 };
+
+const MAX_THERMAL_THROTTLES = 50;
+export const handleThermalThrottle = (record: ThermalThrottleRecord) => {
+    useMiningMetricsStore.setState((c) => ({
+        ...c,
+        thermal_throttles: [...c.thermal_throttles, record].slice(-MAX_THERMAL_THROTTLES),
+    }));
+};
@@ -1,4 +1,5 @@
 import { PoolStats } from '@app/types/app-status';
+import { PoolSwitchRecord } from '@app/types/events-payloads.ts';
 import { PoolType, RewardValues, useMiningPoolsStore } from '../useMiningPoolsStore';
 import { deepEqual } from '@app/utils/objectDeepEqual.ts';
 import i18n from 'i18next';
@@ -94,3 +95,11 @@ export const setGpuPoolStats = (gpuPoolStats: Record<string, PoolStats>) => {
         };
     });
 };
+
+const MAX_POOL_SWITCHES = 50;
+export const handlePoolSwitched = (record: PoolSwitchRecord) => {
+    useMiningPoolsStore.setState((c) => ({
+        ...c,
+        poolSwitches: [...c.poolSwitches, record].slice(-MAX_POOL_SWITCHES),
+    }));
+};
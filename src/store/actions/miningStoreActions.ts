@@ -8,7 +8,7 @@ import { useSetupStore } from '@app/store/useSetupStore.ts';
 import { useConfigMiningStore } from '../useAppConfigStore.ts';
 import { Network } from '@app/utils/network.ts';
 import { setupStoreSelectors } from '../selectors/setupStoreSelectors.ts';
-import { GpuMiner, GpuMinerType, MinerControlsState } from '@app/types/events-payloads.ts';
+import { CpuMiner, CpuMinerType, GpuMiner, GpuMinerType, MinerControlsState } from '@app/types/events-payloads.ts';
 import { MiningModeType } from '@app/types/configs.ts';
 import { useAirdropStore } from '@app/store';
 import { FEATURE_FLAGS } from '@app/store/consts.ts';
@@ -195,10 +195,18 @@ export const handleSelectedMinerChanged = (miner: GpuMinerType) => {
     useMiningStore.setState({ selectedMiner: miner });
 };
 
-export const handleAvailableMinersChanged = (miners: Record<GpuMinerType, GpuMiner>) => {
+export const handleAvailableMinersChanged = (miners: Partial<Record<GpuMinerType, GpuMiner>>) => {
     useMiningStore.setState({ availableMiners: miners });
 };
 
+export const handleSelectedCpuMinerChanged = (miner: CpuMinerType) => {
+    useMiningStore.setState({ selectedCpuMiner: miner });
+};
+
+export const handleAvailableCpuMinersChanged = (miners: Record<CpuMinerType, CpuMiner>) => {
+    useMiningStore.setState({ availableCpuMiners: miners });
+};
+
 export const handleSessionMiningTime = ({ startTimestamp, stopTimestamp }: SessionMiningTime) => {
     const current = useMiningStore.getState().sessionMiningTime;
     if (stopTimestamp) {
@@ -256,16 +264,23 @@ export const handleCpuMinerControlsStateChanged = (state: MinerControlsState) =>
 export const handleGpuMinerControlsStateChanged = (state: MinerControlsState) => {
     switch (state) {
         case MinerControlsState.Idle:
-            useMiningStore.setState({ isGpuMiningInitiated: false });
+            useMiningStore.setState({ isGpuMiningInitiated: false, isGpuMiningThermalPaused: false });
             break;
         case MinerControlsState.Started:
-            useMiningStore.setState({ isGpuMiningInitiated: true, userManuallyStopped: false });
+            useMiningStore.setState({
+                isGpuMiningInitiated: true,
+                isGpuMiningThermalPaused: false,
+                userManuallyStopped: false,
+            });
             handleStartSideEffects();
             break;
         case MinerControlsState.Stopped:
-            useMiningStore.setState({ isGpuMiningInitiated: false });
+            useMiningStore.setState({ isGpuMiningInitiated: false, isGpuMiningThermalPaused: false });
             handleStopSideEffects();
             break;
+        case MinerControlsState.ThermalPaused:
+            useMiningStore.setState({ isGpuMiningThermalPaused: true });
+            break;
     }
 };
 
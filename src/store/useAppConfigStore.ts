@@ -99,6 +99,9 @@ const configMcpInitialState: ConfigMcp = {
     read_tier_enabled: true,
     control_tier_enabled: true,
     rate_limit_transaction: 5,
+    clients: [],
+    spend_history: [],
+    destination_allowlist_enabled: false,
 };
 
 export const useConfigMcpStore = create<ConfigMcp>()(() => ({
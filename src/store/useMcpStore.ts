@@ -5,10 +5,12 @@ export interface McpAuditEntry {
     timestamp: string;
     tool_name: string;
     tier: string;
-    status: 'Started' | 'Success' | 'Error' | 'Denied' | 'RateLimited';
+    status: 'Started' | 'Success' | 'Error' | 'Denied' | 'RateLimited' | 'BudgetExceeded' | 'DestinationNotAllowed';
     duration_ms?: number;
     client_info?: string;
     details?: string;
+    prev_hash?: string;
+    hash?: string;
 }
 
 export interface McpPendingTransaction {
@@ -6,6 +6,7 @@ describe('useMiningMetricsStore', () => {
     beforeEach(() => {
         useMiningMetricsStore.setState({
             gpu_devices: [],
+            gpu_devices_mining_status: [],
             gpu_mining_status: {
                 is_mining: false,
                 hash_rate: 0,
@@ -1,14 +1,18 @@
 import { create } from 'zustand';
-import { CpuMinerStatus, GpuMinerStatus, GpuDevice } from '@app/types/app-status';
+import { CpuMinerStatus, GpuMinerStatus, GpuDevice, GpuDeviceMiningStatus } from '@app/types/app-status';
+import { ThermalThrottleRecord } from '@app/types/events-payloads';
 
 interface MiningMetricsStoreState {
     gpu_devices: GpuDevice[];
+    gpu_devices_mining_status: GpuDeviceMiningStatus[];
     gpu_mining_status: GpuMinerStatus;
     cpu_mining_status: CpuMinerStatus;
+    thermal_throttles: ThermalThrottleRecord[];
 }
 
 const initialState: MiningMetricsStoreState = {
     gpu_devices: [],
+    gpu_devices_mining_status: [],
     gpu_mining_status: {
         is_mining: false,
         hash_rate: 0,
@@ -21,6 +25,7 @@ const initialState: MiningMetricsStoreState = {
         estimated_earnings: 0,
         connection: { is_connected: false },
     },
+    thermal_throttles: [],
 };
 
 export const useMiningMetricsStore = create<MiningMetricsStoreState>()(() => ({
@@ -1,5 +1,6 @@
 import { create } from 'zustand';
 import { PoolStats } from '../types/app-status.ts';
+import { PoolSwitchRecord } from '../types/events-payloads.ts';
 
 export type PoolType = 'CPU' | 'GPU';
 export interface RewardValues {
@@ -11,6 +12,7 @@ interface MiningPoolsStoreState {
     cpuPoolStats?: PoolStats;
     gpuRewards?: RewardValues;
     gpuPoolStats?: PoolStats;
+    poolSwitches: PoolSwitchRecord[];
 }
 
 const initialRewards: RewardValues = {
@@ -22,6 +24,7 @@ const initialState: MiningPoolsStoreState = {
     cpuRewards: initialRewards,
     gpuPoolStats: undefined,
     gpuRewards: initialRewards,
+    poolSwitches: [],
 };
 
 export const useMiningPoolsStore = create<MiningPoolsStoreState>()(() => ({
@@ -1,6 +1,6 @@
 import { create } from 'zustand';
 import { Network } from '@app/utils/network';
-import { GpuMiner, GpuMinerType } from '@app/types/events-payloads';
+import { CpuMiner, CpuMinerType, GpuMiner, GpuMinerType } from '@app/types/events-payloads';
 
 export interface SessionMiningTime {
     startTimestamp?: number;
@@ -21,12 +21,15 @@ export interface MiningStoreState {
     counter: number;
     isCpuMiningInitiated: boolean;
     isGpuMiningInitiated: boolean;
+    isGpuMiningThermalPaused: boolean; // Stopped by the thermal throttle until the gpu cools down
     wasMineOnAppStartExecuted?: boolean;
     resumeAfterRestart: { cpu: boolean; gpu: boolean };
     customLevelsDialogOpen: boolean;
     network?: Network;
-    availableMiners?: Record<GpuMinerType, GpuMiner>;
+    availableMiners?: Partial<Record<GpuMinerType, GpuMiner>>;
     selectedMiner?: GpuMinerType;
+    availableCpuMiners?: Record<CpuMinerType, CpuMiner>;
+    selectedCpuMiner?: CpuMinerType;
     sessionMiningTime: SessionMiningTime;
     showEcoAlert: boolean;
     selectedResumeDuration?: ResumeMiningTime;
@@ -42,6 +45,7 @@ const initialState: MiningStoreState = {
     hashrateReady: false,
     isCpuMiningInitiated: false,
     isGpuMiningInitiated: false,
+    isGpuMiningThermalPaused: false,
     wasMineOnAppStartExecuted: false,
     resumeAfterRestart: { cpu: false, gpu: false },
     isChangingMode: false,
@@ -51,6 +55,8 @@ const initialState: MiningStoreState = {
     network: undefined,
     availableMiners: undefined,
     selectedMiner: undefined,
+    availableCpuMiners: undefined,
+    selectedCpuMiner: undefined,
     showEcoAlert: false,
 };
 
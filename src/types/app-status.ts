@@ -54,6 +54,24 @@ export interface GpuDevice {
     device_id: number;
 }
 
+interface GpuDeviceParameters {
+    usage_percentage: number;
+    current_temperature: number;
+    max_temperature: number;
+}
+
+export interface GpuDeviceMiningStatus {
+    device_index: number;
+    name: string;
+    hash_rate: number;
+    accepted_shares: number;
+    rejected_shares: number;
+    temperature?: number;
+    fan_speed?: number;
+    power_watts?: number;
+    hardware_parameters?: GpuDeviceParameters; // Hardware monitor readings of the card with the same name
+}
+
 export interface CpuMinerStatus {
     is_mining: boolean;
     hash_rate: number;
@@ -70,6 +88,7 @@ export interface PoolStats {
 
 interface CpuMinerConnectionStatus {
     is_connected: boolean;
+    accepted_shares?: number;
 }
 
 export interface GpuMinerStatus {
@@ -77,6 +96,8 @@ export interface GpuMinerStatus {
     hash_rate: number;
     estimated_earnings: number;
     is_available: boolean;
+    is_connected?: boolean;
+    accepted_shares?: number;
 }
 
 export interface BaseNodeStatus {
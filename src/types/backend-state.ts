@@ -1,6 +1,8 @@
 import {
     BackgroundNodeSyncUpdatePayload,
     ConnectionStatusPayload,
+    CpuMiner,
+    CpuMinerType,
     CriticalProblemPayload,
     DetectedDevicesPayload,
     GpuMiner,
@@ -8,15 +10,18 @@ import {
     MinerControlsState,
     NewBlockHeightPayload,
     NodeTypeUpdatePayload,
+    PoolSwitchRecord,
     ProgressTrackerUpdatePayload,
     SetupPhase,
     ShowReleaseNotesPayload,
     TariAddressUpdatePayload,
+    ThermalThrottleRecord,
     WalletUIMode,
 } from './events-payloads.ts';
 import {
     BaseNodeStatus,
     CpuMinerStatus,
+    GpuDeviceMiningStatus,
     GpuMinerStatus,
     NetworkStatus,
     PoolStats,
@@ -58,6 +63,10 @@ export type BackendStateUpdateEvent =
           event_type: 'GpuMiningUpdate';
           payload: GpuMinerStatus;
       }
+    | {
+          event_type: 'GpuDevicesMiningUpdate';
+          payload: GpuDeviceMiningStatus[];
+      }
     | {
           event_type: 'NewBlockHeight';
           payload: NewBlockHeightPayload;
@@ -150,6 +159,14 @@ export type BackendStateUpdateEvent =
           event_type: 'GpuPoolsStatsUpdate';
           payload: Record<string, PoolStats>;
       }
+    | {
+          event_type: 'PoolSwitched';
+          payload: PoolSwitchRecord;
+      }
+    | {
+          event_type: 'ThermalThrottle';
+          payload: ThermalThrottleRecord;
+      }
     | {
           event_type: 'ExchangeIdChanged';
           payload: string;
@@ -200,7 +217,15 @@ export type BackendStateUpdateEvent =
       }
     | {
           event_type: 'AvailableMiners';
-          payload: Record<GpuMinerType, GpuMiner>;
+          payload: Partial<Record<GpuMinerType, GpuMiner>>;
+      }
+    | {
+          event_type: 'UpdateSelectedCpuMiner';
+          payload: CpuMinerType;
+      }
+    | {
+          event_type: 'AvailableCpuMiners';
+          payload: Record<CpuMinerType, CpuMiner>;
       }
     | {
           event_type: 'WalletStatusUpdate';
@@ -276,7 +301,14 @@ export type BackendStateUpdateEvent =
               timestamp: string;
               tool_name: string;
               tier: string;
-              status: 'Started' | 'Success' | 'Error' | 'Denied' | 'RateLimited';
+              status:
+                  | 'Started'
+                  | 'Success'
+                  | 'Error'
+                  | 'Denied'
+                  | 'RateLimited'
+                  | 'BudgetExceeded'
+                  | 'DestinationNotAllowed';
               duration_ms?: number;
               client_info?: string;
               details?: string;
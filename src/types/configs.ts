@@ -1,4 +1,5 @@
-import { WalletUIMode } from './events-payloads';
+import { CpuMinerType, GpuMinerType, GpuMiningAlgorithm, WalletUIMode } from './events-payloads';
+import { TariffSettings } from './mining/tariff';
 
 export interface ConfigWallet {
     created_at: string;
@@ -54,6 +55,48 @@ export interface ConfigMining {
     eco_alert_needed: boolean;
     mode_mining_times?: MiningModeTimes;
     pause_on_battery_mode: PauseOnBatteryModeState;
+    tariff_settings?: TariffSettings;
+    selected_cpu_miner?: CpuMinerType;
+    selected_gpu_miner?: GpuMinerType;
+    cpu_thread_benchmark?: CpuThreadBenchmark;
+    gpu_miner_benchmark?: GpuMinersBenchmark;
+    thermal_limits?: ThermalLimitsSettings;
+}
+
+export interface ThermalLimitsSettings {
+    enabled: boolean;
+    cpu_max_temperature: number; // Celsius above which cpu threads are lowered
+    gpu_max_temperature: number; // Celsius above which gpu intensity is lowered
+    hysteresis: number; // Degrees below the limit a device has to cool down to before its usage is raised again
+}
+
+export interface CpuThreadBenchmark {
+    available_threads: number; // Results are ignored once the machine reports a different thread count
+    hash_rates: Record<number, number>; // Hash rate in H/s per benchmarked thread count
+    mode_threads: Record<string, number>; // Thread count used by the Eco, Turbo and Ludicrous modes
+    benchmarked_at: number;
+}
+
+interface GpuDeviceBenchmark {
+    device_index: number;
+    name: string;
+    hash_rate: number;
+    stability_percentage: number; // Share of the samples in which the device reported a hash rate
+}
+
+interface GpuMinerBenchmark {
+    miner_type: GpuMinerType;
+    algorithm: GpuMiningAlgorithm;
+    hash_rate: number; // In the unit of the miner's algorithm, not comparable across algorithms
+    stability_percentage: number;
+    devices: GpuDeviceBenchmark[];
+    error?: string;
+}
+
+export interface GpuMinersBenchmark {
+    results: GpuMinerBenchmark[];
+    recommended_miner?: GpuMinerType; // Only ranked against miners of the previously selected miner's algorithm
+    benchmarked_at: number;
 }
 
 export interface ConfigMiningSelectors {
@@ -63,6 +106,9 @@ export interface ConfigMiningSelectors {
 export interface GpuDeviceSettings {
     device_id: number;
     is_excluded: boolean;
+    intensity_percentage?: number;
+    power_limit_watts?: number;
+    max_temperature?: number;
 }
 
 export enum MiningModeType {
@@ -88,28 +134,43 @@ export interface ConfigPools {
     gpu_pool_enabled: boolean; // Whether GPU pool mining is enabled | defaults to true
     current_gpu_pool?: GpuPools; // Name of the selected GPU pool => defaults to LuckyPool
     gpu_pools?: Record<GpuPools, BasePoolData>; // Available GPU pools
+    gpu_pool_priority?: GpuPools[]; // Failover order, the first pool is the primary one
     // ======= Cpu Pool =======
     // When false we are solo mining with xmrig and mmproxy if true we are pool mining with xmrig
     cpu_pool_enabled: boolean; // Whether CPU pool mining is enabled | defaults to true
     current_cpu_pool?: CpuPools; // Name of the selected CPU pool => defaults to LuckyPool
     cpu_pools?: Record<CpuPools, BasePoolData>; // Available CPU pools
+    cpu_pool_priority?: CpuPools[]; // Failover order, the first pool is the primary one
+    // ======= Failover =======
+    pool_failover?: PoolFailoverSettings;
+}
+
+export interface PoolFailoverSettings {
+    enabled: boolean; // Off by default
+    failover_after_secs: number; // No connection or accepted shares for this long switches to the next pool
+    failback_probe_interval_secs: number; // How often higher priority pools are probed while on a fallback pool
+    probe_before_start?: boolean; // Stratum handshake with the selected pool before the miner starts | defaults to false
 }
 
 export enum GpuPools {
     LuckyPoolC29 = 'LuckyPoolC29',
     KryptexPoolC29 = 'KryptexPoolC29',
+    Custom = 'Custom', // User defined C29 pool
+    CustomSha3x = 'CustomSha3x', // User defined SHA3X pool, used by Glytex
 }
 
 export enum CpuPools {
     SupportXTMPoolRandomX = 'SupportXTMPoolRANDOMX',
     LuckyPoolRandomX = 'LuckyPoolRANDOMX',
     KryptexPoolRandomX = 'KryptexPoolRANDOMX',
+    Custom = 'Custom',
 }
 
 export enum PoolOrigin {
     SupportXTM = 'SupportXTM',
     LuckyPool = 'LuckyPool',
     Kryptex = 'Kryptex',
+    Custom = 'Custom',
 }
 
 export interface BasePoolData {
@@ -118,6 +179,28 @@ export interface BasePoolData {
     pool_origin: PoolOrigin;
     pool_url: string;
     stats_url: string;
+    custom?: CustomPoolSettings; // Only set for user defined pools
+}
+
+// JSON pointers into the stats endpoint response, e.g. '/stats/unpaid'
+interface PoolStatsPointers {
+    accepted_shares?: string;
+    unpaid?: string;
+    balance?: string;
+    min_payout?: string;
+}
+
+interface CustomPoolSettings {
+    worker_name_template: string; // Appended to the mining address, %WORKER_NAME% is replaced with Tari-universe
+    stats_pointers: PoolStatsPointers;
+}
+
+export interface CustomPoolConfig {
+    pool_name?: string;
+    pool_url: string; // host:port with an optional stratum+tcp:// or stratum+ssl:// scheme
+    stats_url?: string; // %TARI_ADDRESS% is replaced with the mining address
+    worker_name_template: string;
+    stats_pointers: PoolStatsPointers;
 }
 
 export interface ConfigBackendInMemory {
@@ -140,4 +223,23 @@ export interface ConfigMcp {
     read_tier_enabled: boolean;
     control_tier_enabled: boolean;
     rate_limit_transaction: number;
+    clients: McpClientCredential[];
+    daily_spend_limit?: number;
+    weekly_spend_limit?: number;
+    spend_history: McpSpendRecord[];
+    destination_allowlist_enabled: boolean;
+}
+
+export interface McpSpendRecord {
+    timestamp: { secs_since_epoch: number; nanos_since_epoch: number };
+    amount: number;
+}
+
+export interface McpClientCredential {
+    name: string;
+    tiers: string[];
+    created_at: { secs_since_epoch: number; nanos_since_epoch: number };
+    expires_at?: { secs_since_epoch: number; nanos_since_epoch: number };
+    rate_limit_per_minute?: number;
+    revoked: boolean;
 }
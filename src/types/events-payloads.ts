@@ -92,6 +92,17 @@ export interface ProgressTrackerUpdatePayload {
 
 export enum GpuMinerType {
     LolMiner = 'LolMiner',
+    Glytex = 'Glytex',
+}
+
+export enum CpuMinerType {
+    Xmrig = 'Xmrig',
+}
+
+export interface CpuMiner {
+    miner_type: CpuMinerType;
+    is_healthy: boolean;
+    last_error?: string;
 }
 
 export enum GpuMinerFeature {
@@ -99,11 +110,13 @@ export enum GpuMinerFeature {
     PoolMining = 'PoolMining',
     DeviceExclusion = 'DeviceExclusion',
     MiningIntensity = 'MiningIntensity',
+    DeviceLimits = 'DeviceLimits',
     EngineSelection = 'EngineSelection',
 }
 
 export enum GpuMiningAlgorithm {
     C29 = 'C29',
+    Sha3x = 'Sha3x',
 }
 
 export enum MinerControlsState {
@@ -112,6 +125,7 @@ export enum MinerControlsState {
     Stopped = 'Stopped',
     Restarting = 'Restarting',
     Idle = 'Idle',
+    ThermalPaused = 'ThermalPaused',
 }
 
 export interface GpuMiner {
@@ -121,3 +135,34 @@ export interface GpuMiner {
     is_healthy: boolean;
     last_error?: string;
 }
+
+export interface PoolSwitchRecord {
+    kind: 'Cpu' | 'Gpu';
+    from: string;
+    to: string;
+    reason: 'Failover' | 'Failback';
+    switched_at: number;
+}
+
+export interface ThermalThrottleRecord {
+    kind: 'Cpu' | 'Gpu';
+    reason: 'OverTemperature' | 'CooledDown' | 'Disabled';
+    temperature?: number;
+    max_temperature: number;
+    throttle_percentage: number; // Share of the selected mode usage the miner runs with after the change
+    throttled_at: number;
+}
+
+interface StratumProbeError {
+    class: 'Dns' | 'Refused' | 'Timeout' | 'Tls' | 'Protocol';
+    message: string;
+}
+
+export interface PoolLatencyReport {
+    kind: 'Cpu' | 'Gpu';
+    pool: string;
+    pool_name: string;
+    pool_url: string;
+    latency_ms?: number; // Missing when the pool did not complete the stratum handshake
+    error?: StratumProbeError;
+}
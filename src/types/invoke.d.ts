@@ -9,11 +9,22 @@ import {
 import { PaperWalletDetails } from './app-status.ts';
 import { LocalBlockStats } from './mining/blocks.ts';
 import { displayMode } from '../store/types.ts';
-import { BasePoolData, ConfigBackendInMemory, PauseOnBatteryModeState } from './configs.ts';
+import {
+    BasePoolData,
+    ConfigBackendInMemory,
+    CpuThreadBenchmark,
+    CustomPoolConfig,
+    GpuMinersBenchmark,
+    PauseOnBatteryModeState,
+    PoolFailoverSettings,
+    ThermalLimitsSettings,
+} from './configs.ts';
 import { ExchangeMiner } from './exchange';
 import { ActiveTapplet } from './tapplets/tapplet.types';
-import { SchedulerEventTiming, SchedulerEventType } from './mining/schedule.ts';
+import { EventActivations, SchedulerEventTiming, SchedulerEventType } from './mining/schedule.ts';
+import { TariffBandEstimate, TariffSettings } from './mining/tariff.ts';
 import { Language } from '../i18initializer.ts';
+import { CpuMinerType, GpuMinerType, PoolLatencyReport, PoolSwitchRecord } from './events-payloads.ts';
 
 declare module '@tauri-apps/api/core' {
     function invoke(
@@ -55,6 +66,10 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'reset_settings', payload: { resetWallet: boolean }): Promise<string>;
     function invoke(param: 'set_gpu_mining_enabled', payload: { enabled: boolean }): Promise<void>;
     function invoke(param: 'set_cpu_mining_enabled', payload: { enabled: boolean }): Promise<void>;
+    function invoke(param: 'switch_cpu_miner', payload: { miner: CpuMinerType }): Promise<void>;
+    function invoke(param: 'switch_gpu_miner', payload: { miner: GpuMinerType }): Promise<void>;
+    function invoke(param: 'benchmark_cpu_threads'): Promise<CpuThreadBenchmark>;
+    function invoke(param: 'benchmark_gpu_miners', payload: { autoSelect: boolean }): Promise<GpuMinersBenchmark>;
     function invoke(param: 'exit_application'): Promise<string>;
     function invoke(param: 'restart_application'): Promise<string>;
     function invoke(param: 'set_use_tor', payload: { useTor: boolean }): Promise<void>;
@@ -80,6 +95,14 @@ declare module '@tauri-apps/api/core' {
         param: 'toggle_device_exclusion',
         payload: { device_index: number; excluded: boolean }
     ): Promise<void>;
+    function invoke(
+        param: 'set_gpu_device_intensity',
+        payload: { deviceIndex: number; intensityPercentage?: number }
+    ): Promise<void>;
+    function invoke(
+        param: 'set_gpu_device_limits',
+        payload: { deviceIndex: number; powerLimitWatts?: number; maxTemperature?: number }
+    ): Promise<void>;
     function invoke(
         param: 'set_show_experimental_settings',
         payload: { showExperimentalSettings: boolean }
@@ -133,6 +156,19 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'update_selected_gpu_pool', payload: { updated_config: BasePoolData }): Promise<void>;
     function invoke(param: 'reset_gpu_pool_config', payload: { gpuPoolType: string }): Promise<void>;
     function invoke(param: 'reset_cpu_pool_config', payload: { cpuPoolType: string }): Promise<void>;
+    function invoke(param: 'save_custom_cpu_pool', payload: { config: CustomPoolConfig }): Promise<void>;
+    function invoke(
+        param: 'save_custom_gpu_pool',
+        payload: { config: CustomPoolConfig; algorithm?: 'C29' | 'Sha3x' }
+    ): Promise<void>;
+    function invoke(param: 'remove_cpu_pool', payload: { cpuPool: string }): Promise<void>;
+    function invoke(param: 'remove_gpu_pool', payload: { gpuPool: string }): Promise<void>;
+    function invoke(param: 'set_cpu_pool_priority', payload: { cpuPools: string[] }): Promise<void>;
+    function invoke(param: 'set_gpu_pool_priority', payload: { gpuPools: string[] }): Promise<void>;
+    function invoke(param: 'set_pool_failover_settings', payload: { settings: PoolFailoverSettings }): Promise<void>;
+    function invoke(param: 'get_pool_switch_history'): Promise<PoolSwitchRecord[]>; // Current session only
+    function invoke(param: 'set_thermal_limits', payload: { settings: ThermalLimitsSettings }): Promise<void>;
+    function invoke(param: 'rank_pools_by_latency'): Promise<PoolLatencyReport[]>;
     function invoke(param: 'restart_phases', payload: { phases: SetupPhase[] }): Promise<void>;
     function invoke(param: 'list_connected_peers'): Promise<string[]>;
 
@@ -154,5 +190,9 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'remove_scheduler_event', payload: { eventId: string }): Promise<void>;
     function invoke(param: 'pause_scheduler_event', payload: { eventId: string }): Promise<void>;
     function invoke(param: 'resume_scheduler_event', payload: { eventId: string }): Promise<void>;
+    function invoke(param: 'get_scheduler_event_activations', payload: { count?: number }): Promise<EventActivations[]>;
+    function invoke(param: 'set_tariff_settings', payload: { settings: TariffSettings }): Promise<TariffBandEstimate[]>;
+    function invoke(param: 'get_tariff_cost_estimates'): Promise<TariffBandEstimate[]>;
+    function invoke(param: 'clear_tariff_schedule'): Promise<void>;
     function invoke(param: 'set_custom_node_directory', payload: { path: string }): Promise<void>;
 }
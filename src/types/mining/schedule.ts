@@ -2,7 +2,16 @@ export const TIME_PERIOD = ['AM', 'PM'] as const;
 type TimePeriodTuple = typeof TIME_PERIOD;
 export type TimePeriod = TimePeriodTuple[number];
 
-export type SchedulerEventType = 'ResumeMining' | { Mine: { mining_mode: string } };
+export type SchedulerEventType =
+    | 'ResumeMining'
+    | { Mine: { mining_mode: string } }
+    | { CpuMining: { enabled: boolean } }
+    | { GpuMining: { enabled: boolean } }
+    | { SwitchCpuPool: { pool_name: string } }
+    | { SwitchGpuPool: { pool_name: string } }
+    | { CpuPoolMining: { enabled: boolean } }
+    | { GpuPoolMining: { enabled: boolean } }
+    | { SwitchNodeType: { node_type: 'Local' | 'Remote' | 'RemoteUntilLocal' } };
 export type SchedulerEventTiming = { In: InTime } | { Between: BetweenTime };
 
 export interface TimeParts {
@@ -22,6 +31,15 @@ export interface InTime {
     time_unit: TimeUnit;
 }
 
+export type DayOfWeek = 'Monday' | 'Tuesday' | 'Wednesday' | 'Thursday' | 'Friday' | 'Saturday' | 'Sunday';
+
+export interface ScheduleDateRange {
+    start_month: number;
+    start_day: number;
+    end_month: number;
+    end_day: number;
+}
+
 export interface BetweenTime {
     start_hour: number;
     start_minute: number;
@@ -29,6 +47,8 @@ export interface BetweenTime {
     end_hour: number;
     end_minute: number;
     end_period: TimePeriod;
+    weekdays?: DayOfWeek[];
+    date_range?: ScheduleDateRange | null;
 }
 
 export enum SchedulerEventState {
@@ -38,9 +58,39 @@ export enum SchedulerEventState {
     Completed = 'Completed',
 }
 
+export type CatchUpReason = 'Startup' | 'Wake' | 'ClockJump' | 'Benchmark';
+
+export type CatchUpAction = 'Enter' | 'Exit';
+
+export interface CatchUpRecord {
+    at: number;
+    action: CatchUpAction;
+    reason: CatchUpReason;
+}
+
+export type ExecutionPhase = 'Enter' | 'Exit';
+
+export interface ExecutionRecord {
+    triggered_at: number;
+    phase: ExecutionPhase;
+    action: string;
+    catch_up?: CatchUpReason | null;
+    error?: string | null;
+}
+
+export interface EventActivations {
+    event_id: string;
+    starts: number[];
+    ends: number[];
+}
+
 export interface SchedulerEvent {
     id: string;
     event_type: SchedulerEventType;
     timing: SchedulerEventTiming;
     state: SchedulerEventState;
+    next_activation?: number | null;
+    priority?: number;
+    catch_ups?: CatchUpRecord[];
+    journal?: ExecutionRecord[];
 }
@@ -0,0 +1,26 @@
+import { DayOfWeek } from './schedule.ts';
+
+export interface TariffBand {
+    name?: string | null;
+    start: string;
+    end: string;
+    weekdays?: DayOfWeek[];
+    price_per_kwh: number;
+}
+
+export interface TariffSettings {
+    file_path?: string | null;
+    cheap_mining_mode: string;
+    max_cheap_price_per_kwh: number;
+    mode_wattages: Record<string, number>;
+}
+
+export interface TariffBandEstimate {
+    band: string;
+    price_per_kwh: number;
+    mining_mode?: string | null;
+    watts: number;
+    hours_per_week: number;
+    cost_per_hour: number;
+    cost_per_week: number;
+}